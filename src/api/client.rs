@@ -4,15 +4,133 @@
 //! 01/14/2026 - Initial implementation with GraphQL + REST (Claude)
 
 use anyhow::{bail, Context, Result};
-use reqwest::Client;
+use base64::Engine;
+use reqwest::{Client, Method};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::path::PathBuf;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
-use crate::models::{GraphQLResponse, Issue, Notification, PullRequest, Repository, User};
+use super::meta;
+use crate::bootstrap::LabelSpec;
+use crate::models::{
+    ActionsCache, ActionsSecret, ActionsVariable, BranchInfo, Codespace, CommitSignature,
+    CommitVerification, CommunityProfile, CreatedCommit, FileCommit, FileWrite, ForkSyncResult,
+    GpgKey, GraphQLResponse, Issue, ItemLinks, JobStep, LabelEvent, LinkedItem, MergeQueue,
+    MergeQueueEntry, Notification, OrgInvitation, OrgMember, PrFile, PrFiles, PrReviewSample,
+    PullRequest, RefCheck, ReleasePr, RepoHealth, RepoInvitation, RepoPermission, RepoPermissions,
+    RepoSetup, Repository, ReviewSample, ReviewThread, SearchHit, ServiceComponent, ServiceStatus,
+    SshKey, SsoOrgStatus, StatusIncident, SubmittedReview, TreeEntry, User, WorkflowJob,
+};
 
 const GRAPHQL_ENDPOINT: &str = "https://api.github.com/graphql";
 const REST_ENDPOINT: &str = "https://api.github.com";
+const STATUS_ENDPOINT: &str = "https://www.githubstatus.com/api/v2/summary.json";
+
+/// The token is not authorized for an organization that enforces SAML SSO.
+///
+/// Raised from any request whose response carries an `X-GitHub-SSO: required`
+/// header; callers can `downcast_ref` it out of the `anyhow::Error`.
+#[derive(Debug, Clone)]
+pub struct SsoRequired {
+    /// URL the user must visit to authorize the token for the org.
+    pub authorization_url: String,
+}
+
+impl std::fmt::Display for SsoRequired {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SSO_REQUIRED: token is not authorized for this organization's SAML SSO. \
+             Authorize it at {}",
+            self.authorization_url
+        )
+    }
+}
+
+impl std::error::Error for SsoRequired {}
+
+/// The target changed upstream since the caller last read it.
+///
+/// Raised when an `expected_updated_at`/`expected_sha` precondition fails,
+/// either in our own check or as a `409 Conflict` on the write that carried
+/// the expected value. Other 409s (e.g. an empty repository) stay ordinary
+/// errors.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub message: String,
+}
+
+impl std::fmt::Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CONFLICT: {}", self.message)
+    }
+}
+
+impl std::error::Error for Conflict {}
+
+/// A REST request answered with a non-success status.
+///
+/// Callers can `downcast_ref` it to branch on the status rather than the
+/// message text.
+#[derive(Debug, Clone)]
+pub struct RestError {
+    pub status: reqwest::StatusCode,
+    pub body: String,
+}
+
+impl std::fmt::Display for RestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "REST request failed: {} - {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for RestError {}
+
+/// Whether `e` is a REST 404.
+fn is_not_found(e: &anyhow::Error) -> bool {
+    e.downcast_ref::<RestError>()
+        .is_some_and(|e| e.status == reqwest::StatusCode::NOT_FOUND)
+}
+
+/// Where an Actions secret or variable lives.
+#[derive(Debug, Clone)]
+pub enum ActionsScope {
+    Repo {
+        owner: String,
+        repo: String,
+    },
+    Org {
+        org: String,
+    },
+    Environment {
+        owner: String,
+        repo: String,
+        environment: String,
+    },
+}
+
+impl ActionsScope {
+    /// REST path prefix under which `/secrets` and `/variables` live.
+    fn base_path(&self) -> String {
+        match self {
+            ActionsScope::Repo { owner, repo } => format!("/repos/{}/{}/actions", owner, repo),
+            ActionsScope::Org { org } => format!("/orgs/{}/actions", org),
+            ActionsScope::Environment {
+                owner,
+                repo,
+                environment,
+            } => format!("/repos/{}/{}/environments/{}", owner, repo, environment),
+        }
+    }
+
+    fn is_org(&self) -> bool {
+        matches!(self, ActionsScope::Org { .. })
+    }
+}
 
 /// GitHub API client with persistent connection pooling.
 pub struct GitHubClient {
@@ -20,6 +138,36 @@ pub struct GitHubClient {
     token: String,
 }
 
+/// Creates the [`GitHubClient`] on first use.
+///
+/// Token resolution runs on the first call instead of at daemon start, and is
+/// retried on later calls if it failed (e.g. until `gh auth login` is run), so
+/// a missing or bad credential doesn't stop the daemon from starting.
+pub struct LazyClient {
+    token: Option<String>,
+    client: Mutex<Option<Arc<GitHubClient>>>,
+}
+
+impl LazyClient {
+    pub fn new(token: Option<String>) -> Self {
+        Self {
+            token,
+            client: Mutex::new(None),
+        }
+    }
+
+    /// Return the client, creating it if needed.
+    pub fn get(&self) -> Result<Arc<GitHubClient>> {
+        let mut slot = self.client.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(client) = slot.as_ref() {
+            return Ok(client.clone());
+        }
+        let client = Arc::new(GitHubClient::new(self.token.clone())?);
+        *slot = Some(client.clone());
+        Ok(client)
+    }
+}
+
 impl GitHubClient {
     /// Create a new GitHub client.
     ///
@@ -116,7 +264,7 @@ impl GitHubClient {
         variables: Option<Value>,
     ) -> Result<T> {
         let body = GraphQLRequest {
-            query: query.to_string(),
+            query: meta::with_rate_limit(query),
             variables,
         };
 
@@ -129,6 +277,11 @@ impl GitHubClient {
             .await
             .context("Failed to send GraphQL request")?;
 
+        let sso = sso_required(&response);
+        if let (Some(sso), false) = (&sso, response.status().is_success()) {
+            return Err(sso.clone().into());
+        }
+
         if !response.status().is_success() {
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
@@ -137,7 +290,7 @@ impl GitHubClient {
 
         let text = response.text().await.context("Failed to read response")?;
 
-        let result: GraphQLResponse<T> = serde_json::from_str(&text).map_err(|e| {
+        let result: GraphQLResponse<Value> = serde_json::from_str(&text).map_err(|e| {
             anyhow::anyhow!(
                 "JSON parse error: {} | Raw: {}",
                 e,
@@ -149,13 +302,35 @@ impl GitHubClient {
         if result.data.is_none() {
             if let Some(errors) = result.errors {
                 if !errors.is_empty() {
+                    if let Some(sso) = sso {
+                        return Err(sso.into());
+                    }
                     let messages: Vec<_> = errors.iter().map(|e| e.message.as_str()).collect();
                     bail!("GraphQL errors: {}", messages.join(", "));
                 }
             }
         }
 
-        result.data.context("GraphQL response missing data field")
+        let mut data = result.data.context("GraphQL response missing data field")?;
+
+        let rate_limit = data.as_object_mut().and_then(|d| d.remove("rateLimit"));
+        let rate = rate_limit.as_ref();
+        meta::record_graphql(
+            rate.and_then(|r| r.get("cost")).and_then(|v| v.as_i64()),
+            rate.and_then(|r| r.get("remaining"))
+                .and_then(|v| v.as_i64()),
+            rate.and_then(|r| r.get("resetAt"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+        );
+
+        serde_json::from_value(data).map_err(|e| {
+            anyhow::anyhow!(
+                "JSON parse error: {} | Raw: {}",
+                e,
+                &text[..text.len().min(500)]
+            )
+        })
     }
 
     /// Execute a REST API request (GET).
@@ -172,16 +347,176 @@ impl GitHubClient {
             .await
             .context("Failed to send REST request")?;
 
+        meta::record_rest(rate_limit_remaining(&response));
+
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            if let Some(sso) = sso_required(&response) {
+                return Err(sso.into());
+            }
+        }
+
         if !response.status().is_success() {
             let status = response.status();
-            let text = response.text().await.unwrap_or_default();
-            bail!("REST request failed: {} - {}", status, text);
+            let body = response.text().await.unwrap_or_default();
+            return Err(RestError { status, body }.into());
         }
 
         let result = response.json().await.context("Failed to parse JSON")?;
         Ok(result)
     }
 
+    /// Like [`rest_get`](Self::rest_get), but `None` if the resource doesn't
+    /// exist.
+    async fn rest_get_optional<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+    ) -> Result<Option<T>> {
+        match self.rest_get(path).await {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if is_not_found(&e) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Fetch every page of a REST list endpoint (100 items per page).
+    async fn rest_get_all<T: for<'de> Deserialize<'de>>(&self, path: &str) -> Result<Vec<T>> {
+        self.rest_get_pages(path, None).await
+    }
+
+    /// Like [`rest_get_all`](Self::rest_get_all), for endpoints that wrap
+    /// each page's items in an object field, e.g. `{"total_count": 2,
+    /// "secrets": [...]}`.
+    async fn rest_get_all_in<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        field: &str,
+    ) -> Result<Vec<T>> {
+        self.rest_get_pages(path, Some(field)).await
+    }
+
+    async fn rest_get_pages<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        field: Option<&str>,
+    ) -> Result<Vec<T>> {
+        let separator = if path.contains('?') { '&' } else { '?' };
+        let mut items = Vec::new();
+
+        for page in 1.. {
+            let mut response: Value = self
+                .rest_get(&format!("{}{}per_page=100&page={}", path, separator, page))
+                .await?;
+            if let Some(field) = field {
+                response = response.get_mut(field).map(Value::take).ok_or_else(|| {
+                    anyhow::anyhow!("Response from {} has no '{}' list", path, field)
+                })?;
+            }
+            let batch: Vec<T> = serde_json::from_value(response).context("Failed to parse JSON")?;
+            let done = batch.len() < 100;
+            items.extend(batch);
+            if done {
+                break;
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Execute a REST API request with an optional JSON body.
+    ///
+    /// Returns the response after checking the status, so callers can decide
+    /// whether to parse a body (some endpoints answer 204 No Content).
+    async fn rest_request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<reqwest::Response> {
+        self.rest_send(method, path, body, false).await
+    }
+
+    /// Like [`rest_request`](Self::rest_request), for writes guarded by a
+    /// caller-supplied precondition: a `409 Conflict` means it no longer
+    /// holds and is returned as [`Conflict`].
+    async fn rest_request_conditional(
+        &self,
+        method: Method,
+        path: &str,
+        body: &Value,
+    ) -> Result<reqwest::Response> {
+        self.rest_send(method, path, Some(body), true).await
+    }
+
+    async fn rest_send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+        conditional: bool,
+    ) -> Result<reqwest::Response> {
+        let url = format!("{}{}", REST_ENDPOINT, path);
+
+        let mut request = self
+            .client
+            .request(method, &url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("Failed to send REST request")?;
+
+        meta::record_rest(rate_limit_remaining(&response));
+
+        if response.status() == reqwest::StatusCode::FORBIDDEN {
+            if let Some(sso) = sso_required(&response) {
+                return Err(sso.into());
+            }
+        }
+
+        if conditional && response.status() == reqwest::StatusCode::CONFLICT {
+            let message = response.text().await.unwrap_or_default();
+            return Err(Conflict { message }.into());
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(RestError { status, body }.into());
+        }
+
+        Ok(response)
+    }
+
+    /// Execute a REST API request (POST).
+    async fn rest_post<T: for<'de> Deserialize<'de>>(&self, path: &str, body: &Value) -> Result<T> {
+        let response = self.rest_request(Method::POST, path, Some(body)).await?;
+        response.json().await.context("Failed to parse JSON")
+    }
+
+    /// Execute a REST API request (PUT) where the response body is not needed.
+    async fn rest_put(&self, path: &str, body: &Value) -> Result<()> {
+        self.rest_request(Method::PUT, path, Some(body)).await?;
+        Ok(())
+    }
+
+    /// Execute a REST API request (PATCH) where the response body is not needed.
+    async fn rest_patch(&self, path: &str, body: &Value) -> Result<()> {
+        self.rest_request(Method::PATCH, path, Some(body)).await?;
+        Ok(())
+    }
+
+    /// Execute a REST API request (DELETE).
+    async fn rest_delete(&self, path: &str) -> Result<()> {
+        self.rest_request(Method::DELETE, path, None).await?;
+        Ok(())
+    }
+
     /// Check if the client can connect to GitHub API.
     pub async fn ping(&self) -> Result<bool> {
         let query = r#"
@@ -436,6 +771,7 @@ impl GitHubClient {
                         nodes {{
                             number
                             title
+                            body
                             state
                             url
                             createdAt
@@ -452,6 +788,9 @@ impl GitHubClient {
                             comments {{
                                 totalCount
                             }}
+                            isPinned
+                            locked
+                            activeLockReason
                         }}
                     }}
                 }}
@@ -480,6 +819,7 @@ impl GitHubClient {
         struct IssueNode {
             number: i32,
             title: String,
+            body: Option<String>,
             state: String,
             url: String,
             created_at: String,
@@ -487,6 +827,11 @@ impl GitHubClient {
             author: Option<AuthorNode>,
             labels: LabelNodes,
             comments: CommentCount,
+            #[serde(default)]
+            is_pinned: bool,
+            #[serde(default)]
+            locked: bool,
+            active_lock_reason: Option<String>,
         }
 
         #[derive(Deserialize)]
@@ -528,6 +873,7 @@ impl GitHubClient {
             .map(|n| Issue {
                 number: n.number,
                 title: n.title,
+                body: n.body,
                 state: n.state,
                 url: n.url,
                 created_at: n.created_at,
@@ -535,6 +881,9 @@ impl GitHubClient {
                 author: n.author.map(|a| a.login),
                 labels: n.labels.nodes.into_iter().map(|l| l.name).collect(),
                 comment_count: n.comments.total_count,
+                is_pinned: n.is_pinned,
+                locked: n.locked,
+                lock_reason: n.active_lock_reason,
             })
             .collect();
 
@@ -557,6 +906,7 @@ impl GitHubClient {
                 subject_url: n.subject.url,
                 repo_full_name: n.repository.full_name,
                 updated_at: n.updated_at,
+                bucket: None,
             })
             .collect();
 
@@ -571,6 +921,7 @@ impl GitHubClient {
                     pullRequest(number: $number) {
                         number
                         title
+                        body
                         state
                         url
                         isDraft
@@ -591,6 +942,13 @@ impl GitHubClient {
                         comments {
                             totalCount
                         }
+                        locked
+                        activeLockReason
+                        reviewThreads(first: 100) {
+                            nodes {
+                                isResolved
+                            }
+                        }
                         reviews(first: 10) {
                             nodes {
                                 author {
@@ -643,6 +1001,7 @@ impl GitHubClient {
         struct PullRequestNode {
             number: i32,
             title: String,
+            body: Option<String>,
             state: String,
             url: String,
             is_draft: bool,
@@ -658,6 +1017,21 @@ impl GitHubClient {
             commits: CommitCount,
             comments: CommentCount,
             reviews: ReviewNodes,
+            #[serde(default)]
+            locked: bool,
+            active_lock_reason: Option<String>,
+            review_threads: ThreadNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct ThreadNodes {
+            nodes: Vec<ThreadNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ThreadNode {
+            is_resolved: bool,
         }
 
         #[derive(Deserialize)]
@@ -699,6 +1073,13 @@ impl GitHubClient {
         let result: RepoResponse = self.graphql(query, Some(variables)).await?;
         let pr = result.repository.pull_request;
 
+        let resolved_threads = pr
+            .review_threads
+            .nodes
+            .iter()
+            .filter(|t| t.is_resolved)
+            .count() as i32;
+
         let reviews = pr
             .reviews
             .nodes
@@ -713,6 +1094,7 @@ impl GitHubClient {
         Ok(PullRequest {
             number: pr.number,
             title: pr.title,
+            body: pr.body,
             state: pr.state,
             url: pr.url,
             is_draft: pr.is_draft,
@@ -728,6 +1110,10 @@ impl GitHubClient {
             commit_count: pr.commits.total_count,
             comment_count: pr.comments.total_count,
             reviews,
+            locked: pr.locked,
+            lock_reason: pr.active_lock_reason,
+            resolved_threads: Some(resolved_threads),
+            unresolved_threads: Some(pr.review_threads.nodes.len() as i32 - resolved_threads),
         })
     }
 
@@ -775,6 +1161,8 @@ impl GitHubClient {
                             comments {{
                                 totalCount
                             }}
+                            locked
+                            activeLockReason
                             reviews(first: 5) {{
                                 nodes {{
                                     author {{
@@ -828,6 +1216,9 @@ impl GitHubClient {
             commits: CommitCount,
             comments: CommentCount,
             reviews: ReviewNodes,
+            #[serde(default)]
+            locked: bool,
+            active_lock_reason: Option<String>,
         }
 
         #[derive(Deserialize)]
@@ -888,6 +1279,7 @@ impl GitHubClient {
                 PullRequest {
                     number: pr.number,
                     title: pr.title,
+                    body: None,
                     state: pr.state,
                     url: pr.url,
                     is_draft: pr.is_draft,
@@ -903,6 +1295,10 @@ impl GitHubClient {
                     commit_count: pr.commits.total_count,
                     comment_count: pr.comments.total_count,
                     reviews,
+                    locked: pr.locked,
+                    lock_reason: pr.active_lock_reason,
+                    resolved_threads: None,
+                    unresolved_threads: None,
                 }
             })
             .collect();
@@ -979,6 +1375,7 @@ impl GitHubClient {
         Ok(Issue {
             number: issue.number,
             title: issue.title,
+            body: None,
             state: issue.state,
             url: issue.url,
             created_at: issue.created_at,
@@ -986,78 +1383,5016 @@ impl GitHubClient {
             author: issue.author.map(|a| a.login),
             labels: vec![],
             comment_count: 0,
+            is_pinned: false,
+            locked: false,
+            lock_reason: None,
         })
     }
 
-    /// Get repository node ID (needed for mutations).
-    async fn get_repo_id(&self, owner: &str, repo: &str) -> Result<String> {
+    /// Get the merge queue for a branch (default branch if `branch` is None).
+    pub async fn get_merge_queue(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: Option<&str>,
+        limit: i32,
+    ) -> Result<Option<MergeQueue>> {
         let query = r#"
-            query($owner: String!, $name: String!) {
+            query($owner: String!, $name: String!, $branch: String, $first: Int!) {
                 repository(owner: $owner, name: $name) {
-                    id
+                    defaultBranchRef {
+                        name
+                    }
+                    mergeQueue(branch: $branch) {
+                        url
+                        configuration {
+                            mergeMethod
+                        }
+                        entries(first: $first) {
+                            nodes {
+                                id
+                                position
+                                state
+                                enqueuedAt
+                                estimatedTimeToMerge
+                                jump
+                                solo
+                                enqueuer {
+                                    login
+                                }
+                                pullRequest {
+                                    number
+                                    title
+                                    url
+                                }
+                            }
+                        }
+                    }
                 }
             }
         "#;
 
         #[derive(Deserialize)]
         struct RepoResponse {
-            repository: RepoId,
+            repository: RepoData,
         }
 
         #[derive(Deserialize)]
-        struct RepoId {
-            id: String,
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            default_branch_ref: Option<BranchRef>,
+            merge_queue: Option<MergeQueueNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchRef {
+            name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct MergeQueueNode {
+            url: String,
+            configuration: Option<ConfigurationNode>,
+            entries: EntryNodes,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ConfigurationNode {
+            merge_method: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct EntryNodes {
+            nodes: Vec<MergeQueueEntryNode>,
         }
 
         let variables = serde_json::json!({
             "owner": owner,
-            "name": repo
+            "name": repo,
+            "branch": branch,
+            "first": limit
         });
 
         let result: RepoResponse = self.graphql(query, Some(variables)).await?;
-        Ok(result.repository.id)
+        let data = result.repository;
+
+        let Some(queue) = data.merge_queue else {
+            return Ok(None);
+        };
+
+        let branch = match branch {
+            Some(b) => b.to_string(),
+            None => data.default_branch_ref.map(|b| b.name).unwrap_or_default(),
+        };
+
+        Ok(Some(MergeQueue {
+            branch,
+            url: queue.url,
+            merge_method: queue.configuration.and_then(|c| c.merge_method),
+            entries: queue
+                .entries
+                .nodes
+                .into_iter()
+                .filter_map(MergeQueueEntryNode::into_entry)
+                .collect(),
+        }))
     }
-}
 
-/// GraphQL request body.
-#[derive(Serialize)]
-struct GraphQLRequest {
-    query: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    variables: Option<Value>,
-}
+    /// Add a pull request to its base branch's merge queue.
+    pub async fn enqueue_pr(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: i32,
+        jump: bool,
+    ) -> Result<MergeQueueEntry> {
+        let query = r#"
+            mutation($pullRequestId: ID!, $jump: Boolean) {
+                enqueuePullRequest(input: {pullRequestId: $pullRequestId, jump: $jump}) {
+                    mergeQueueEntry {
+                        id
+                        position
+                        state
+                        enqueuedAt
+                        estimatedTimeToMerge
+                        jump
+                        solo
+                        enqueuer {
+                            login
+                        }
+                        pullRequest {
+                            number
+                            title
+                            url
+                        }
+                    }
+                }
+            }
+        "#;
 
-/// Raw notification from REST API.
-#[derive(Deserialize)]
-struct NotificationRaw {
-    id: String,
-    unread: bool,
-    reason: String,
-    subject: NotificationSubject,
-    repository: NotificationRepo,
-    updated_at: String,
-}
+        let pr_id = self.get_pr_id(owner, repo, pr_number).await?;
 
-#[derive(Deserialize)]
-struct NotificationSubject {
-    title: String,
-    #[serde(rename = "type")]
-    type_field: String,
-    url: Option<String>,
-}
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct EnqueueResponse {
+            enqueue_pull_request: EnqueueData,
+        }
 
-#[derive(Deserialize)]
-struct NotificationRepo {
-    full_name: String,
-}
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct EnqueueData {
+            merge_queue_entry: Option<MergeQueueEntryNode>,
+        }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let variables = serde_json::json!({
+            "pullRequestId": pr_id,
+            "jump": jump
+        });
 
-    #[test]
-    fn test_gh_config_path() {
-        let path = GitHubClient::gh_config_path().unwrap();
-        assert!(path.to_string_lossy().contains("gh/hosts.yml"));
+        let result: EnqueueResponse = self.graphql(query, Some(variables)).await?;
+
+        result
+            .enqueue_pull_request
+            .merge_queue_entry
+            .and_then(MergeQueueEntryNode::into_entry)
+            .context("GitHub did not return a merge queue entry")
+    }
+
+    /// Remove a pull request from the merge queue.
+    pub async fn dequeue_pr(&self, owner: &str, repo: &str, pr_number: i32) -> Result<()> {
+        let query = r#"
+            mutation($id: ID!) {
+                dequeuePullRequest(input: {id: $id}) {
+                    clientMutationId
+                }
+            }
+        "#;
+
+        let pr_id = self.get_pr_id(owner, repo, pr_number).await?;
+
+        let variables = serde_json::json!({ "id": pr_id });
+        let _: Value = self.graphql(query, Some(variables)).await?;
+        Ok(())
+    }
+
+    /// Download a repository archive (tarball or zipball) to `dest`.
+    ///
+    /// GitHub answers with a redirect to codeload.github.com, which reqwest
+    /// follows. The body is streamed to disk chunk by chunk. Returns bytes written.
+    pub async fn download_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: Option<&str>,
+        format: &str,
+        dest: &Path,
+    ) -> Result<u64> {
+        let mut url = format!("{}/repos/{}/{}/{}", REST_ENDPOINT, owner, repo, format);
+        if let Some(r) = git_ref {
+            url.push('/');
+            url.push_str(r);
+        }
+
+        let mut response = self
+            .client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", self.token))
+            .header("Accept", "application/vnd.github+json")
+            .header("X-GitHub-Api-Version", "2022-11-28")
+            .timeout(std::time::Duration::from_secs(300))
+            .send()
+            .await
+            .context("Failed to send archive request")?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            bail!("Archive download failed: {} - {}", status, text);
+        }
+
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent).context("Failed to create destination directory")?;
+        }
+
+        // Stream into `<dest>.part` and rename once complete, so a failed
+        // download never leaves a truncated archive at `dest`
+        let mut part = dest.as_os_str().to_owned();
+        part.push(".part");
+        let part = PathBuf::from(part);
+
+        let written = async {
+            let mut file = std::fs::File::create(&part)
+                .with_context(|| format!("Failed to create {}", part.display()))?;
+            let mut size: u64 = 0;
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .context("Failed to read archive stream")?
+            {
+                file.write_all(&chunk)
+                    .with_context(|| format!("Failed to write {}", part.display()))?;
+                size += chunk.len() as u64;
+            }
+            file.flush().context("Failed to flush archive file")?;
+            anyhow::Ok(size)
+        }
+        .await;
+
+        let size = match written {
+            Ok(size) => size,
+            Err(e) => {
+                let _ = std::fs::remove_file(&part);
+                return Err(e);
+            }
+        };
+        if let Err(e) = std::fs::rename(&part, dest) {
+            let _ = std::fs::remove_file(&part);
+            return Err(e).with_context(|| format!("Failed to move archive to {}", dest.display()));
+        }
+
+        Ok(size)
+    }
+
+    /// List the authenticated user's codespaces.
+    pub async fn list_codespaces(&self) -> Result<Vec<Codespace>> {
+        #[derive(Deserialize)]
+        struct CodespaceList {
+            codespaces: Vec<CodespaceRaw>,
+        }
+
+        let result: CodespaceList = self.rest_get("/user/codespaces?per_page=100").await?;
+        Ok(result.codespaces.into_iter().map(Codespace::from).collect())
+    }
+
+    /// Start a codespace by name.
+    pub async fn start_codespace(&self, name: &str) -> Result<Codespace> {
+        let path = format!("/user/codespaces/{}/start", name);
+        let raw: CodespaceRaw = self.rest_post(&path, &serde_json::json!({})).await?;
+        Ok(raw.into())
+    }
+
+    /// Stop a codespace by name.
+    pub async fn stop_codespace(&self, name: &str) -> Result<Codespace> {
+        let path = format!("/user/codespaces/{}/stop", name);
+        let raw: CodespaceRaw = self.rest_post(&path, &serde_json::json!({})).await?;
+        Ok(raw.into())
+    }
+
+    /// List the authenticated user's SSH keys.
+    pub async fn list_ssh_keys(&self) -> Result<Vec<SshKey>> {
+        let keys: Vec<SshKeyRaw> = self.rest_get("/user/keys?per_page=100").await?;
+        Ok(keys.into_iter().map(SshKey::from).collect())
+    }
+
+    /// Add an SSH public key to the authenticated user's account.
+    pub async fn add_ssh_key(&self, title: &str, key: &str) -> Result<SshKey> {
+        let body = serde_json::json!({ "title": title, "key": key });
+        let raw: SshKeyRaw = self.rest_post("/user/keys", &body).await?;
+        Ok(raw.into())
+    }
+
+    /// Remove an SSH key by ID.
+    pub async fn remove_ssh_key(&self, key_id: i64) -> Result<()> {
+        self.rest_delete(&format!("/user/keys/{}", key_id)).await
+    }
+
+    /// List the authenticated user's GPG keys.
+    pub async fn list_gpg_keys(&self) -> Result<Vec<GpgKey>> {
+        let keys: Vec<GpgKeyRaw> = self.rest_get("/user/gpg_keys?per_page=100").await?;
+        Ok(keys.into_iter().map(GpgKey::from).collect())
+    }
+
+    /// Add an ASCII-armored GPG public key to the authenticated user's account.
+    pub async fn add_gpg_key(
+        &self,
+        name: Option<&str>,
+        armored_public_key: &str,
+    ) -> Result<GpgKey> {
+        let body = serde_json::json!({
+            "name": name,
+            "armored_public_key": armored_public_key
+        });
+        let raw: GpgKeyRaw = self.rest_post("/user/gpg_keys", &body).await?;
+        Ok(raw.into())
+    }
+
+    /// Remove a GPG key by ID.
+    pub async fn remove_gpg_key(&self, key_id: i64) -> Result<()> {
+        self.rest_delete(&format!("/user/gpg_keys/{}", key_id))
+            .await
+    }
+
+    /// List Actions secret names and timestamps (values are never returned).
+    pub async fn list_secrets(&self, scope: &ActionsScope) -> Result<Vec<ActionsSecret>> {
+        self.rest_get_all_in(&format!("{}/secrets", scope.base_path()), "secrets")
+            .await
+    }
+
+    /// Create or update an Actions secret.
+    ///
+    /// The value is encrypted client-side with the scope's public key
+    /// (libsodium sealed box) so it never leaves the daemon in plaintext.
+    /// `visibility` only applies to org secrets ("all", "private", "selected");
+    /// without it an existing org secret keeps its visibility and repository
+    /// list, and a new one is private.
+    pub async fn set_secret(
+        &self,
+        scope: &ActionsScope,
+        name: &str,
+        value: &str,
+        visibility: Option<&str>,
+        selected_repository_ids: Option<&[i64]>,
+    ) -> Result<()> {
+        #[derive(Deserialize)]
+        struct PublicKey {
+            key_id: String,
+            key: String,
+        }
+
+        let base = scope.base_path();
+        let public_key: PublicKey = self
+            .rest_get(&format!("{}/secrets/public-key", base))
+            .await?;
+        let encrypted_value = seal_secret(&public_key.key, value)?;
+
+        let mut body = serde_json::json!({
+            "encrypted_value": encrypted_value,
+            "key_id": public_key.key_id,
+        });
+        let path = format!("{}/secrets/{}", base, name);
+        if scope.is_org() {
+            let current: Option<OrgVisibilityRaw> = match visibility {
+                Some(_) => None,
+                None => self.rest_get_optional(&path).await?,
+            };
+            let (visibility, selected) = self
+                .org_access(
+                    &path,
+                    current.and_then(|c| c.visibility),
+                    visibility,
+                    selected_repository_ids,
+                )
+                .await?;
+            body["visibility"] = Value::from(visibility);
+            if let Some(ids) = selected {
+                body["selected_repository_ids"] = serde_json::json!(ids);
+            }
+        }
+
+        self.rest_put(&path, &body).await
+    }
+
+    /// Visibility and selected repository IDs to send when writing the org
+    /// secret or variable at `path`, whose current visibility is `current`
+    /// (`None` if it doesn't exist). An explicit `visibility` wins; otherwise
+    /// an existing one keeps its settings and a new one is private.
+    async fn org_access(
+        &self,
+        path: &str,
+        current: Option<String>,
+        visibility: Option<&str>,
+        selected_repository_ids: Option<&[i64]>,
+    ) -> Result<(String, Option<Vec<i64>>)> {
+        #[derive(Deserialize)]
+        struct RepoId {
+            id: i64,
+        }
+
+        let selected = selected_repository_ids.map(<[i64]>::to_vec);
+        let visibility = match (visibility, current) {
+            (Some(visibility), _) => return Ok((visibility.to_string(), selected)),
+            (None, Some(current)) => current,
+            (None, None) => return Ok(("private".to_string(), selected)),
+        };
+        if visibility != "selected" || selected.is_some() {
+            return Ok((visibility, selected));
+        }
+
+        let repos: Vec<RepoId> = self
+            .rest_get_all_in(&format!("{}/repositories", path), "repositories")
+            .await?;
+        Ok((visibility, Some(repos.into_iter().map(|r| r.id).collect())))
+    }
+
+    /// Delete an Actions secret.
+    pub async fn delete_secret(&self, scope: &ActionsScope, name: &str) -> Result<()> {
+        self.rest_delete(&format!("{}/secrets/{}", scope.base_path(), name))
+            .await
+    }
+
+    /// List Actions variables with their values.
+    pub async fn list_variables(&self, scope: &ActionsScope) -> Result<Vec<ActionsVariable>> {
+        self.rest_get_all_in(&format!("{}/variables", scope.base_path()), "variables")
+            .await
+    }
+
+    /// Create or update an Actions variable. Returns true if it was created.
+    ///
+    /// As with [`set_secret`](Self::set_secret), an existing org variable
+    /// keeps its visibility unless `visibility` is given.
+    pub async fn set_variable(
+        &self,
+        scope: &ActionsScope,
+        name: &str,
+        value: &str,
+        visibility: Option<&str>,
+        selected_repository_ids: Option<&[i64]>,
+    ) -> Result<bool> {
+        let base = scope.base_path();
+        let path = format!("{}/variables/{}", base, name);
+
+        // Variables have separate create/update endpoints
+        let current: Option<OrgVisibilityRaw> = self.rest_get_optional(&path).await?;
+        let exists = current.is_some();
+
+        let mut body = serde_json::json!({
+            "name": name,
+            "value": value,
+        });
+        if scope.is_org() {
+            let (visibility, selected) = self
+                .org_access(
+                    &path,
+                    current.and_then(|c| c.visibility),
+                    visibility,
+                    selected_repository_ids,
+                )
+                .await?;
+            body["visibility"] = Value::from(visibility);
+            if let Some(ids) = selected {
+                body["selected_repository_ids"] = serde_json::json!(ids);
+            }
+        }
+
+        if exists {
+            self.rest_patch(&path, &body).await?;
+        } else {
+            self.rest_request(Method::POST, &format!("{}/variables", base), Some(&body))
+                .await?;
+        }
+        Ok(!exists)
+    }
+
+    /// Delete an Actions variable.
+    pub async fn delete_variable(&self, scope: &ActionsScope, name: &str) -> Result<()> {
+        self.rest_delete(&format!("{}/variables/{}", scope.base_path(), name))
+            .await
+    }
+
+    /// List a repository's Actions caches, most recently used first.
+    ///
+    /// `key` matches as a prefix, as the API does. Stops after `limit` entries.
+    pub async fn list_actions_caches(
+        &self,
+        owner: &str,
+        repo: &str,
+        key: Option<&str>,
+        git_ref: Option<&str>,
+        limit: usize,
+    ) -> Result<(Vec<ActionsCache>, i64)> {
+        #[derive(Deserialize)]
+        struct CacheList {
+            total_count: i64,
+            actions_caches: Vec<ActionsCache>,
+        }
+
+        let mut base = format!(
+            "/repos/{}/{}/actions/caches?sort=last_accessed_at&direction=desc&per_page=100",
+            owner, repo
+        );
+        if let Some(key) = key {
+            base.push_str(&format!("&key={}", query_escape(key)));
+        }
+        if let Some(git_ref) = git_ref {
+            base.push_str(&format!("&ref={}", query_escape(git_ref)));
+        }
+
+        let mut caches = Vec::new();
+        let mut total_count = 0;
+        for page in 1.. {
+            let batch: CacheList = self.rest_get(&format!("{}&page={}", base, page)).await?;
+            total_count = batch.total_count;
+            let done = batch.actions_caches.len() < 100;
+            caches.extend(batch.actions_caches);
+            if done || caches.len() >= limit {
+                break;
+            }
+        }
+        caches.truncate(limit);
+
+        Ok((caches, total_count))
+    }
+
+    /// Delete an Actions cache entry by ID.
+    pub async fn delete_actions_cache(&self, owner: &str, repo: &str, id: i64) -> Result<()> {
+        self.rest_delete(&format!("/repos/{}/{}/actions/caches/{}", owner, repo, id))
+            .await
+    }
+
+    /// List jobs for a workflow run, with per-step timings.
+    ///
+    /// `filter` is "latest" (most recent attempt only) or "all".
+    pub async fn list_run_jobs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        filter: &str,
+    ) -> Result<Vec<WorkflowJob>> {
+        #[derive(Deserialize)]
+        struct JobList {
+            jobs: Vec<WorkflowJobRaw>,
+        }
+
+        let path = format!(
+            "/repos/{}/{}/actions/runs/{}/jobs?filter={}&per_page=100",
+            owner, repo, run_id, filter
+        );
+        let result: JobList = self.rest_get(&path).await?;
+        Ok(result.jobs.into_iter().map(WorkflowJob::from).collect())
+    }
+
+    /// Get GitHub's own service status from githubstatus.com.
+    ///
+    /// This is a public Statuspage endpoint, so no token is sent.
+    pub async fn get_service_status(&self) -> Result<ServiceStatus> {
+        #[derive(Deserialize)]
+        struct Summary {
+            page: SummaryPage,
+            status: SummaryStatus,
+            #[serde(default)]
+            components: Vec<SummaryComponent>,
+            #[serde(default)]
+            incidents: Vec<SummaryIncident>,
+        }
+
+        #[derive(Deserialize)]
+        struct SummaryPage {
+            updated_at: String,
+        }
+
+        #[derive(Deserialize)]
+        struct SummaryStatus {
+            indicator: String,
+            description: String,
+        }
+
+        #[derive(Deserialize)]
+        struct SummaryComponent {
+            name: String,
+            status: String,
+            #[serde(default)]
+            group: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct SummaryIncident {
+            name: String,
+            status: String,
+            impact: String,
+            shortlink: Option<String>,
+            created_at: String,
+        }
+
+        let response = self
+            .client
+            .get(STATUS_ENDPOINT)
+            .timeout(std::time::Duration::from_secs(5))
+            .send()
+            .await
+            .context("Failed to reach githubstatus.com")?;
+
+        if !response.status().is_success() {
+            bail!("githubstatus.com returned {}", response.status());
+        }
+
+        let summary: Summary = response
+            .json()
+            .await
+            .context("Failed to parse githubstatus.com summary")?;
+
+        Ok(ServiceStatus {
+            indicator: summary.status.indicator,
+            description: summary.status.description,
+            updated_at: summary.page.updated_at,
+            components: summary
+                .components
+                .into_iter()
+                // The summary includes a pseudo-component linking to the site
+                .filter(|c| !c.group && !c.name.starts_with("Visit "))
+                .map(|c| ServiceComponent {
+                    name: c.name,
+                    status: c.status,
+                })
+                .collect(),
+            incidents: summary
+                .incidents
+                .into_iter()
+                .map(|i| StatusIncident {
+                    name: i.name,
+                    status: i.status,
+                    impact: i.impact,
+                    url: i.shortlink,
+                    created_at: i.created_at,
+                })
+                .collect(),
+        })
+    }
+
+    /// Transfer an issue to another repository (same owner or org).
+    ///
+    /// Returns the issue as it exists in the target repository.
+    pub async fn transfer_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        target_owner: &str,
+        target_repo: &str,
+        create_labels: bool,
+    ) -> Result<Issue> {
+        let query = r#"
+            mutation($issueId: ID!, $repositoryId: ID!, $createLabels: Boolean) {
+                transferIssue(input: {issueId: $issueId, repositoryId: $repositoryId, createLabelsIfMissing: $createLabels}) {
+                    issue {
+                        number
+                        title
+                        state
+                        url
+                        createdAt
+                        updatedAt
+                        author {
+                            login
+                        }
+                        labels(first: 10) {
+                            nodes {
+                                name
+                            }
+                        }
+                        comments {
+                            totalCount
+                        }
+                        isPinned
+                        locked
+                        activeLockReason
+                    }
+                }
+            }
+        "#;
+
+        let issue_id = self.get_issue_id(owner, repo, number).await?;
+        let repo_id = self.get_repo_id(target_owner, target_repo).await?;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TransferResponse {
+            transfer_issue: TransferData,
+        }
+
+        #[derive(Deserialize)]
+        struct TransferData {
+            issue: IssueNode,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct IssueNode {
+            number: i32,
+            title: String,
+            state: String,
+            url: String,
+            created_at: String,
+            updated_at: String,
+            author: Option<AuthorNode>,
+            labels: LabelNodes,
+            comments: CommentCount,
+            #[serde(default)]
+            is_pinned: bool,
+            #[serde(default)]
+            locked: bool,
+            active_lock_reason: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            login: String,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNodes {
+            nodes: Vec<LabelNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNode {
+            name: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommentCount {
+            total_count: i32,
+        }
+
+        let variables = serde_json::json!({
+            "issueId": issue_id,
+            "repositoryId": repo_id,
+            "createLabels": create_labels
+        });
+
+        let result: TransferResponse = self.graphql(query, Some(variables)).await?;
+        let issue = result.transfer_issue.issue;
+
+        Ok(Issue {
+            number: issue.number,
+            title: issue.title,
+            body: None,
+            state: issue.state,
+            url: issue.url,
+            created_at: issue.created_at,
+            updated_at: issue.updated_at,
+            author: issue.author.map(|a| a.login),
+            labels: issue.labels.nodes.into_iter().map(|l| l.name).collect(),
+            comment_count: issue.comments.total_count,
+            is_pinned: issue.is_pinned,
+            locked: issue.locked,
+            lock_reason: issue.active_lock_reason,
+        })
+    }
+
+    /// Pin or unpin an issue on the repository's issue list.
+    pub async fn set_issue_pinned(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        pinned: bool,
+    ) -> Result<()> {
+        let query = if pinned {
+            r#"
+            mutation($issueId: ID!) {
+                pinIssue(input: {issueId: $issueId}) {
+                    clientMutationId
+                }
+            }
+        "#
+        } else {
+            r#"
+            mutation($issueId: ID!) {
+                unpinIssue(input: {issueId: $issueId}) {
+                    clientMutationId
+                }
+            }
+        "#
+        };
+
+        let issue_id = self.get_issue_id(owner, repo, number).await?;
+
+        let variables = serde_json::json!({ "issueId": issue_id });
+        let _: Value = self.graphql(query, Some(variables)).await?;
+        Ok(())
+    }
+
+    /// Lock the conversation on an issue or pull request.
+    ///
+    /// `reason` is one of OFF_TOPIC, TOO_HEATED, RESOLVED, SPAM (or None).
+    pub async fn lock_conversation(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let query = r#"
+            mutation($lockableId: ID!, $lockReason: LockReason) {
+                lockLockable(input: {lockableId: $lockableId, lockReason: $lockReason}) {
+                    clientMutationId
+                }
+            }
+        "#;
+
+        let lockable_id = self.get_issue_or_pr_id(owner, repo, number).await?;
+
+        let variables = serde_json::json!({
+            "lockableId": lockable_id,
+            "lockReason": reason
+        });
+        let _: Value = self.graphql(query, Some(variables)).await?;
+        Ok(())
+    }
+
+    /// Unlock the conversation on an issue or pull request.
+    pub async fn unlock_conversation(&self, owner: &str, repo: &str, number: i32) -> Result<()> {
+        let query = r#"
+            mutation($lockableId: ID!) {
+                unlockLockable(input: {lockableId: $lockableId}) {
+                    clientMutationId
+                }
+            }
+        "#;
+
+        let lockable_id = self.get_issue_or_pr_id(owner, repo, number).await?;
+
+        let variables = serde_json::json!({ "lockableId": lockable_id });
+        let _: Value = self.graphql(query, Some(variables)).await?;
+        Ok(())
+    }
+
+    /// List pending repository invitations for the authenticated user.
+    pub async fn list_invitations(&self) -> Result<Vec<RepoInvitation>> {
+        #[derive(Deserialize)]
+        struct InvitationRaw {
+            id: i64,
+            repository: InvitationRepo,
+            inviter: Option<InvitationUser>,
+            permissions: String,
+            created_at: String,
+            #[serde(default)]
+            expired: bool,
+            html_url: String,
+        }
+
+        #[derive(Deserialize)]
+        struct InvitationRepo {
+            full_name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct InvitationUser {
+            login: String,
+        }
+
+        let raw: Vec<InvitationRaw> = self
+            .rest_get("/user/repository_invitations?per_page=100")
+            .await?;
+
+        Ok(raw
+            .into_iter()
+            .map(|i| RepoInvitation {
+                id: i.id,
+                repo_full_name: i.repository.full_name,
+                inviter: i.inviter.map(|u| u.login),
+                permissions: i.permissions,
+                created_at: i.created_at,
+                expired: i.expired,
+                url: i.html_url,
+            })
+            .collect())
+    }
+
+    /// Accept a repository invitation.
+    pub async fn accept_invitation(&self, invitation_id: i64) -> Result<()> {
+        let path = format!("/user/repository_invitations/{}", invitation_id);
+        self.rest_request(Method::PATCH, &path, None).await?;
+        Ok(())
+    }
+
+    /// Decline a repository invitation.
+    pub async fn decline_invitation(&self, invitation_id: i64) -> Result<()> {
+        self.rest_delete(&format!("/user/repository_invitations/{}", invitation_id))
+            .await
+    }
+
+    /// Get the commit SHA at the tip of a branch.
+    pub async fn get_branch_head(&self, owner: &str, repo: &str, branch: &str) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!, $qualifiedName: String!) {
+                repository(owner: $owner, name: $name) {
+                    ref(qualifiedName: $qualifiedName) {
+                        target {
+                            oid
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            #[serde(rename = "ref")]
+            git_ref: Option<RefNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct RefNode {
+            target: TargetNode,
+        }
+
+        #[derive(Deserialize)]
+        struct TargetNode {
+            oid: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "qualifiedName": format!("refs/heads/{}", branch)
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        result
+            .repository
+            .git_ref
+            .map(|r| r.target.oid)
+            .with_context(|| format!("Branch '{}' not found in {}/{}", branch, owner, repo))
+    }
+
+    /// Create a single commit on a branch with multiple file additions and
+    /// deletions via `createCommitOnBranch`.
+    ///
+    /// Commits made this way are signed by GitHub and show as verified.
+    /// If `expected_head` is None the current branch tip is used, so the
+    /// commit still fails cleanly if the branch moves mid-call.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        headline: &str,
+        body: Option<&str>,
+        additions: &[(String, Vec<u8>)],
+        deletions: &[String],
+        expected_head: Option<&str>,
+    ) -> Result<CreatedCommit> {
+        let query = r#"
+            mutation($input: CreateCommitOnBranchInput!) {
+                createCommitOnBranch(input: $input) {
+                    commit {
+                        oid
+                        url
+                        committedDate
+                        signature {
+                            __typename
+                            state
+                            wasSignedByGitHub
+                            signer {
+                                login
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let expected_head = match expected_head {
+            Some(oid) => oid.to_string(),
+            None => self.get_branch_head(owner, repo, branch).await?,
+        };
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitResponse {
+            create_commit_on_branch: CommitData,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitData {
+            commit: CommitNode,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitNode {
+            oid: String,
+            url: String,
+            committed_date: Option<String>,
+            signature: Option<SignatureRaw>,
+        }
+
+        let encoded_additions: Vec<Value> = additions
+            .iter()
+            .map(|(path, contents)| {
+                serde_json::json!({
+                    "path": path,
+                    "contents": base64::engine::general_purpose::STANDARD.encode(contents)
+                })
+            })
+            .collect();
+        let encoded_deletions: Vec<Value> = deletions
+            .iter()
+            .map(|path| serde_json::json!({ "path": path }))
+            .collect();
+
+        let variables = serde_json::json!({
+            "input": {
+                "branch": {
+                    "repositoryNameWithOwner": format!("{}/{}", owner, repo),
+                    "branchName": branch
+                },
+                "message": {
+                    "headline": headline,
+                    "body": body
+                },
+                "fileChanges": {
+                    "additions": encoded_additions,
+                    "deletions": encoded_deletions
+                },
+                "expectedHeadOid": expected_head
+            }
+        });
+
+        let result: CommitResponse = self.graphql(query, Some(variables)).await?;
+        let commit = result.create_commit_on_branch.commit;
+
+        Ok(CreatedCommit {
+            oid: commit.oid,
+            url: commit.url,
+            branch: branch.to_string(),
+            committed_date: commit.committed_date,
+            additions: additions.len(),
+            deletions: deletions.len(),
+            signature: commit_signature(commit.signature),
+        })
+    }
+
+    /// List review threads on a pull request.
+    pub async fn list_review_threads(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: i32,
+    ) -> Result<Vec<ReviewThread>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    pullRequest(number: $number) {
+                        reviewThreads(first: 100) {
+                            nodes {
+                                id
+                                isResolved
+                                isOutdated
+                                path
+                                line
+                                resolvedBy {
+                                    login
+                                }
+                                comments(first: 1) {
+                                    totalCount
+                                    nodes {
+                                        body
+                                        author {
+                                            login
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            pull_request: PullRequestNode,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PullRequestNode {
+            review_threads: ThreadNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct ThreadNodes {
+            nodes: Vec<ThreadNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ThreadNode {
+            id: String,
+            is_resolved: bool,
+            is_outdated: bool,
+            path: Option<String>,
+            line: Option<i32>,
+            resolved_by: Option<AuthorNode>,
+            comments: CommentNodes,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommentNodes {
+            total_count: i32,
+            nodes: Vec<CommentNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct CommentNode {
+            body: String,
+            author: Option<AuthorNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            login: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": pr_number
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+
+        let threads = result
+            .repository
+            .pull_request
+            .review_threads
+            .nodes
+            .into_iter()
+            .map(|t| {
+                let first = t.comments.nodes.into_iter().next();
+                ReviewThread {
+                    id: t.id,
+                    is_resolved: t.is_resolved,
+                    is_outdated: t.is_outdated,
+                    path: t.path,
+                    line: t.line,
+                    resolved_by: t.resolved_by.map(|a| a.login),
+                    comment_count: t.comments.total_count,
+                    author: first
+                        .as_ref()
+                        .and_then(|c| c.author.as_ref().map(|a| a.login.clone())),
+                    first_comment: first.map(|c| c.body),
+                }
+            })
+            .collect();
+
+        Ok(threads)
+    }
+
+    /// Resolve or unresolve a PR review thread by node ID.
+    pub async fn set_thread_resolved(&self, thread_id: &str, resolved: bool) -> Result<bool> {
+        let query = if resolved {
+            r#"
+            mutation($threadId: ID!) {
+                resolveReviewThread(input: {threadId: $threadId}) {
+                    thread {
+                        isResolved
+                    }
+                }
+            }
+        "#
+        } else {
+            r#"
+            mutation($threadId: ID!) {
+                unresolveReviewThread(input: {threadId: $threadId}) {
+                    thread {
+                        isResolved
+                    }
+                }
+            }
+        "#
+        };
+
+        let variables = serde_json::json!({ "threadId": thread_id });
+        let result: Value = self.graphql(query, Some(variables)).await?;
+
+        let field = if resolved {
+            "resolveReviewThread"
+        } else {
+            "unresolveReviewThread"
+        };
+        result
+            .get(field)
+            .and_then(|d| d.get("thread"))
+            .and_then(|t| t.get("isResolved"))
+            .and_then(|v| v.as_bool())
+            .context("GitHub did not return the updated thread")
+    }
+
+    /// List forks of a repository.
+    ///
+    /// `order_by` is a GraphQL `RepositoryOrderField` (e.g. UPDATED_AT, STARGAZERS).
+    pub async fn list_forks(
+        &self,
+        owner: &str,
+        repo: &str,
+        order_by: &str,
+        limit: i32,
+    ) -> Result<Vec<Repository>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $first: Int!, $field: RepositoryOrderField!) {
+                repository(owner: $owner, name: $name) {
+                    forks(first: $first, orderBy: {field: $field, direction: DESC}) {
+                        nodes {
+                            name
+                            nameWithOwner
+                            description
+                            url
+                            isPrivate
+                            isFork
+                            stargazerCount
+                            forkCount
+                            primaryLanguage {
+                                name
+                            }
+                            updatedAt
+                            pushedAt
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            forks: RepoNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoNodes {
+            nodes: Vec<RepoNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoNode {
+            name: String,
+            name_with_owner: String,
+            description: Option<String>,
+            url: String,
+            is_private: bool,
+            is_fork: bool,
+            stargazer_count: i32,
+            fork_count: i32,
+            primary_language: Option<LanguageNode>,
+            updated_at: String,
+            pushed_at: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct LanguageNode {
+            name: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "first": limit,
+            "field": order_by
+        });
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+
+        let forks = result
+            .repository
+            .forks
+            .nodes
+            .into_iter()
+            .map(|n| Repository {
+                name: n.name,
+                full_name: n.name_with_owner,
+                description: n.description,
+                url: n.url,
+                is_private: n.is_private,
+                is_fork: n.is_fork,
+                stars: n.stargazer_count,
+                forks: n.fork_count,
+                language: n.primary_language.map(|l| l.name),
+                updated_at: n.updated_at,
+                pushed_at: n.pushed_at,
+            })
+            .collect();
+
+        Ok(forks)
+    }
+
+    /// Sync a fork branch with the upstream repository (merge-upstream).
+    pub async fn sync_fork(&self, owner: &str, repo: &str, branch: &str) -> Result<ForkSyncResult> {
+        self.rest_post(
+            &format!("/repos/{}/{}/merge-upstream", owner, repo),
+            &serde_json::json!({ "branch": branch }),
+        )
+        .await
+    }
+
+    /// Get diff statistics and changed files for a pull request.
+    pub async fn get_pr_files(&self, owner: &str, repo: &str, pr_number: i32) -> Result<PrFiles> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    pullRequest(number: $number) {
+                        baseRefName
+                        additions
+                        deletions
+                        changedFiles
+                        files(first: 100) {
+                            nodes {
+                                path
+                                additions
+                                deletions
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            pull_request: PullRequestNode,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PullRequestNode {
+            base_ref_name: String,
+            additions: i32,
+            deletions: i32,
+            changed_files: i32,
+            files: Option<FileNodes>,
+        }
+
+        #[derive(Deserialize)]
+        struct FileNodes {
+            nodes: Vec<PrFile>,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": pr_number
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let pr = result.repository.pull_request;
+
+        Ok(PrFiles {
+            base_ref: pr.base_ref_name,
+            additions: pr.additions,
+            deletions: pr.deletions,
+            changed_files: pr.changed_files,
+            files: pr.files.map(|f| f.nodes).unwrap_or_default(),
+        })
+    }
+
+    /// Fetch the repository's CODEOWNERS file at `git_ref`, if any.
+    pub async fn get_codeowners(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: &str,
+    ) -> Result<Option<String>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $github: String!, $root: String!, $docs: String!) {
+                repository(owner: $owner, name: $name) {
+                    github: object(expression: $github) {
+                        ... on Blob { text }
+                    }
+                    root: object(expression: $root) {
+                        ... on Blob { text }
+                    }
+                    docs: object(expression: $docs) {
+                        ... on Blob { text }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            github: Option<BlobNode>,
+            root: Option<BlobNode>,
+            docs: Option<BlobNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct BlobNode {
+            text: Option<String>,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "github": format!("{}:.github/CODEOWNERS", git_ref),
+            "root": format!("{}:CODEOWNERS", git_ref),
+            "docs": format!("{}:docs/CODEOWNERS", git_ref),
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let data = result.repository;
+
+        // Same precedence as GitHub: .github/, then root, then docs/
+        Ok([data.github, data.root, data.docs]
+            .into_iter()
+            .flatten()
+            .find_map(|blob| blob.text))
+    }
+
+    /// Get closing-reference links for an issue or pull request.
+    ///
+    /// For a PR this returns the issues it closes; for an issue, the PRs
+    /// (open or closed) that reference it with a closing keyword.
+    pub async fn get_links(&self, owner: &str, repo: &str, number: i32) -> Result<ItemLinks> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    issueOrPullRequest(number: $number) {
+                        __typename
+                        ... on PullRequest {
+                            closingIssuesReferences(first: 50) {
+                                nodes {
+                                    number
+                                    title
+                                    state
+                                    url
+                                    repository {
+                                        nameWithOwner
+                                    }
+                                }
+                            }
+                        }
+                        ... on Issue {
+                            closedByPullRequestsReferences(first: 50, includeClosedPrs: true) {
+                                nodes {
+                                    number
+                                    title
+                                    state
+                                    url
+                                    repository {
+                                        nameWithOwner
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            issue_or_pull_request: Option<ItemNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ItemNode {
+            #[serde(rename = "__typename")]
+            typename: String,
+            closing_issues_references: Option<LinkNodes>,
+            closed_by_pull_requests_references: Option<LinkNodes>,
+        }
+
+        #[derive(Deserialize)]
+        struct LinkNodes {
+            nodes: Vec<LinkNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct LinkNode {
+            number: i32,
+            title: String,
+            state: String,
+            url: String,
+            repository: RepoName,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoName {
+            name_with_owner: String,
+        }
+
+        fn to_items(links: Option<LinkNodes>) -> Vec<LinkedItem> {
+            links
+                .map(|l| l.nodes)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|n| LinkedItem {
+                    repo: n.repository.name_with_owner,
+                    number: n.number,
+                    title: n.title,
+                    state: n.state,
+                    url: n.url,
+                })
+                .collect()
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": number
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let item = result
+            .repository
+            .issue_or_pull_request
+            .with_context(|| format!("Issue or PR #{} not found", number))?;
+
+        Ok(ItemLinks {
+            kind: if item.typename == "PullRequest" {
+                "pull_request".to_string()
+            } else {
+                "issue".to_string()
+            },
+            closes: to_items(item.closing_issues_references),
+            closed_by: to_items(item.closed_by_pull_requests_references),
+        })
+    }
+
+    /// List commit SHAs between two refs (compare API, at most 250 commits).
+    ///
+    /// Returns the SHAs and the total commit count reported by GitHub.
+    pub async fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: &str,
+        head: &str,
+    ) -> Result<(Vec<String>, i32)> {
+        #[derive(Deserialize)]
+        struct CompareRaw {
+            total_commits: i32,
+            commits: Vec<CommitRaw>,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitRaw {
+            sha: String,
+        }
+
+        let compare: CompareRaw = self
+            .rest_get(&format!(
+                "/repos/{}/{}/compare/{}...{}?per_page=250",
+                owner, repo, base, head
+            ))
+            .await?;
+
+        Ok((
+            compare.commits.into_iter().map(|c| c.sha).collect(),
+            compare.total_commits,
+        ))
+    }
+
+    /// Find the merged PRs associated with a set of commits.
+    ///
+    /// Returns the PRs (deduplicated, in commit order) and how many commits
+    /// had no merged PR.
+    pub async fn merged_prs_for_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        shas: &[String],
+    ) -> Result<(Vec<ReleasePr>, i32)> {
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: HashMap<String, Option<CommitNode>>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitNode {
+            associated_pull_requests: Option<PrNodes>,
+        }
+
+        #[derive(Deserialize)]
+        struct PrNodes {
+            nodes: Vec<PrNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PrNode {
+            number: i32,
+            title: String,
+            url: String,
+            merged_at: Option<String>,
+            author: Option<AuthorNode>,
+            labels: LabelNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            login: String,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNodes {
+            nodes: Vec<LabelNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNode {
+            name: String,
+        }
+
+        let mut prs: Vec<ReleasePr> = Vec::new();
+        let mut unlinked = 0;
+
+        for chunk in shas.chunks(50) {
+            let fields: String = chunk
+                .iter()
+                .enumerate()
+                .map(|(i, sha)| {
+                    format!(
+                        r#"c{}: object(oid: "{}") {{
+                        ... on Commit {{
+                            associatedPullRequests(first: 5) {{
+                                nodes {{
+                                    number title url mergedAt
+                                    author {{ login }}
+                                    labels(first: 20) {{ nodes {{ name }} }}
+                                }}
+                            }}
+                        }}
+                    }}
+                    "#,
+                        i, sha
+                    )
+                })
+                .collect();
+            let query = format!(
+                "query($owner: String!, $name: String!) {{\n    repository(owner: $owner, name: $name) {{\n{}}}\n}}",
+                fields
+            );
+
+            let variables = serde_json::json!({
+                "owner": owner,
+                "name": repo
+            });
+            let result: RepoResponse = self.graphql(&query, Some(variables)).await?;
+
+            let mut commits = result.repository;
+            for i in 0..chunk.len() {
+                let merged: Vec<PrNode> = commits
+                    .remove(&format!("c{}", i))
+                    .flatten()
+                    .and_then(|c| c.associated_pull_requests)
+                    .map(|p| p.nodes)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|n| n.merged_at.is_some())
+                    .collect();
+
+                if merged.is_empty() {
+                    unlinked += 1;
+                }
+                for pr in merged {
+                    if prs.iter().any(|p| p.number == pr.number) {
+                        continue;
+                    }
+                    prs.push(ReleasePr {
+                        number: pr.number,
+                        title: pr.title,
+                        url: pr.url,
+                        author: pr.author.map(|a| a.login),
+                        labels: pr.labels.nodes.into_iter().map(|l| l.name).collect(),
+                        merged_at: pr.merged_at,
+                    });
+                }
+            }
+        }
+
+        Ok((prs, unlinked))
+    }
+
+    /// Generate release notes with GitHub's generate-notes endpoint.
+    pub async fn generate_release_notes(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_name: &str,
+        previous_tag: &str,
+    ) -> Result<String> {
+        #[derive(Deserialize)]
+        struct NotesRaw {
+            body: String,
+        }
+
+        let notes: NotesRaw = self
+            .rest_post(
+                &format!("/repos/{}/{}/releases/generate-notes", owner, repo),
+                &serde_json::json!({
+                    "tag_name": tag_name,
+                    "previous_tag_name": previous_tag
+                }),
+            )
+            .await?;
+
+        Ok(notes.body)
+    }
+
+    /// Get all check runs and commit statuses for a ref.
+    ///
+    /// Returns the resolved commit SHA and the normalized checks.
+    pub async fn get_ref_checks(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: &str,
+    ) -> Result<(String, Vec<RefCheck>)> {
+        let query = r#"
+            query($owner: String!, $name: String!, $ref: String!) {
+                repository(owner: $owner, name: $name) {
+                    object(expression: $ref) {
+                        ... on Commit {
+                            oid
+                            statusCheckRollup {
+                                contexts(first: 100) {
+                                    nodes {
+                                        __typename
+                                        ... on CheckRun {
+                                            name
+                                            status
+                                            conclusion
+                                            detailsUrl
+                                        }
+                                        ... on StatusContext {
+                                            context
+                                            state
+                                            targetUrl
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        ... on Tag {
+                            target {
+                                ... on Commit {
+                                    oid
+                                    statusCheckRollup {
+                                        contexts(first: 100) {
+                                            nodes {
+                                                __typename
+                                                ... on CheckRun {
+                                                    name
+                                                    status
+                                                    conclusion
+                                                    detailsUrl
+                                                }
+                                                ... on StatusContext {
+                                                    context
+                                                    state
+                                                    targetUrl
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            object: Option<ObjectNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ObjectNode {
+            oid: Option<String>,
+            status_check_rollup: Option<RollupNode>,
+            target: Option<Box<ObjectNode>>,
+        }
+
+        #[derive(Deserialize)]
+        struct RollupNode {
+            contexts: ContextNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct ContextNodes {
+            nodes: Vec<ContextNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ContextNode {
+            #[serde(rename = "__typename")]
+            typename: String,
+            name: Option<String>,
+            status: Option<String>,
+            conclusion: Option<String>,
+            details_url: Option<String>,
+            context: Option<String>,
+            state: Option<String>,
+            target_url: Option<String>,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "ref": git_ref
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let object = result
+            .repository
+            .object
+            .with_context(|| format!("Ref '{}' not found", git_ref))?;
+        // Annotated tags point at the commit through `target`
+        let commit = match object.target {
+            Some(target) => *target,
+            None => object,
+        };
+        let sha = commit
+            .oid
+            .with_context(|| format!("Ref '{}' does not point to a commit", git_ref))?;
+
+        let checks = commit
+            .status_check_rollup
+            .map(|r| r.contexts.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|n| {
+                if n.typename == "CheckRun" {
+                    let state = match (n.status.as_deref(), n.conclusion.as_deref()) {
+                        (Some("COMPLETED"), Some("SUCCESS")) => "success",
+                        (Some("COMPLETED"), Some("NEUTRAL")) => "neutral",
+                        (Some("COMPLETED"), Some("SKIPPED")) => "skipped",
+                        (Some("COMPLETED"), _) => "failure",
+                        _ => "pending",
+                    };
+                    Some(RefCheck {
+                        name: n.name?,
+                        kind: "check_run".to_string(),
+                        state: state.to_string(),
+                        url: n.details_url,
+                    })
+                } else {
+                    let state = match n.state.as_deref() {
+                        Some("SUCCESS") => "success",
+                        Some("PENDING") | Some("EXPECTED") => "pending",
+                        _ => "failure",
+                    };
+                    Some(RefCheck {
+                        name: n.context?,
+                        kind: "status".to_string(),
+                        state: state.to_string(),
+                        url: n.target_url,
+                    })
+                }
+            })
+            .collect();
+
+        Ok((sha, checks))
+    }
+
+    /// Start a pending (draft) review on a pull request.
+    ///
+    /// Returns the review node ID. Nothing is visible to others until the
+    /// review is submitted.
+    pub async fn start_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: i32,
+        body: Option<&str>,
+    ) -> Result<String> {
+        let query = r#"
+            mutation($pullRequestId: ID!, $body: String) {
+                addPullRequestReview(input: {pullRequestId: $pullRequestId, body: $body}) {
+                    pullRequestReview {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct AddReviewResponse {
+            add_pull_request_review: AddReviewPayload,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct AddReviewPayload {
+            pull_request_review: ReviewNode,
+        }
+
+        #[derive(Deserialize)]
+        struct ReviewNode {
+            id: String,
+        }
+
+        let pr_id = self.get_pr_id(owner, repo, pr_number).await?;
+
+        let variables = serde_json::json!({
+            "pullRequestId": pr_id,
+            "body": body
+        });
+
+        let result: AddReviewResponse = self.graphql(query, Some(variables)).await?;
+        Ok(result.add_pull_request_review.pull_request_review.id)
+    }
+
+    /// Add an inline comment thread to a pending review.
+    ///
+    /// `side` is LEFT or RIGHT; `start_line` makes it a multi-line comment.
+    /// Returns the thread node ID.
+    pub async fn add_review_comment(
+        &self,
+        review_id: &str,
+        path: &str,
+        line: i32,
+        body: &str,
+        side: &str,
+        start_line: Option<i32>,
+    ) -> Result<String> {
+        let query = r#"
+            mutation($reviewId: ID!, $path: String!, $line: Int!, $body: String!, $side: DiffSide, $startLine: Int) {
+                addPullRequestReviewThread(input: {
+                    pullRequestReviewId: $reviewId,
+                    path: $path,
+                    line: $line,
+                    side: $side,
+                    startLine: $startLine,
+                    startSide: $side,
+                    body: $body
+                }) {
+                    thread {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct AddThreadResponse {
+            add_pull_request_review_thread: AddThreadPayload,
+        }
+
+        #[derive(Deserialize)]
+        struct AddThreadPayload {
+            thread: Option<ThreadNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct ThreadNode {
+            id: String,
+        }
+
+        let variables = serde_json::json!({
+            "reviewId": review_id,
+            "path": path,
+            "line": line,
+            "body": body,
+            "side": side,
+            "startLine": start_line
+        });
+
+        let result: AddThreadResponse = self.graphql(query, Some(variables)).await?;
+        result
+            .add_pull_request_review_thread
+            .thread
+            .map(|t| t.id)
+            .with_context(|| format!("Could not comment on {}:{} (line not in diff?)", path, line))
+    }
+
+    /// Submit a pending review.
+    ///
+    /// `event` is APPROVE, REQUEST_CHANGES, or COMMENT.
+    pub async fn submit_review(
+        &self,
+        review_id: &str,
+        event: &str,
+        body: Option<&str>,
+    ) -> Result<SubmittedReview> {
+        let query = r#"
+            mutation($reviewId: ID!, $event: PullRequestReviewEvent!, $body: String) {
+                submitPullRequestReview(input: {pullRequestReviewId: $reviewId, event: $event, body: $body}) {
+                    pullRequestReview {
+                        id
+                        state
+                        url
+                        comments {
+                            totalCount
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SubmitResponse {
+            submit_pull_request_review: SubmitPayload,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SubmitPayload {
+            pull_request_review: ReviewNode,
+        }
+
+        #[derive(Deserialize)]
+        struct ReviewNode {
+            id: String,
+            state: String,
+            url: String,
+            comments: CountNode,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CountNode {
+            total_count: i32,
+        }
+
+        let variables = serde_json::json!({
+            "reviewId": review_id,
+            "event": event,
+            "body": body
+        });
+
+        let result: SubmitResponse = self.graphql(query, Some(variables)).await?;
+        let review = result.submit_pull_request_review.pull_request_review;
+
+        Ok(SubmittedReview {
+            id: review.id,
+            state: review.state,
+            url: review.url,
+            comment_count: review.comments.total_count,
+        })
+    }
+
+    /// Discard a pending review and all of its comments.
+    pub async fn discard_review(&self, review_id: &str) -> Result<()> {
+        let query = r#"
+            mutation($reviewId: ID!) {
+                deletePullRequestReview(input: {pullRequestReviewId: $reviewId}) {
+                    clientMutationId
+                }
+            }
+        "#;
+
+        let variables = serde_json::json!({ "reviewId": review_id });
+        let _: Value = self.graphql(query, Some(variables)).await?;
+        Ok(())
+    }
+
+    /// List login names of the organizations the user belongs to.
+    pub async fn list_user_orgs(&self) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct OrgRaw {
+            login: String,
+        }
+
+        let orgs: Vec<OrgRaw> = self.rest_get("/user/orgs?per_page=100").await?;
+        Ok(orgs.into_iter().map(|o| o.login).collect())
+    }
+
+    /// Check whether the token is SSO-authorized for an organization.
+    ///
+    /// Probes an org-scoped endpoint; orgs without SAML enforcement always
+    /// report as authorized.
+    pub async fn get_sso_status(&self, org: &str) -> Result<SsoOrgStatus> {
+        let probe: Result<Value> = self
+            .rest_get(&format!("/orgs/{}/repos?per_page=1", org))
+            .await;
+
+        match probe {
+            Ok(_) => Ok(SsoOrgStatus {
+                org: org.to_string(),
+                authorized: true,
+                authorization_url: None,
+                error: None,
+            }),
+            Err(e) => match e.downcast_ref::<SsoRequired>() {
+                Some(sso) => Ok(SsoOrgStatus {
+                    org: org.to_string(),
+                    authorized: false,
+                    authorization_url: Some(sso.authorization_url.clone()),
+                    error: None,
+                }),
+                None => Err(e),
+            },
+        }
+    }
+
+    /// List all members of an organization with their role.
+    pub async fn list_org_members(&self, org: &str) -> Result<Vec<OrgMember>> {
+        #[derive(Deserialize)]
+        struct MemberRaw {
+            login: String,
+        }
+
+        let admins_path = format!("/orgs/{}/members?role=admin", org);
+        let members_path = format!("/orgs/{}/members?role=member", org);
+        let (admins, members) = tokio::join!(
+            self.rest_get_all::<MemberRaw>(&admins_path),
+            self.rest_get_all::<MemberRaw>(&members_path)
+        );
+
+        let with_role = |list: Vec<MemberRaw>, role: &str| {
+            list.into_iter()
+                .map(|m| OrgMember {
+                    login: m.login,
+                    role: role.to_string(),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut result = with_role(admins?, "admin");
+        result.extend(with_role(members?, "member"));
+        Ok(result)
+    }
+
+    /// List the names of all repositories in an organization.
+    pub async fn list_org_repo_names(&self, org: &str) -> Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct RepoRaw {
+            name: String,
+        }
+
+        let repos: Vec<RepoRaw> = self
+            .rest_get_all(&format!("/orgs/{}/repos?type=all", org))
+            .await?;
+        Ok(repos.into_iter().map(|r| r.name).collect())
+    }
+
+    /// List outside collaborators on a repository with their permission.
+    pub async fn list_outside_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<(String, String)>> {
+        #[derive(Deserialize)]
+        struct CollaboratorRaw {
+            login: String,
+            role_name: String,
+        }
+
+        let collaborators: Vec<CollaboratorRaw> = self
+            .rest_get_all(&format!(
+                "/repos/{}/{}/collaborators?affiliation=outside",
+                owner, repo
+            ))
+            .await?;
+        Ok(collaborators
+            .into_iter()
+            .map(|c| (c.login, c.role_name))
+            .collect())
+    }
+
+    /// List pending invitations to join an organization.
+    pub async fn list_org_invitations(&self, org: &str) -> Result<Vec<OrgInvitation>> {
+        #[derive(Deserialize)]
+        struct InvitationRaw {
+            id: i64,
+            login: Option<String>,
+            email: Option<String>,
+            role: String,
+            inviter: Option<LoginRaw>,
+            created_at: String,
+        }
+
+        #[derive(Deserialize)]
+        struct LoginRaw {
+            login: String,
+        }
+
+        let invitations: Vec<InvitationRaw> = self
+            .rest_get_all(&format!("/orgs/{}/invitations", org))
+            .await?;
+
+        Ok(invitations
+            .into_iter()
+            .map(|i| OrgInvitation {
+                id: i.id,
+                login: i.login,
+                email: i.email,
+                role: i.role,
+                inviter: i.inviter.map(|u| u.login),
+                created_at: i.created_at,
+            })
+            .collect())
+    }
+
+    /// Summarize the health of an organization's repositories.
+    ///
+    /// One query fetches counts, CI state, and latest release per repo; a
+    /// second batched query counts commits since each latest release.
+    pub async fn org_repo_summary(
+        &self,
+        org: &str,
+        limit: i32,
+        include_archived: bool,
+    ) -> Result<Vec<RepoHealth>> {
+        let query = r#"
+            query($org: String!, $first: Int!, $after: String) {
+                organization(login: $org) {
+                    repositories(first: $first, after: $after, orderBy: {field: PUSHED_AT, direction: DESC}) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            name
+                            isArchived
+                            pushedAt
+                            issues(states: OPEN) {
+                                totalCount
+                            }
+                            pullRequests(states: OPEN) {
+                                totalCount
+                            }
+                            defaultBranchRef {
+                                name
+                                target {
+                                    ... on Commit {
+                                        statusCheckRollup {
+                                            state
+                                        }
+                                    }
+                                }
+                            }
+                            latestRelease {
+                                tagName
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct OrgResponse {
+            organization: Option<OrgData>,
+        }
+
+        #[derive(Deserialize)]
+        struct OrgData {
+            repositories: RepoNodes,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoNodes {
+            page_info: PageInfo,
+            nodes: Vec<RepoNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PageInfo {
+            has_next_page: bool,
+            end_cursor: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoNode {
+            name: String,
+            is_archived: bool,
+            pushed_at: Option<String>,
+            issues: CountNode,
+            pull_requests: CountNode,
+            default_branch_ref: Option<BranchNode>,
+            latest_release: Option<ReleaseNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CountNode {
+            total_count: i32,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchNode {
+            name: String,
+            target: Option<CommitNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitNode {
+            status_check_rollup: Option<RollupNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct RollupNode {
+            state: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ReleaseNode {
+            tag_name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct CompareResponse {
+            #[serde(flatten)]
+            repos: HashMap<String, Option<CompareRepo>>,
+        }
+
+        #[derive(Deserialize)]
+        struct CompareRepo {
+            #[serde(rename = "ref")]
+            tag_ref: Option<CompareRef>,
+        }
+
+        #[derive(Deserialize)]
+        struct CompareRef {
+            compare: Option<Comparison>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Comparison {
+            ahead_by: i32,
+        }
+
+        // `limit` counts repositories fetched, 100 per page
+        let limit = usize::try_from(limit).unwrap_or(0);
+        let mut nodes: Vec<RepoNode> = Vec::new();
+        let mut after: Option<String> = None;
+        while nodes.len() < limit {
+            let variables = serde_json::json!({
+                "org": org,
+                "first": (limit - nodes.len()).min(100),
+                "after": after,
+            });
+            let result: OrgResponse = self.graphql(query, Some(variables)).await?;
+            let page = result
+                .organization
+                .with_context(|| format!("Organization '{}' not found", org))?
+                .repositories;
+            nodes.extend(page.nodes);
+            match page.page_info.end_cursor {
+                Some(cursor) if page.page_info.has_next_page => after = Some(cursor),
+                _ => break,
+            }
+        }
+
+        let mut repos: Vec<RepoHealth> = nodes
+            .into_iter()
+            .filter(|n| include_archived || !n.is_archived)
+            .map(|n| RepoHealth {
+                name: n.name,
+                is_archived: n.is_archived,
+                open_issues: n.issues.total_count,
+                open_prs: n.pull_requests.total_count,
+                pushed_at: n.pushed_at,
+                ci_state: n
+                    .default_branch_ref
+                    .as_ref()
+                    .and_then(|b| b.target.as_ref())
+                    .and_then(|t| t.status_check_rollup.as_ref())
+                    .map(|r| r.state.clone()),
+                default_branch: n.default_branch_ref.map(|b| b.name),
+                latest_release: n.latest_release.map(|r| r.tag_name),
+                unreleased_commits: None,
+            })
+            .collect();
+
+        // Batch the release-to-default-branch comparisons, 50 repos per query
+        let released: Vec<usize> = (0..repos.len())
+            .filter(|&i| repos[i].latest_release.is_some() && repos[i].default_branch.is_some())
+            .collect();
+
+        for chunk in released.chunks(50) {
+            let fields: String = chunk
+                .iter()
+                .map(|&i| {
+                    let repo = &repos[i];
+                    format!(
+                        "r{}: repository(owner: $org, name: {}) {{ ref(qualifiedName: {}) {{ compare(headRef: {}) {{ aheadBy }} }} }}\n",
+                        i,
+                        serde_json::to_string(&repo.name).unwrap_or_default(),
+                        serde_json::to_string(&format!(
+                            "refs/tags/{}",
+                            repo.latest_release.as_deref().unwrap_or_default()
+                        ))
+                        .unwrap_or_default(),
+                        serde_json::to_string(repo.default_branch.as_deref().unwrap_or_default())
+                            .unwrap_or_default(),
+                    )
+                })
+                .collect();
+            let query = format!("query($org: String!) {{\n{}}}", fields);
+
+            let variables = serde_json::json!({ "org": org });
+            let result: CompareResponse = self.graphql(&query, Some(variables)).await?;
+
+            for (alias, repo) in result.repos {
+                let Some(i) = alias
+                    .strip_prefix('r')
+                    .and_then(|i| i.parse::<usize>().ok())
+                else {
+                    continue;
+                };
+                if let Some(ahead) = repo
+                    .and_then(|r| r.tag_ref)
+                    .and_then(|r| r.compare)
+                    .map(|c| c.ahead_by)
+                {
+                    repos[i].unreleased_commits = Some(ahead);
+                }
+            }
+        }
+
+        Ok(repos)
+    }
+
+    /// List commits that touched a path, newest first.
+    pub async fn file_history(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: &str,
+        limit: i32,
+    ) -> Result<Vec<FileCommit>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $ref: String!, $path: String!, $first: Int!) {
+                repository(owner: $owner, name: $name) {
+                    object(expression: $ref) {
+                        ... on Commit {
+                            history(first: $first, path: $path) {
+                                nodes {
+                                    oid
+                                    abbreviatedOid
+                                    messageHeadline
+                                    committedDate
+                                    url
+                                    author {
+                                        name
+                                        user {
+                                            login
+                                        }
+                                    }
+                                    signature {
+                                        __typename
+                                        state
+                                        wasSignedByGitHub
+                                        signer {
+                                            login
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            object: Option<CommitNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitNode {
+            history: Option<HistoryNodes>,
+        }
+
+        #[derive(Deserialize)]
+        struct HistoryNodes {
+            nodes: Vec<HistoryNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct HistoryNode {
+            oid: String,
+            abbreviated_oid: String,
+            message_headline: String,
+            committed_date: String,
+            url: String,
+            author: Option<AuthorNode>,
+            signature: Option<SignatureRaw>,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            name: Option<String>,
+            user: Option<UserNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct UserNode {
+            login: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "ref": git_ref,
+            "path": path,
+            "first": limit
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let object = result
+            .repository
+            .object
+            .with_context(|| format!("Ref '{}' not found", git_ref))?;
+
+        Ok(object
+            .history
+            .map(|h| h.nodes)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|n| FileCommit {
+                sha: n.oid,
+                short_sha: n.abbreviated_oid,
+                message: n.message_headline,
+                author_name: n.author.as_ref().and_then(|a| a.name.clone()),
+                author_login: n.author.and_then(|a| a.user).map(|u| u.login),
+                committed_date: n.committed_date,
+                url: n.url,
+                path: path.to_string(),
+                signature: commit_signature(n.signature),
+            })
+            .collect())
+    }
+
+    /// If `sha` added `path` by renaming another file, return the old path.
+    pub async fn renamed_from(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+    ) -> Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct CommitRaw {
+            #[serde(default)]
+            files: Vec<FileRaw>,
+        }
+
+        #[derive(Deserialize)]
+        struct FileRaw {
+            filename: String,
+            status: String,
+            previous_filename: Option<String>,
+        }
+
+        let commit: CommitRaw = self
+            .rest_get(&format!("/repos/{}/{}/commits/{}", owner, repo, sha))
+            .await?;
+
+        Ok(commit
+            .files
+            .into_iter()
+            .find(|f| f.filename == path && f.status == "renamed")
+            .and_then(|f| f.previous_filename))
+    }
+
+    /// List a directory in a repository.
+    ///
+    /// With `recursive`, descends up to `max_depth` levels below `path`.
+    /// Returns the entries and whether the listing was truncated by GitHub.
+    pub async fn list_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: &str,
+        path: &str,
+        recursive: bool,
+        max_depth: usize,
+    ) -> Result<(Vec<TreeEntry>, bool)> {
+        let query = r#"
+            query($owner: String!, $name: String!, $expression: String!) {
+                repository(owner: $owner, name: $name) {
+                    object(expression: $expression) {
+                        __typename
+                        oid
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            object: Option<ObjectNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct ObjectNode {
+            #[serde(rename = "__typename")]
+            typename: String,
+            oid: String,
+        }
+
+        #[derive(Deserialize)]
+        struct TreeRaw {
+            tree: Vec<EntryRaw>,
+            #[serde(default)]
+            truncated: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct EntryRaw {
+            path: String,
+            #[serde(rename = "type")]
+            entry_type: String,
+            size: Option<i64>,
+            sha: String,
+            mode: String,
+        }
+
+        let path = path.trim_matches('/');
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "expression": format!("{}:{}", git_ref, path)
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let object = result
+            .repository
+            .object
+            .with_context(|| format!("Path '{}' not found at '{}'", path, git_ref))?;
+        if object.typename != "Tree" {
+            bail!("'{}' is a file, not a directory", path);
+        }
+
+        let tree: TreeRaw = self
+            .rest_get(&format!(
+                "/repos/{}/{}/git/trees/{}{}",
+                owner,
+                repo,
+                object.oid,
+                if recursive { "?recursive=1" } else { "" }
+            ))
+            .await?;
+
+        let entries = tree
+            .tree
+            .into_iter()
+            .filter(|e| !recursive || e.path.matches('/').count() < max_depth)
+            .map(|e| TreeEntry {
+                path: if path.is_empty() {
+                    e.path
+                } else {
+                    format!("{}/{}", path, e.path)
+                },
+                entry_type: e.entry_type,
+                size: e.size,
+                sha: e.sha,
+                mode: e.mode,
+            })
+            .collect();
+
+        Ok((entries, tree.truncated))
+    }
+
+    /// Fetch every workflow file in `.github/workflows` as `(path, text)`.
+    pub async fn get_workflow_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: &str,
+    ) -> Result<Vec<(String, String)>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $expression: String!) {
+                repository(owner: $owner, name: $name) {
+                    object(expression: $expression) {
+                        ... on Tree {
+                            entries {
+                                name
+                                path
+                                object {
+                                    ... on Blob {
+                                        text
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            object: Option<TreeNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct TreeNode {
+            #[serde(default)]
+            entries: Vec<EntryNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct EntryNode {
+            name: String,
+            path: String,
+            object: Option<BlobNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct BlobNode {
+            text: Option<String>,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "expression": format!("{}:.github/workflows", git_ref)
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+
+        // No workflows directory means no workflows, not an error
+        Ok(result
+            .repository
+            .object
+            .map(|t| t.entries)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|e| e.name.ends_with(".yml") || e.name.ends_with(".yaml"))
+            .filter_map(|e| Some((e.path, e.object?.text?)))
+            .collect())
+    }
+
+    /// Get the default branch name of a repository.
+    pub async fn get_default_branch(&self, owner: &str, repo: &str) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    defaultBranchRef {
+                        name
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            default_branch_ref: Option<BranchRef>,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchRef {
+            name: String,
+        }
+
+        let variables = serde_json::json!({ "owner": owner, "name": repo });
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        result
+            .repository
+            .default_branch_ref
+            .map(|b| b.name)
+            .context("Repository has no default branch")
+    }
+
+    /// List all branches (except `default_branch`) compared to the default branch.
+    pub async fn list_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+        default_branch: &str,
+    ) -> Result<Vec<BranchInfo>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $default: String!, $after: String) {
+                repository(owner: $owner, name: $name) {
+                    refs(refPrefix: "refs/heads/", first: 100, after: $after) {
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                        nodes {
+                            name
+                            branchProtectionRule {
+                                pattern
+                            }
+                            compare(headRef: $default) {
+                                aheadBy
+                                behindBy
+                            }
+                            open: associatedPullRequests(states: OPEN, first: 1) {
+                                nodes {
+                                    number
+                                }
+                            }
+                            merged: associatedPullRequests(states: MERGED, first: 1) {
+                                nodes {
+                                    number
+                                }
+                            }
+                            target {
+                                ... on Commit {
+                                    committedDate
+                                    author {
+                                        name
+                                        user {
+                                            login
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            refs: RefNodes,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RefNodes {
+            page_info: PageInfo,
+            nodes: Vec<RefNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PageInfo {
+            has_next_page: bool,
+            end_cursor: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RefNode {
+            name: String,
+            branch_protection_rule: Option<Value>,
+            compare: Option<Comparison>,
+            open: PrNodes,
+            merged: PrNodes,
+            target: Option<CommitNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Comparison {
+            ahead_by: i32,
+            behind_by: i32,
+        }
+
+        #[derive(Deserialize)]
+        struct PrNodes {
+            nodes: Vec<PrNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct PrNode {
+            number: i32,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitNode {
+            committed_date: Option<String>,
+            author: Option<AuthorNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            name: Option<String>,
+            user: Option<UserNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct UserNode {
+            login: String,
+        }
+
+        let mut branches = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let variables = serde_json::json!({
+                "owner": owner,
+                "name": repo,
+                "default": default_branch,
+                "after": after
+            });
+            let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+            let refs = result.repository.refs;
+
+            for node in refs.nodes {
+                if node.name == default_branch {
+                    continue;
+                }
+                // compare() treats this branch as base, so "ahead" is the default branch's lead
+                let (behind_by, ahead_by) = node
+                    .compare
+                    .map(|c| (c.ahead_by, c.behind_by))
+                    .unwrap_or_default();
+                let commit = node.target;
+                branches.push(BranchInfo {
+                    name: node.name,
+                    last_commit_date: commit
+                        .as_ref()
+                        .and_then(|c| c.committed_date.clone())
+                        .unwrap_or_default(),
+                    author: commit
+                        .and_then(|c| c.author)
+                        .and_then(|a| a.user.map(|u| u.login).or(a.name)),
+                    ahead_by,
+                    behind_by,
+                    open_pr: node.open.nodes.first().map(|p| p.number),
+                    merged_pr: node.merged.nodes.first().map(|p| p.number),
+                    protected: node.branch_protection_rule.is_some(),
+                });
+            }
+
+            if !refs.page_info.has_next_page {
+                break;
+            }
+            after = refs.page_info.end_cursor;
+        }
+
+        Ok(branches)
+    }
+
+    /// Delete a branch.
+    pub async fn delete_branch(&self, owner: &str, repo: &str, branch: &str) -> Result<()> {
+        self.rest_delete(&format!(
+            "/repos/{}/{}/git/refs/heads/{}",
+            owner, repo, branch
+        ))
+        .await
+    }
+
+    /// Search issues in a repository, returning `(number, title, body, state, url)`.
+    pub async fn search_issues(
+        &self,
+        owner: &str,
+        repo: &str,
+        terms: &str,
+        limit: i32,
+    ) -> Result<Vec<(i32, String, String, String, String)>> {
+        let query = r#"
+            query($q: String!, $first: Int!) {
+                search(query: $q, type: ISSUE, first: $first) {
+                    nodes {
+                        ... on Issue {
+                            number
+                            title
+                            body
+                            state
+                            url
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct SearchResponse {
+            search: SearchNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct SearchNodes {
+            nodes: Vec<IssueNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueNode {
+            number: Option<i32>,
+            title: Option<String>,
+            body: Option<String>,
+            state: Option<String>,
+            url: Option<String>,
+        }
+
+        let variables = serde_json::json!({
+            "q": format!("repo:{}/{} is:issue {}", owner, repo, terms),
+            "first": limit
+        });
+
+        let result: SearchResponse = self.graphql(query, Some(variables)).await?;
+
+        Ok(result
+            .search
+            .nodes
+            .into_iter()
+            .filter_map(|n| {
+                Some((
+                    n.number?,
+                    n.title?,
+                    n.body.unwrap_or_default(),
+                    n.state?,
+                    n.url?,
+                ))
+            })
+            .collect())
+    }
+
+    /// Search issues and pull requests with GitHub search syntax.
+    pub async fn search(&self, search_query: &str, limit: i32) -> Result<Vec<SearchHit>> {
+        let query = r#"
+            query($q: String!, $first: Int!) {
+                search(query: $q, type: ISSUE, first: $first) {
+                    nodes {
+                        __typename
+                        ... on Issue {
+                            number
+                            title
+                            url
+                            createdAt
+                            updatedAt
+                            author {
+                                login
+                            }
+                            repository {
+                                nameWithOwner
+                            }
+                        }
+                        ... on PullRequest {
+                            number
+                            title
+                            url
+                            createdAt
+                            updatedAt
+                            author {
+                                login
+                            }
+                            repository {
+                                nameWithOwner
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct SearchResponse {
+            search: SearchNodes,
+        }
+
+        #[derive(Deserialize)]
+        struct SearchNodes {
+            nodes: Vec<HitNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct HitNode {
+            #[serde(rename = "__typename")]
+            typename: String,
+            number: Option<i32>,
+            title: Option<String>,
+            url: Option<String>,
+            created_at: Option<String>,
+            updated_at: Option<String>,
+            author: Option<AuthorNode>,
+            repository: Option<RepoName>,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            login: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoName {
+            name_with_owner: String,
+        }
+
+        let variables = serde_json::json!({ "q": search_query, "first": limit });
+        let result: SearchResponse = self.graphql(query, Some(variables)).await?;
+
+        Ok(result
+            .search
+            .nodes
+            .into_iter()
+            .filter_map(|n| {
+                Some(SearchHit {
+                    kind: if n.typename == "PullRequest" {
+                        "pull_request".to_string()
+                    } else {
+                        "issue".to_string()
+                    },
+                    repo: n.repository?.name_with_owner,
+                    number: n.number?,
+                    title: n.title?,
+                    url: n.url?,
+                    author: n.author.map(|a| a.login),
+                    created_at: n.created_at.unwrap_or_default(),
+                    updated_at: n.updated_at.unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+
+    /// Get an issue via REST.
+    async fn get_issue_rest(&self, owner: &str, repo: &str, number: i32) -> Result<Issue> {
+        let issue: IssueRaw = self
+            .rest_get(&format!("/repos/{}/{}/issues/{}", owner, repo, number))
+            .await?;
+        Ok(issue.into())
+    }
+
+    /// Update an issue's title, body, or state.
+    ///
+    /// With `expected_updated_at`, fails with [`Conflict`] if the issue was
+    /// modified after that time.
+    pub async fn update_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        changes: &Value,
+        expected_updated_at: Option<&str>,
+    ) -> Result<Issue> {
+        if expected_updated_at.is_some() {
+            let current = self.get_issue_rest(owner, repo, number).await?;
+            ensure_unchanged(
+                &format!("Issue #{}", number),
+                expected_updated_at,
+                &current.updated_at,
+            )?;
+        }
+
+        let response = self
+            .rest_request(
+                Method::PATCH,
+                &format!("/repos/{}/{}/issues/{}", owner, repo, number),
+                Some(changes),
+            )
+            .await?;
+        let issue: IssueRaw = response.json().await.context("Failed to parse JSON")?;
+        Ok(issue.into())
+    }
+
+    /// Replace all labels on an issue or pull request.
+    ///
+    /// With `expected_updated_at`, fails with [`Conflict`] if the issue was
+    /// modified after that time.
+    pub async fn set_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        labels: &[String],
+        expected_updated_at: Option<&str>,
+    ) -> Result<Vec<String>> {
+        if expected_updated_at.is_some() {
+            let current = self.get_issue_rest(owner, repo, number).await?;
+            ensure_unchanged(
+                &format!("Issue #{}", number),
+                expected_updated_at,
+                &current.updated_at,
+            )?;
+        }
+
+        #[derive(Deserialize)]
+        struct LabelRaw {
+            name: String,
+        }
+
+        let response = self
+            .rest_request(
+                Method::PUT,
+                &format!("/repos/{}/{}/issues/{}/labels", owner, repo, number),
+                Some(&serde_json::json!({ "labels": labels })),
+            )
+            .await?;
+        let labels: Vec<LabelRaw> = response.json().await.context("Failed to parse JSON")?;
+        Ok(labels.into_iter().map(|l| l.name).collect())
+    }
+
+    /// Blob SHA of a file on a branch (default branch if `None`), or `None`
+    /// if the file doesn't exist.
+    async fn get_file_sha(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        branch: Option<&str>,
+    ) -> Result<Option<String>> {
+        let query = r#"
+            query($owner: String!, $name: String!, $expression: String!) {
+                repository(owner: $owner, name: $name) {
+                    object(expression: $expression) {
+                        ... on Blob {
+                            oid
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            object: Option<BlobNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct BlobNode {
+            oid: Option<String>,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "expression": format!("{}:{}", branch.unwrap_or("HEAD"), path)
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        Ok(result.repository.object.and_then(|o| o.oid))
+    }
+
+    /// Create or overwrite a single file with a commit.
+    ///
+    /// With `expected_sha`, fails with [`Conflict`] unless the file's current
+    /// blob SHA matches; without it an existing file is overwritten.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn put_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        content: &[u8],
+        message: &str,
+        branch: Option<&str>,
+        expected_sha: Option<&str>,
+    ) -> Result<FileWrite> {
+        let current = self.get_file_sha(owner, repo, path, branch).await?;
+        if let Some(expected) = expected_sha {
+            match &current {
+                Some(sha) => ensure_unchanged(path, Some(expected), sha)?,
+                None => {
+                    return Err(Conflict {
+                        message: format!("{} no longer exists (expected {})", path, expected),
+                    }
+                    .into())
+                }
+            }
+        }
+
+        let mut body = serde_json::json!({
+            "message": message,
+            "content": base64::engine::general_purpose::STANDARD.encode(content),
+        });
+        if let Some(sha) = &current {
+            body["sha"] = Value::from(sha.as_str());
+        }
+        if let Some(branch) = branch {
+            body["branch"] = Value::from(branch);
+        }
+
+        #[derive(Deserialize)]
+        struct PutResponse {
+            content: ContentNode,
+            commit: CommitNode,
+        }
+
+        #[derive(Deserialize)]
+        struct ContentNode {
+            sha: String,
+        }
+
+        #[derive(Deserialize)]
+        struct CommitNode {
+            sha: String,
+            html_url: String,
+            verification: Option<VerificationRaw>,
+        }
+
+        let url = format!("/repos/{}/{}/contents/{}", owner, repo, path);
+        let response = if expected_sha.is_some() {
+            // GitHub rejects the write with 409 if the file moved past `sha`
+            // after our check
+            self.rest_request_conditional(Method::PUT, &url, &body)
+                .await?
+        } else {
+            self.rest_request(Method::PUT, &url, Some(&body)).await?
+        };
+        let result: PutResponse = response.json().await.context("Failed to parse JSON")?;
+
+        Ok(FileWrite {
+            path: path.to_string(),
+            sha: result.content.sha,
+            previous_sha: current.clone(),
+            created: current.is_none(),
+            commit_sha: result.commit.sha,
+            commit_url: result.commit.html_url,
+            commit_signature: result
+                .commit
+                .verification
+                .map(CommitSignature::from)
+                .unwrap_or_else(CommitSignature::unsigned),
+        })
+    }
+
+    /// Get the caller's permission on a repository.
+    pub async fn get_repo_permissions(&self, owner: &str, repo: &str) -> Result<RepoPermissions> {
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    nameWithOwner
+                    viewerPermission
+                    isPrivate
+                    isArchived
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            name_with_owner: String,
+            viewer_permission: Option<String>,
+            is_private: bool,
+            is_archived: bool,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let data = result
+            .repository
+            .with_context(|| format!("Repository {}/{} not found", owner, repo))?;
+
+        Ok(RepoPermissions::build(
+            data.name_with_owner,
+            data.viewer_permission
+                .as_deref()
+                .and_then(RepoPermission::from_graphql),
+            data.is_private,
+            data.is_archived,
+        ))
+    }
+
+    /// Check commit signatures on `head`.
+    ///
+    /// With `base`, checks every commit in `base...head` (up to `limit`);
+    /// otherwise the latest `limit` commits of `head`. Returns the commits,
+    /// whether the range had more than `limit`, and the signature of `head`
+    /// itself if it names an annotated tag.
+    pub async fn verify_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        base: Option<&str>,
+        head: &str,
+        limit: usize,
+    ) -> Result<(Vec<CommitVerification>, bool, Option<CommitSignature>)> {
+        let query = r#"
+            query($owner: String!, $name: String!, $base: String!, $head: String!, $hasBase: Boolean!, $first: Int!, $after: String) {
+                repository(owner: $owner, name: $name) {
+                    baseRef: ref(qualifiedName: $base) @include(if: $hasBase) {
+                        compare(headRef: $head) {
+                            commits(first: $first, after: $after) {
+                                totalCount
+                                pageInfo {
+                                    hasNextPage
+                                    endCursor
+                                }
+                                nodes {
+                                    oid
+                                    abbreviatedOid
+                                    messageHeadline
+                                    committedDate
+                                    author {
+                                        user {
+                                            login
+                                        }
+                                    }
+                                    signature {
+                                        __typename
+                                        state
+                                        wasSignedByGitHub
+                                        signer {
+                                            login
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    headRef: ref(qualifiedName: $head) {
+                        target {
+                            __typename
+                            ... on Tag {
+                                signature {
+                                    __typename
+                                    state
+                                    wasSignedByGitHub
+                                    signer {
+                                        login
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    headObject: object(expression: $head) @skip(if: $hasBase) {
+                        ... on Commit {
+                            history(first: $first) {
+                                totalCount
+                                pageInfo {
+                                    hasNextPage
+                                    endCursor
+                                }
+                                nodes {
+                                    oid
+                                    abbreviatedOid
+                                    messageHeadline
+                                    committedDate
+                                    author {
+                                        user {
+                                            login
+                                        }
+                                    }
+                                    signature {
+                                        __typename
+                                        state
+                                        wasSignedByGitHub
+                                        signer {
+                                            login
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        ... on Tag {
+                            target {
+                                ... on Commit {
+                                    history(first: $first) {
+                                        totalCount
+                                        pageInfo {
+                                            hasNextPage
+                                            endCursor
+                                        }
+                                        nodes {
+                                            oid
+                                            abbreviatedOid
+                                            messageHeadline
+                                            committedDate
+                                            author {
+                                                user {
+                                                    login
+                                                }
+                                            }
+                                            signature {
+                                                __typename
+                                                state
+                                                wasSignedByGitHub
+                                                signer {
+                                                    login
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            base_ref: Option<BaseRef>,
+            head_ref: Option<HeadRef>,
+            head_object: Option<HeadObject>,
+        }
+
+        #[derive(Deserialize)]
+        struct BaseRef {
+            compare: Option<Comparison>,
+        }
+
+        #[derive(Deserialize)]
+        struct Comparison {
+            commits: CommitConnection,
+        }
+
+        #[derive(Deserialize)]
+        struct HeadRef {
+            target: Option<RefTarget>,
+        }
+
+        #[derive(Deserialize)]
+        struct RefTarget {
+            #[serde(rename = "__typename")]
+            typename: String,
+            signature: Option<SignatureRaw>,
+        }
+
+        #[derive(Deserialize)]
+        struct HeadObject {
+            history: Option<CommitConnection>,
+            target: Option<TagTarget>,
+        }
+
+        #[derive(Deserialize)]
+        struct TagTarget {
+            history: Option<CommitConnection>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitConnection {
+            total_count: i32,
+            page_info: PageInfo,
+            nodes: Vec<CommitNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PageInfo {
+            has_next_page: bool,
+            end_cursor: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CommitNode {
+            oid: String,
+            abbreviated_oid: String,
+            message_headline: String,
+            committed_date: String,
+            author: Option<AuthorNode>,
+            signature: Option<SignatureRaw>,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            user: Option<UserNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct UserNode {
+            login: String,
+        }
+
+        let mut commits = Vec::new();
+        let mut tag_signature = None;
+        let mut after: Option<String> = None;
+
+        loop {
+            let first = (limit - commits.len()).min(100);
+            let variables = serde_json::json!({
+                "owner": owner,
+                "name": repo,
+                "base": base.unwrap_or(""),
+                "head": head,
+                "hasBase": base.is_some(),
+                "first": first,
+                "after": after
+            });
+
+            let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+            let data = result
+                .repository
+                .with_context(|| format!("Repository {}/{} not found", owner, repo))?;
+
+            if let Some(target) = data.head_ref.and_then(|r| r.target) {
+                if target.typename == "Tag" {
+                    tag_signature = Some(commit_signature(target.signature));
+                }
+            }
+
+            let connection = match base {
+                Some(base) => {
+                    data.base_ref
+                        .with_context(|| format!("Ref '{}' not found", base))?
+                        .compare
+                        .with_context(|| format!("Ref '{}' not found", head))?
+                        .commits
+                }
+                None => {
+                    let object = data
+                        .head_object
+                        .with_context(|| format!("Ref '{}' not found", head))?;
+                    object
+                        .history
+                        .or(object.target.and_then(|t| t.history))
+                        .with_context(|| format!("'{}' does not point to a commit", head))?
+                }
+            };
+
+            commits.extend(connection.nodes.into_iter().map(|n| CommitVerification {
+                sha: n.oid,
+                short_sha: n.abbreviated_oid,
+                message: n.message_headline,
+                author_login: n.author.and_then(|a| a.user).map(|u| u.login),
+                committed_date: n.committed_date,
+                signature: commit_signature(n.signature),
+            }));
+
+            // History is only checked up to `limit` and doesn't paginate here
+            let more = connection.page_info.has_next_page;
+            if base.is_none() || !more || commits.len() >= limit {
+                let truncated = base.is_some() && (connection.total_count as usize) > commits.len();
+                return Ok((commits, truncated, tag_signature));
+            }
+            after = connection.page_info.end_cursor;
+        }
+    }
+
+    /// Fetch what `github.bootstrap_repo` needs to know about a repository.
+    pub async fn get_repo_setup(&self, owner: &str, repo: &str) -> Result<RepoSetup> {
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    name
+                    description
+                    isEmpty
+                    defaultBranchRef {
+                        name
+                    }
+                    owner {
+                        login
+                        ... on User {
+                            name
+                        }
+                        ... on Organization {
+                            name
+                        }
+                    }
+                    labels(first: 100) {
+                        nodes {
+                            name
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            name: String,
+            description: Option<String>,
+            is_empty: bool,
+            default_branch_ref: Option<BranchRef>,
+            owner: OwnerNode,
+            labels: Option<LabelNodes>,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchRef {
+            name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct OwnerNode {
+            login: String,
+            name: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNodes {
+            nodes: Vec<LabelNode>,
+        }
+
+        #[derive(Deserialize)]
+        struct LabelNode {
+            name: String,
+        }
+
+        let variables = serde_json::json!({ "owner": owner, "name": repo });
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let data = result
+            .repository
+            .with_context(|| format!("Repository {}/{} not found", owner, repo))?;
+
+        Ok(RepoSetup {
+            name: data.name,
+            description: data.description,
+            owner_name: data
+                .owner
+                .name
+                .filter(|n| !n.is_empty())
+                .unwrap_or(data.owner.login),
+            is_empty: data.is_empty,
+            default_branch: data.default_branch_ref.map(|b| b.name),
+            labels: data
+                .labels
+                .map(|l| l.nodes.into_iter().map(|n| n.name).collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Get a .gitignore template by name (e.g. "Rust", "Node").
+    pub async fn get_gitignore_template(&self, name: &str) -> Result<String> {
+        #[derive(Deserialize)]
+        struct Template {
+            source: String,
+        }
+
+        let template: Template = self
+            .rest_get(&format!("/gitignore/templates/{}", name))
+            .await
+            .with_context(|| format!("Unknown .gitignore template '{}'", name))?;
+        Ok(template.source)
+    }
+
+    /// Get a license template by key (e.g. "mit", "apache-2.0"). Returns the
+    /// license name and its unfilled text.
+    pub async fn get_license_template(&self, key: &str) -> Result<(String, String)> {
+        #[derive(Deserialize)]
+        struct License {
+            name: String,
+            body: String,
+        }
+
+        let license: License = self
+            .rest_get(&format!("/licenses/{}", key))
+            .await
+            .with_context(|| format!("Unknown license '{}'", key))?;
+        Ok((license.name, license.body))
+    }
+
+    /// Create a label on a repository.
+    pub async fn create_label(&self, owner: &str, repo: &str, label: &LabelSpec) -> Result<()> {
+        self.rest_request(
+            Method::POST,
+            &format!("/repos/{}/{}/labels", owner, repo),
+            Some(&serde_json::json!(label)),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Get a repository's community profile, plus whether it has a security
+    /// policy (which the REST profile doesn't report).
+    pub async fn get_community_profile(&self, owner: &str, repo: &str) -> Result<CommunityProfile> {
+        #[derive(Deserialize)]
+        struct ProfileRaw {
+            health_percentage: i32,
+            updated_at: Option<String>,
+            files: FilesRaw,
+        }
+
+        #[derive(Deserialize)]
+        struct FilesRaw {
+            code_of_conduct: Option<Value>,
+            code_of_conduct_file: Option<Value>,
+            contributing: Option<Value>,
+            issue_template: Option<Value>,
+            pull_request_template: Option<Value>,
+            license: Option<LicenseRaw>,
+            readme: Option<Value>,
+        }
+
+        #[derive(Deserialize)]
+        struct LicenseRaw {
+            spdx_id: Option<String>,
+        }
+
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    isSecurityPolicyEnabled
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: Option<RepoData>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            is_security_policy_enabled: Option<bool>,
+        }
+
+        let variables = serde_json::json!({ "owner": owner, "name": repo });
+        let path = format!("/repos/{}/{}/community/profile", owner, repo);
+        let (profile, security) = tokio::join!(
+            self.rest_get::<ProfileRaw>(&path),
+            self.graphql::<RepoResponse>(query, Some(variables))
+        );
+        let profile = profile?;
+        let security_policy = security?
+            .repository
+            .and_then(|r| r.is_security_policy_enabled)
+            .unwrap_or(false);
+        let files = profile.files;
+
+        Ok(CommunityProfile {
+            repo: format!("{}/{}", owner, repo),
+            health_percentage: profile.health_percentage,
+            readme: files.readme.is_some(),
+            code_of_conduct: files.code_of_conduct.is_some()
+                || files.code_of_conduct_file.is_some(),
+            contributing: files.contributing.is_some(),
+            security_policy,
+            issue_templates: files.issue_template.is_some(),
+            pull_request_template: files.pull_request_template.is_some(),
+            license: files.license.and_then(|l| l.spdx_id),
+            missing: Vec::new(),
+            updated_at: profile.updated_at,
+        }
+        .with_missing())
+    }
+
+    /// Pull requests created in a repository since `since` (YYYY-MM-DD),
+    /// with their review timestamps. Search caps results at 1000.
+    pub async fn pr_review_samples(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: &str,
+    ) -> Result<Vec<PrReviewSample>> {
+        let query = r#"
+            query($q: String!, $after: String) {
+                search(query: $q, type: ISSUE, first: 50, after: $after) {
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                    nodes {
+                        ... on PullRequest {
+                            number
+                            createdAt
+                            mergedAt
+                            isDraft
+                            author {
+                                login
+                            }
+                            reviews(first: 50) {
+                                nodes {
+                                    submittedAt
+                                    author {
+                                        login
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct SearchResponse {
+            search: SearchNodes,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SearchNodes {
+            page_info: PageInfo,
+            nodes: Vec<PrNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PageInfo {
+            has_next_page: bool,
+            end_cursor: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct PrNode {
+            number: Option<i32>,
+            created_at: Option<String>,
+            merged_at: Option<String>,
+            #[serde(default)]
+            is_draft: bool,
+            author: Option<AuthorNode>,
+            reviews: Option<ReviewNodes>,
+        }
+
+        #[derive(Deserialize)]
+        struct AuthorNode {
+            login: String,
+        }
+
+        #[derive(Deserialize)]
+        struct ReviewNodes {
+            nodes: Vec<ReviewNode>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ReviewNode {
+            submitted_at: Option<String>,
+            author: Option<AuthorNode>,
+        }
+
+        let search_query = format!("repo:{}/{} is:pr created:>={}", owner, repo, since);
+        let mut samples = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let variables = serde_json::json!({ "q": search_query, "after": after });
+            let result: SearchResponse = self.graphql(query, Some(variables)).await?;
+
+            samples.extend(result.search.nodes.into_iter().filter_map(|n| {
+                if n.is_draft {
+                    return None;
+                }
+                // Pending reviews have no submittedAt
+                let reviews = n
+                    .reviews
+                    .map(|r| r.nodes)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|r| {
+                        Some(ReviewSample {
+                            reviewer: r.author?.login,
+                            submitted_at: r.submitted_at?,
+                        })
+                    })
+                    .collect();
+                Some(PrReviewSample {
+                    repo: format!("{}/{}", owner, repo),
+                    number: n.number?,
+                    author: n.author.map(|a| a.login),
+                    created_at: n.created_at?,
+                    merged_at: n.merged_at,
+                    reviews,
+                })
+            }));
+
+            if !result.search.page_info.has_next_page {
+                break;
+            }
+            after = result.search.page_info.end_cursor;
+        }
+
+        Ok(samples)
+    }
+
+    /// Recent label changes on a repository's issues and PRs, newest first.
+    pub async fn list_label_events(&self, owner: &str, repo: &str) -> Result<Vec<LabelEvent>> {
+        #[derive(Deserialize)]
+        struct EventRaw {
+            id: i64,
+            event: String,
+            label: Option<NameRaw>,
+            actor: Option<LoginRaw>,
+            created_at: String,
+            issue: Option<IssueRef>,
+        }
+
+        #[derive(Deserialize)]
+        struct NameRaw {
+            name: String,
+        }
+
+        #[derive(Deserialize)]
+        struct LoginRaw {
+            login: String,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueRef {
+            number: i32,
+            title: String,
+            html_url: String,
+            pull_request: Option<Value>,
+        }
+
+        let events: Vec<EventRaw> = self
+            .rest_get(&format!(
+                "/repos/{}/{}/issues/events?per_page=100",
+                owner, repo
+            ))
+            .await?;
+
+        Ok(events
+            .into_iter()
+            .filter(|e| e.event == "labeled" || e.event == "unlabeled")
+            .filter_map(|e| {
+                let issue = e.issue?;
+                Some(LabelEvent {
+                    event_id: e.id,
+                    action: e.event,
+                    repo: format!("{}/{}", owner, repo),
+                    number: issue.number,
+                    title: issue.title,
+                    url: issue.html_url,
+                    is_pull_request: issue.pull_request.is_some(),
+                    label: e.label?.name,
+                    actor: e.actor.map(|a| a.login),
+                    created_at: e.created_at,
+                })
+            })
+            .collect())
+    }
+
+    /// Create a branch pointing at `sha`.
+    pub async fn create_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        sha: &str,
+    ) -> Result<()> {
+        self.rest_request(
+            Method::POST,
+            &format!("/repos/{}/{}/git/refs", owner, repo),
+            Some(&serde_json::json!({
+                "ref": format!("refs/heads/{}", branch),
+                "sha": sha,
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Open a pull request, returning `(number, url)`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: Option<&str>,
+        head: &str,
+        base: &str,
+        draft: bool,
+    ) -> Result<(i32, String)> {
+        #[derive(Deserialize)]
+        struct PullResponse {
+            number: i32,
+            html_url: String,
+        }
+
+        let pull: PullResponse = self
+            .rest_post(
+                &format!("/repos/{}/{}/pulls", owner, repo),
+                &serde_json::json!({
+                    "title": title,
+                    "body": body,
+                    "head": head,
+                    "base": base,
+                    "draft": draft,
+                }),
+            )
+            .await?;
+        Ok((pull.number, pull.html_url))
+    }
+
+    /// Request reviews on a pull request from users and/or teams (team slugs).
+    pub async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        number: i32,
+        reviewers: &[String],
+        team_reviewers: &[String],
+    ) -> Result<()> {
+        self.rest_request(
+            Method::POST,
+            &format!(
+                "/repos/{}/{}/pulls/{}/requested_reviewers",
+                owner, repo, number
+            ),
+            Some(&serde_json::json!({
+                "reviewers": reviewers,
+                "team_reviewers": team_reviewers,
+            })),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Get repository node ID (needed for mutations).
+    async fn get_repo_id(&self, owner: &str, repo: &str) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!) {
+                repository(owner: $owner, name: $name) {
+                    id
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoId,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoId {
+            id: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        Ok(result.repository.id)
+    }
+
+    /// Get issue node ID (needed for mutations).
+    async fn get_issue_id(&self, owner: &str, repo: &str, number: i32) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    issue(number: $number) {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        struct RepoData {
+            issue: IssueId,
+        }
+
+        #[derive(Deserialize)]
+        struct IssueId {
+            id: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": number
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        Ok(result.repository.issue.id)
+    }
+
+    /// Get node ID for an issue or pull request by number.
+    async fn get_issue_or_pr_id(&self, owner: &str, repo: &str, number: i32) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    issueOrPullRequest(number: $number) {
+                        ... on Issue {
+                            id
+                        }
+                        ... on PullRequest {
+                            id
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            issue_or_pull_request: Option<NodeId>,
+        }
+
+        #[derive(Deserialize)]
+        struct NodeId {
+            id: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": number
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        result
+            .repository
+            .issue_or_pull_request
+            .map(|n| n.id)
+            .with_context(|| format!("Issue or pull request #{} not found", number))
+    }
+
+    /// Get pull request node ID (needed for mutations).
+    async fn get_pr_id(&self, owner: &str, repo: &str, pr_number: i32) -> Result<String> {
+        let query = r#"
+            query($owner: String!, $name: String!, $number: Int!) {
+                repository(owner: $owner, name: $name) {
+                    pullRequest(number: $number) {
+                        id
+                    }
+                }
+            }
+        "#;
+
+        #[derive(Deserialize)]
+        struct RepoResponse {
+            repository: RepoData,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RepoData {
+            pull_request: Option<PrId>,
+        }
+
+        #[derive(Deserialize)]
+        struct PrId {
+            id: String,
+        }
+
+        let variables = serde_json::json!({
+            "owner": owner,
+            "name": repo,
+            "number": pr_number
+        });
+
+        let result: RepoResponse = self.graphql(query, Some(variables)).await?;
+        let pr = result
+            .repository
+            .pull_request
+            .with_context(|| format!("Pull request #{} not found", pr_number))?;
+        Ok(pr.id)
+    }
+}
+
+/// Percent-encode a query string value.
+fn query_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+/// GraphQL request body.
+#[derive(Serialize)]
+struct GraphQLRequest {
+    query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    variables: Option<Value>,
+}
+
+/// Raw notification from REST API.
+#[derive(Deserialize)]
+struct NotificationRaw {
+    id: String,
+    unread: bool,
+    reason: String,
+    subject: NotificationSubject,
+    repository: NotificationRepo,
+    updated_at: String,
+}
+
+#[derive(Deserialize)]
+struct NotificationSubject {
+    title: String,
+    #[serde(rename = "type")]
+    type_field: String,
+    url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct NotificationRepo {
+    full_name: String,
+}
+
+/// Merge queue entry node shared by queue queries and enqueue mutations.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MergeQueueEntryNode {
+    id: String,
+    position: i32,
+    state: String,
+    enqueued_at: String,
+    estimated_time_to_merge: Option<i32>,
+    jump: bool,
+    solo: bool,
+    enqueuer: Option<ActorNode>,
+    pull_request: Option<MergeQueuePrNode>,
+}
+
+#[derive(Deserialize)]
+struct MergeQueuePrNode {
+    number: i32,
+    title: String,
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct ActorNode {
+    login: String,
+}
+
+impl MergeQueueEntryNode {
+    /// Convert to the public model; entries without a pull request are skipped.
+    fn into_entry(self) -> Option<MergeQueueEntry> {
+        let pr = self.pull_request?;
+        Some(MergeQueueEntry {
+            id: self.id,
+            position: self.position,
+            state: self.state,
+            pr_number: pr.number,
+            pr_title: pr.title,
+            pr_url: pr.url,
+            enqueued_at: self.enqueued_at,
+            enqueuer: self.enqueuer.map(|a| a.login),
+            estimated_time_to_merge: self.estimated_time_to_merge,
+            jump: self.jump,
+            solo: self.solo,
+        })
+    }
+}
+
+/// Raw codespace from REST API.
+#[derive(Deserialize)]
+struct CodespaceRaw {
+    name: String,
+    display_name: Option<String>,
+    state: String,
+    repository: CodespaceRepo,
+    machine: Option<CodespaceMachine>,
+    git_status: Option<CodespaceGitStatus>,
+    web_url: String,
+    created_at: String,
+    last_used_at: Option<String>,
+    idle_timeout_minutes: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct CodespaceRepo {
+    full_name: String,
+}
+
+#[derive(Deserialize)]
+struct CodespaceMachine {
+    name: String,
+    display_name: String,
+}
+
+#[derive(Deserialize)]
+struct CodespaceGitStatus {
+    #[serde(rename = "ref")]
+    branch: Option<String>,
+}
+
+impl From<CodespaceRaw> for Codespace {
+    fn from(c: CodespaceRaw) -> Self {
+        Codespace {
+            name: c.name,
+            display_name: c.display_name,
+            state: c.state,
+            repo_full_name: c.repository.full_name,
+            branch: c.git_status.and_then(|g| g.branch),
+            machine_type: c.machine.as_ref().map(|m| m.name.clone()),
+            machine_display_name: c.machine.map(|m| m.display_name),
+            web_url: c.web_url,
+            created_at: c.created_at,
+            last_used_at: c.last_used_at,
+            idle_timeout_minutes: c.idle_timeout_minutes,
+        }
+    }
+}
+
+/// GraphQL `GitSignature` selection.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SignatureRaw {
+    #[serde(rename = "__typename")]
+    typename: String,
+    state: String,
+    was_signed_by_git_hub: bool,
+    signer: Option<SignerRaw>,
+}
+
+#[derive(Deserialize)]
+struct SignerRaw {
+    login: String,
+}
+
+/// Map a GraphQL signature (null when unsigned) to [`CommitSignature`].
+fn commit_signature(raw: Option<SignatureRaw>) -> CommitSignature {
+    let Some(raw) = raw else {
+        return CommitSignature::unsigned();
+    };
+    let kind = match raw.typename.as_str() {
+        "GpgSignature" => Some("gpg"),
+        "SshSignature" => Some("ssh"),
+        "SmimeSignature" => Some("smime"),
+        _ => None,
+    };
+
+    CommitSignature {
+        signed: true,
+        verified: raw.state == "VALID",
+        reason: raw.state.to_lowercase(),
+        signer: raw.signer.map(|s| s.login),
+        kind: kind.map(String::from),
+        signed_by_github: raw.was_signed_by_git_hub,
+    }
+}
+
+/// REST `verification` object on a commit.
+#[derive(Deserialize)]
+struct VerificationRaw {
+    verified: bool,
+    reason: String,
+    signature: Option<String>,
+}
+
+impl From<VerificationRaw> for CommitSignature {
+    fn from(v: VerificationRaw) -> Self {
+        let kind = v.signature.as_deref().and_then(|s| {
+            if s.contains("BEGIN PGP SIGNATURE") {
+                Some("gpg")
+            } else if s.contains("BEGIN SSH SIGNATURE") {
+                Some("ssh")
+            } else if s.contains("BEGIN SIGNED MESSAGE") {
+                Some("smime")
+            } else {
+                None
+            }
+        });
+
+        CommitSignature {
+            signed: v.signature.is_some(),
+            verified: v.verified,
+            reason: v.reason,
+            signer: None,
+            kind: kind.map(String::from),
+            signed_by_github: false,
+        }
+    }
+}
+
+/// Raw issue from REST API.
+#[derive(Deserialize)]
+struct IssueRaw {
+    number: i32,
+    title: String,
+    state: String,
+    html_url: String,
+    created_at: String,
+    updated_at: String,
+    user: Option<IssueUserRaw>,
+    #[serde(default)]
+    labels: Vec<IssueLabelRaw>,
+    #[serde(default)]
+    comments: i32,
+    #[serde(default)]
+    locked: bool,
+    active_lock_reason: Option<String>,
+}
+
+/// Visibility of an Actions secret or variable; only org-level ones have it.
+#[derive(Deserialize)]
+struct OrgVisibilityRaw {
+    #[serde(default)]
+    visibility: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct IssueUserRaw {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct IssueLabelRaw {
+    name: String,
+}
+
+impl From<IssueRaw> for Issue {
+    fn from(i: IssueRaw) -> Self {
+        Issue {
+            number: i.number,
+            title: i.title,
+            body: None,
+            state: i.state.to_uppercase(),
+            url: i.html_url,
+            created_at: i.created_at,
+            updated_at: i.updated_at,
+            author: i.user.map(|u| u.login),
+            labels: i.labels.into_iter().map(|l| l.name).collect(),
+            comment_count: i.comments,
+            is_pinned: false,
+            locked: i.locked,
+            lock_reason: i
+                .active_lock_reason
+                .map(|r| r.to_uppercase().replace('-', "_")),
+        }
+    }
+}
+
+/// Raw SSH key from REST API.
+#[derive(Deserialize)]
+struct SshKeyRaw {
+    id: i64,
+    key: String,
+    title: Option<String>,
+    created_at: Option<String>,
+    #[serde(default)]
+    verified: bool,
+    #[serde(default)]
+    read_only: bool,
+}
+
+impl From<SshKeyRaw> for SshKey {
+    fn from(k: SshKeyRaw) -> Self {
+        SshKey {
+            id: k.id,
+            title: k.title,
+            fingerprint: ssh_fingerprint(&k.key),
+            key: k.key,
+            created_at: k.created_at,
+            verified: k.verified,
+            read_only: k.read_only,
+        }
+    }
+}
+
+/// Raw GPG key from REST API.
+#[derive(Deserialize)]
+struct GpgKeyRaw {
+    id: i64,
+    name: Option<String>,
+    key_id: String,
+    #[serde(default)]
+    emails: Vec<GpgKeyEmail>,
+    #[serde(default)]
+    subkeys: Vec<GpgSubkeyRaw>,
+    #[serde(default)]
+    can_sign: bool,
+    created_at: Option<String>,
+    expires_at: Option<String>,
+    #[serde(default)]
+    revoked: bool,
+}
+
+#[derive(Deserialize)]
+struct GpgKeyEmail {
+    email: String,
+}
+
+#[derive(Deserialize)]
+struct GpgSubkeyRaw {
+    key_id: String,
+}
+
+impl From<GpgKeyRaw> for GpgKey {
+    fn from(k: GpgKeyRaw) -> Self {
+        GpgKey {
+            id: k.id,
+            name: k.name,
+            key_id: k.key_id,
+            subkey_ids: k.subkeys.into_iter().map(|s| s.key_id).collect(),
+            emails: k.emails.into_iter().map(|e| e.email).collect(),
+            can_sign: k.can_sign,
+            created_at: k.created_at,
+            expires_at: k.expires_at,
+            revoked: k.revoked,
+        }
+    }
+}
+
+/// Compute the OpenSSH-style SHA256 fingerprint of a public key line
+/// (`<type> <base64 blob> [comment]`). Returns None if the blob doesn't decode.
+fn ssh_fingerprint(key: &str) -> Option<String> {
+    let blob = key.split_whitespace().nth(1)?;
+    let decoded = base64::engine::general_purpose::STANDARD
+        .decode(blob)
+        .ok()?;
+    let digest = Sha256::digest(&decoded);
+    Some(format!(
+        "SHA256:{}",
+        base64::engine::general_purpose::STANDARD_NO_PAD.encode(digest)
+    ))
+}
+
+/// Encrypt a secret value for the Actions API using a libsodium sealed box.
+///
+/// `public_key_b64` is the base64 key returned by the `secrets/public-key`
+/// endpoint; the result is the base64 ciphertext GitHub expects.
+fn seal_secret(public_key_b64: &str, value: &str) -> Result<String> {
+    let key_bytes = base64::engine::general_purpose::STANDARD
+        .decode(public_key_b64)
+        .context("Invalid base64 in repository public key")?;
+    let public_key = crypto_box::PublicKey::from_slice(&key_bytes)
+        .map_err(|_| anyhow::anyhow!("Repository public key has unexpected length"))?;
+    let sealed = public_key
+        .seal(&mut crypto_box::aead::OsRng, value.as_bytes())
+        .map_err(|_| anyhow::anyhow!("Failed to encrypt secret value"))?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(sealed))
+}
+
+/// Raw workflow job from REST API.
+#[derive(Deserialize)]
+struct WorkflowJobRaw {
+    id: i64,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    started_at: Option<String>,
+    completed_at: Option<String>,
+    runner_name: Option<String>,
+    runner_group_name: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    html_url: Option<String>,
+    run_attempt: Option<i32>,
+    #[serde(default)]
+    steps: Vec<JobStepRaw>,
+}
+
+#[derive(Deserialize)]
+struct JobStepRaw {
+    number: i32,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    started_at: Option<String>,
+    completed_at: Option<String>,
+}
+
+impl From<WorkflowJobRaw> for WorkflowJob {
+    fn from(j: WorkflowJobRaw) -> Self {
+        let steps = j
+            .steps
+            .into_iter()
+            .map(|s| JobStep {
+                number: s.number,
+                duration_seconds: duration_seconds(
+                    s.started_at.as_deref(),
+                    s.completed_at.as_deref(),
+                ),
+                name: s.name,
+                status: s.status,
+                conclusion: s.conclusion,
+                started_at: s.started_at,
+                completed_at: s.completed_at,
+            })
+            .collect();
+
+        WorkflowJob {
+            id: j.id,
+            duration_seconds: duration_seconds(j.started_at.as_deref(), j.completed_at.as_deref()),
+            name: j.name,
+            status: j.status,
+            conclusion: j.conclusion,
+            started_at: j.started_at,
+            completed_at: j.completed_at,
+            runner_name: j.runner_name,
+            runner_group: j.runner_group_name,
+            labels: j.labels,
+            url: j.html_url,
+            run_attempt: j.run_attempt,
+            steps,
+        }
+    }
+}
+
+/// Seconds between two RFC 3339 timestamps, if both are present and parse.
+fn duration_seconds(start: Option<&str>, end: Option<&str>) -> Option<i64> {
+    let start = chrono::DateTime::parse_from_rfc3339(start?).ok()?;
+    let end = chrono::DateTime::parse_from_rfc3339(end?).ok()?;
+    Some((end - start).num_seconds())
+}
+
+/// Check an optimistic-concurrency precondition.
+///
+/// Timestamps are compared as instants so `Z` and `+00:00` forms match;
+/// anything else (e.g. SHAs) is compared as a string.
+fn ensure_unchanged(
+    what: &str,
+    expected: Option<&str>,
+    actual: &str,
+) -> std::result::Result<(), Conflict> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+    let same = match (
+        chrono::DateTime::parse_from_rfc3339(expected),
+        chrono::DateTime::parse_from_rfc3339(actual),
+    ) {
+        (Ok(e), Ok(a)) => e == a,
+        _ => expected.eq_ignore_ascii_case(actual),
+    };
+
+    if same {
+        Ok(())
+    } else {
+        Err(Conflict {
+            message: format!(
+                "{} changed upstream (expected {}, found {})",
+                what, expected, actual
+            ),
+        })
+    }
+}
+
+/// Detect an SSO authorization failure from the `x-github-sso` header.
+fn sso_required(response: &reqwest::Response) -> Option<SsoRequired> {
+    response
+        .headers()
+        .get("x-github-sso")
+        .and_then(|v| v.to_str().ok())
+        .and_then(parse_sso_header)
+}
+
+/// Parse `required; url=<authorization url>` from an `x-github-sso` header.
+///
+/// The `partial-results; organizations=...` form sent on listings is not an
+/// error and yields `None`.
+fn parse_sso_header(header: &str) -> Option<SsoRequired> {
+    let rest = header.trim().strip_prefix("required")?;
+    let url = rest
+        .split(';')
+        .map(str::trim)
+        .find_map(|part| part.strip_prefix("url="))?;
+
+    Some(SsoRequired {
+        authorization_url: url.to_string(),
+    })
+}
+
+/// Read the `x-ratelimit-remaining` header from a REST response.
+fn rate_limit_remaining(response: &reqwest::Response) -> Option<i64> {
+    response
+        .headers()
+        .get("x-ratelimit-remaining")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gh_config_path() {
+        let path = GitHubClient::gh_config_path().unwrap();
+        assert!(path.to_string_lossy().contains("gh/hosts.yml"));
+    }
+
+    #[test]
+    fn test_ssh_fingerprint() {
+        // Expected value from `ssh-keygen -lf`
+        let key = "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAILPBZmamuGIDKFyfjL9Jqu++aqqO1ac9pycORq13NS+V user@host";
+        assert_eq!(
+            ssh_fingerprint(key).as_deref(),
+            Some("SHA256:DR3KWftJJ1unyiGfjsGXOKYzIpYT3fAhf37ouTYJQjU")
+        );
+
+        assert!(ssh_fingerprint("not-a-key").is_none());
+        assert!(ssh_fingerprint("ssh-rsa !!!invalid!!!").is_none());
+    }
+
+    #[test]
+    fn test_query_escape() {
+        assert_eq!(query_escape("Linux-cargo-abc123"), "Linux-cargo-abc123");
+        assert_eq!(
+            query_escape("refs/heads/feat/a b&c"),
+            "refs%2Fheads%2Ffeat%2Fa%20b%26c"
+        );
+    }
+
+    #[test]
+    fn test_commit_signature_mapping() {
+        let raw: SignatureRaw = serde_json::from_value(serde_json::json!({
+            "__typename": "SshSignature",
+            "state": "UNKNOWN_KEY",
+            "wasSignedByGitHub": false,
+            "signer": {"login": "octocat"}
+        }))
+        .unwrap();
+        let sig = commit_signature(Some(raw));
+        assert!(sig.signed);
+        assert!(!sig.verified);
+        assert_eq!(sig.reason, "unknown_key");
+        assert_eq!(sig.kind.as_deref(), Some("ssh"));
+        assert_eq!(sig.signer.as_deref(), Some("octocat"));
+
+        let unsigned = commit_signature(None);
+        assert!(!unsigned.signed && !unsigned.verified);
+    }
+
+    #[test]
+    fn test_seal_secret_roundtrip() {
+        let secret_key = crypto_box::SecretKey::generate(&mut crypto_box::aead::OsRng);
+        let public_b64 =
+            base64::engine::general_purpose::STANDARD.encode(secret_key.public_key().as_bytes());
+
+        let sealed = seal_secret(&public_b64, "hunter2").unwrap();
+        let ciphertext = base64::engine::general_purpose::STANDARD
+            .decode(sealed)
+            .unwrap();
+        let plaintext = secret_key.unseal(&ciphertext).unwrap();
+
+        assert_eq!(plaintext, b"hunter2");
+        assert!(seal_secret("dG9vLXNob3J0", "x").is_err());
+    }
+
+    #[test]
+    fn test_ensure_unchanged() {
+        assert!(ensure_unchanged("Issue #1", None, "2024-01-14T10:00:00Z").is_ok());
+        assert!(ensure_unchanged(
+            "Issue #1",
+            Some("2024-01-14T10:00:00+00:00"),
+            "2024-01-14T10:00:00Z"
+        )
+        .is_ok());
+        assert!(ensure_unchanged("a.txt", Some("ABC123"), "abc123").is_ok());
+
+        let err = ensure_unchanged(
+            "Issue #1",
+            Some("2024-01-14T10:00:00Z"),
+            "2024-01-14T10:05:00Z",
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("CONFLICT: Issue #1 changed upstream"));
+    }
+
+    #[test]
+    fn test_duration_seconds() {
+        assert_eq!(
+            duration_seconds(Some("2024-01-14T10:00:00Z"), Some("2024-01-14T10:02:05Z")),
+            Some(125)
+        );
+        assert_eq!(duration_seconds(Some("2024-01-14T10:00:00Z"), None), None);
+        assert_eq!(
+            duration_seconds(Some("garbage"), Some("2024-01-14T10:00:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_actions_scope_paths() {
+        let repo = ActionsScope::Repo {
+            owner: "octocat".to_string(),
+            repo: "hello".to_string(),
+        };
+        let org = ActionsScope::Org {
+            org: "github".to_string(),
+        };
+        let env = ActionsScope::Environment {
+            owner: "octocat".to_string(),
+            repo: "hello".to_string(),
+            environment: "production".to_string(),
+        };
+
+        assert_eq!(repo.base_path(), "/repos/octocat/hello/actions");
+        assert_eq!(org.base_path(), "/orgs/github/actions");
+        assert_eq!(
+            env.base_path(),
+            "/repos/octocat/hello/environments/production"
+        );
+        assert!(org.is_org());
+        assert!(!env.is_org());
+    }
+
+    #[test]
+    fn test_parse_sso_header() {
+        let sso = parse_sso_header(
+            "required; url=https://github.com/orgs/acme/sso?authorization_request=ABC123",
+        )
+        .unwrap();
+        assert_eq!(
+            sso.authorization_url,
+            "https://github.com/orgs/acme/sso?authorization_request=ABC123"
+        );
+        assert!(sso.to_string().starts_with("SSO_REQUIRED:"));
+
+        assert!(parse_sso_header("partial-results; organizations=21955855,20582480").is_none());
     }
 }
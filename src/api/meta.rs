@@ -0,0 +1,195 @@
+//! Per-call API usage accounting for the optional `_meta` response block.
+//!
+//! Each FGP dispatch runs its API calls inside `Runtime::block_on` on the
+//! dispatching thread, so the collector lives in a thread-local. Fan-outs
+//! that spawn tasks onto runtime workers wrap them in [`scope`], which
+//! carries the same collector into the task so its requests still count.
+
+use serde::Serialize;
+use std::cell::RefCell;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+type Collector = Arc<Mutex<CallMeta>>;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Collector>> = const { RefCell::new(None) };
+}
+
+tokio::task_local! {
+    static TASK: Option<Collector>;
+}
+
+/// API usage recorded while serving a single method call.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CallMeta {
+    /// Number of GraphQL requests made.
+    pub graphql_requests: u32,
+    /// Number of REST requests made.
+    pub rest_requests: u32,
+    /// Total GraphQL rate-limit points consumed.
+    pub cost: i64,
+    /// GraphQL points remaining after the last query.
+    pub remaining: Option<i64>,
+    /// When the GraphQL budget resets.
+    pub reset_at: Option<String>,
+    /// REST requests remaining after the last REST call.
+    pub rest_remaining: Option<i64>,
+    /// Whether the result was served from the response cache.
+    pub cached: bool,
+}
+
+/// Start collecting usage for the current thread's call.
+pub fn begin() {
+    CURRENT.with(|c| *c.borrow_mut() = Some(Collector::default()));
+}
+
+/// Stop collecting and return what was recorded since `begin`.
+pub fn finish() -> Option<CallMeta> {
+    CURRENT
+        .with(|c| c.borrow_mut().take())
+        .map(|collector| collector.lock().unwrap_or_else(|e| e.into_inner()).clone())
+}
+
+/// Run `future` with the current call's collector, so requests it makes on
+/// another runtime thread (e.g. in a `JoinSet` task) are still recorded.
+pub fn scope<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    let collector = CURRENT.with(|c| c.borrow().clone());
+    TASK.scope(collector, future)
+}
+
+/// Mark the current call as answered from the response cache.
+pub(crate) fn mark_cached() {
+    with_current(|meta| meta.cached = true);
+}
+
+/// Apply `f` to the collector for the running task or thread, if any.
+fn with_current(f: impl FnOnce(&mut CallMeta)) {
+    let collector = TASK
+        .try_with(|c| c.clone())
+        .ok()
+        .flatten()
+        .or_else(|| CURRENT.with(|c| c.borrow().clone()));
+    if let Some(collector) = collector {
+        f(&mut collector.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+}
+
+/// Record a GraphQL query's rateLimit block.
+pub(crate) fn record_graphql(cost: Option<i64>, remaining: Option<i64>, reset_at: Option<String>) {
+    with_current(|meta| {
+        meta.graphql_requests += 1;
+        meta.cost += cost.unwrap_or(0);
+        if remaining.is_some() {
+            meta.remaining = remaining;
+            meta.reset_at = reset_at;
+        }
+    });
+}
+
+/// Record a REST call and its `x-ratelimit-remaining` header.
+pub(crate) fn record_rest(remaining: Option<i64>) {
+    with_current(|meta| {
+        meta.rest_requests += 1;
+        if remaining.is_some() {
+            meta.rest_remaining = remaining;
+        }
+    });
+}
+
+/// Add `rateLimit { cost remaining resetAt }` to a GraphQL query document.
+///
+/// Mutations are returned unchanged since `rateLimit` is only on `Query`.
+pub(crate) fn with_rate_limit(query: &str) -> String {
+    let trimmed = query.trim_start();
+    if !trimmed.starts_with("query") || query.contains("rateLimit") {
+        return query.to_string();
+    }
+
+    match query.rfind('}') {
+        Some(idx) => format!(
+            "{}    rateLimit {{ cost remaining resetAt }}\n{}",
+            &query[..idx],
+            &query[idx..]
+        ),
+        None => query.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_rate_limit_injects_into_queries_only() {
+        let query = "query { viewer { login } }";
+        let injected = with_rate_limit(query);
+        assert!(injected.contains("rateLimit { cost remaining resetAt }"));
+        assert!(injected.trim_end().ends_with('}'));
+
+        let mutation =
+            "mutation($id: ID!) { pinIssue(input: {issueId: $id}) { clientMutationId } }";
+        assert_eq!(with_rate_limit(mutation), mutation);
+    }
+
+    #[test]
+    fn test_meta_collection() {
+        record_graphql(Some(5), Some(100), None);
+        assert!(finish().is_none());
+
+        begin();
+        record_graphql(
+            Some(3),
+            Some(4997),
+            Some("2024-01-14T01:00:00Z".to_string()),
+        );
+        record_graphql(
+            Some(2),
+            Some(4995),
+            Some("2024-01-14T01:00:00Z".to_string()),
+        );
+        record_rest(Some(4800));
+        let meta = finish().unwrap();
+
+        assert_eq!(meta.cost, 5);
+        assert_eq!(meta.remaining, Some(4995));
+        assert_eq!(meta.rest_remaining, Some(4800));
+        assert_eq!(meta.graphql_requests, 2);
+        assert!(!meta.cached);
+
+        begin();
+        mark_cached();
+        assert!(finish().unwrap().cached);
+    }
+
+    #[test]
+    fn test_meta_counts_spawned_tasks() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .build()
+            .unwrap();
+
+        begin();
+        runtime.block_on(async {
+            let mut tasks = tokio::task::JoinSet::new();
+            for i in 0..8 {
+                tasks.spawn(scope(async move {
+                    tokio::task::yield_now().await;
+                    record_rest(Some(4000 - i));
+                    record_graphql(Some(1), Some(4900), None);
+                }));
+            }
+            while tasks.join_next().await.is_some() {}
+        });
+        let meta = finish().unwrap();
+
+        assert_eq!(meta.rest_requests, 8);
+        assert_eq!(meta.graphql_requests, 8);
+        assert_eq!(meta.cost, 8);
+        assert!(!meta.cached);
+
+        // Without an active call, scoped tasks record nothing
+        runtime.block_on(scope(async { record_rest(None) }));
+        assert!(finish().is_none());
+    }
+}
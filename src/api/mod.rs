@@ -1,5 +1,6 @@
 //! GitHub API client module.
 
 mod client;
+pub mod meta;
 
-pub use client::GitHubClient;
+pub use client::{ActionsScope, Conflict, GitHubClient, LazyClient};
@@ -0,0 +1,111 @@
+//! Initial repository contents for `github.bootstrap_repo`.
+//!
+//! Builds the file set (.gitignore from a GitHub template, LICENSE with the
+//! year and holder filled in, README skeleton) and the label set for a new
+//! repository. The service fetches templates and applies the result.
+
+use serde::{Deserialize, Serialize};
+
+/// A label to create on the repository.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelSpec {
+    pub name: String,
+    /// Hex color without the leading `#`.
+    pub color: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Labels created when the caller doesn't pass any.
+const DEFAULT_LABELS: &[(&str, &str, &str)] = &[
+    ("bug", "d73a4a", "Something isn't working"),
+    ("enhancement", "a2eeef", "New feature or request"),
+    (
+        "documentation",
+        "0075ca",
+        "Improvements or additions to documentation",
+    ),
+    ("good first issue", "7057ff", "Good for newcomers"),
+    ("help wanted", "008672", "Extra attention is needed"),
+    ("question", "d876e3", "Further information is requested"),
+    (
+        "dependencies",
+        "0366d6",
+        "Pull requests that update a dependency",
+    ),
+];
+
+/// The standard label set.
+pub fn default_labels() -> Vec<LabelSpec> {
+    DEFAULT_LABELS
+        .iter()
+        .map(|(name, color, description)| LabelSpec {
+            name: name.to_string(),
+            color: color.to_string(),
+            description: Some(description.to_string()),
+        })
+        .collect()
+}
+
+/// Labels from `wanted` not already on the repo (names compare
+/// case-insensitively, as GitHub does).
+pub fn missing_labels(wanted: Vec<LabelSpec>, existing: &[String]) -> Vec<LabelSpec> {
+    wanted
+        .into_iter()
+        .filter(|l| !existing.iter().any(|e| e.eq_ignore_ascii_case(&l.name)))
+        .collect()
+}
+
+/// Fill the placeholders GitHub's license templates use for year and holder.
+pub fn fill_license(body: &str, year: i32, holder: &str) -> String {
+    let year = year.to_string();
+    body.replace("[year]", &year)
+        .replace("[yyyy]", &year)
+        .replace("[fullname]", holder)
+        .replace("[name of copyright owner]", holder)
+}
+
+/// README skeleton for a new repository.
+pub fn readme(name: &str, description: Option<&str>, license_name: Option<&str>) -> String {
+    let mut out = format!("# {}\n\n", name);
+    if let Some(description) = description.filter(|d| !d.trim().is_empty()) {
+        out.push_str(description.trim());
+        out.push_str("\n\n");
+    }
+    out.push_str(
+        "## Getting started\n\nTODO\n\n## Contributing\n\nIssues and pull requests are welcome.\n",
+    );
+    if let Some(license_name) = license_name {
+        out.push_str(&format!(
+            "\n## License\n\nDistributed under the {}. See [LICENSE](LICENSE).\n",
+            license_name
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill_license() {
+        let mit = "Copyright (c) [year] [fullname]\n";
+        assert_eq!(
+            fill_license(mit, 2024, "Octo Cat"),
+            "Copyright (c) 2024 Octo Cat\n"
+        );
+        let apache = "Copyright [yyyy] [name of copyright owner]";
+        assert_eq!(fill_license(apache, 2024, "octo"), "Copyright 2024 octo");
+    }
+
+    #[test]
+    fn test_missing_labels() {
+        let existing = vec!["Bug".to_string(), "question".to_string()];
+        let missing = missing_labels(default_labels(), &existing);
+        assert_eq!(missing.len(), DEFAULT_LABELS.len() - 2);
+        assert!(missing
+            .iter()
+            .all(|l| l.name != "bug" && l.name != "question"));
+    }
+}
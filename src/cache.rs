@@ -0,0 +1,811 @@
+//! Response cache for read-only methods.
+//!
+//! Two tiers: a small in-memory LRU for hot entries, backed by JSON files in
+//! `~/.fgp/services/github/cache/` so bulk results survive eviction and
+//! daemon restarts. Only methods with a TTL are cached. TTLs come from the
+//! `[cache]` section of `~/.fgp/services/github/config.toml`:
+//!
+//! ```toml
+//! [cache]
+//! memory_entries = 256
+//! disk_max_mb = 64
+//!
+//! [cache.ttl]
+//! "github.user" = "1h"
+//! "github.notifications" = "30s"
+//! "github.repos" = "10m"
+//! "github.issues" = 0        # disable caching for a method
+//! ```
+//!
+//! Configured TTLs are merged over the built-in defaults. Values are seconds
+//! or a number suffixed with `s`, `m`, `h` or `d`.
+//!
+//! Entry files are private to the user (0600). Expired files are swept a day
+//! after their TTL, so `github.local_search` can still see recent stale
+//! responses, and the oldest files go first once the directory passes
+//! `disk_max_mb`.
+//!
+//! A successful write drops the cached reads it may have changed (see
+//! `INVALIDATIONS`), so a read after the caller's own write is fresh.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{DirBuilder, OpenOptions};
+use std::io::Write;
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const CACHE_DIR: &str = "~/.fgp/services/github/cache";
+
+/// Default number of entries kept in memory.
+const DEFAULT_MEMORY_ENTRIES: usize = 256;
+
+/// Default size cap of the disk tier in MiB.
+const DEFAULT_DISK_MAX_MB: u64 = 64;
+
+/// How long expired entry files stay on disk before they are swept.
+const STALE_RETENTION_SECS: u64 = 86400;
+
+/// Disk writes between sweeps of the cache directory.
+const SWEEP_EVERY_WRITES: u64 = 100;
+
+/// Built-in TTLs in seconds.
+const DEFAULT_TTLS: &[(&str, u64)] = &[
+    ("github.user", 3600),
+    ("github.repos", 600),
+    ("github.notifications", 30),
+    ("github.service_status", 60),
+    ("github.org_repo_summary", 600),
+    ("github.community_profile", 600),
+    ("github.review_metrics", 900),
+];
+
+/// Reads a successful repository content write (commit, file, fork sync) can
+/// change.
+const REPO_CONTENT_READS: &[&str] = &[
+    "github.tree",
+    "github.file_history",
+    "github.workflow_config",
+    "github.stale_branches",
+    "github.community_profile",
+    "github.verify_ref",
+    "github.ref_gate",
+    "github.release_notes",
+    "github.list_forks",
+    "github.pr",
+    "github.prs",
+    "github.pr_score",
+    "github.repos",
+    "github.org_repo_summary",
+];
+
+/// Reads a successful review change can alter.
+const REVIEW_READS: &[&str] = &[
+    "github.review_threads",
+    "github.pr",
+    "github.prs",
+    "github.pr_score",
+    "github.review_metrics",
+];
+
+/// Reads whose cached responses a successful write makes stale. `*` drops
+/// everything, for writes that touch too much to list.
+const INVALIDATIONS: &[(&str, &[&str])] = &[
+    (
+        "github.create_issue",
+        &[
+            "github.issues",
+            "github.similar_issues",
+            "github.org_repo_summary",
+        ],
+    ),
+    (
+        "github.update_issue",
+        &[
+            "github.issues",
+            "github.pr",
+            "github.prs",
+            "github.similar_issues",
+            "github.org_repo_summary",
+        ],
+    ),
+    (
+        "github.set_labels",
+        &[
+            "github.issues",
+            "github.pr",
+            "github.prs",
+            "github.similar_issues",
+        ],
+    ),
+    (
+        "github.transfer_issue",
+        &[
+            "github.issues",
+            "github.similar_issues",
+            "github.org_repo_summary",
+        ],
+    ),
+    ("github.pin_issue", &["github.issues"]),
+    ("github.unpin_issue", &["github.issues"]),
+    ("github.lock", &["github.issues", "github.pr", "github.prs"]),
+    (
+        "github.unlock",
+        &["github.issues", "github.pr", "github.prs"],
+    ),
+    (
+        "github.enqueue_pr",
+        &["github.merge_queue", "github.pr", "github.prs"],
+    ),
+    (
+        "github.dequeue_pr",
+        &["github.merge_queue", "github.pr", "github.prs"],
+    ),
+    ("github.start_codespace", &["github.codespaces"]),
+    ("github.stop_codespace", &["github.codespaces"]),
+    ("github.add_ssh_key", &["github.ssh_keys"]),
+    ("github.remove_ssh_key", &["github.ssh_keys"]),
+    ("github.add_gpg_key", &["github.gpg_keys"]),
+    ("github.remove_gpg_key", &["github.gpg_keys"]),
+    ("github.set_secret", &["github.secrets"]),
+    ("github.delete_secret", &["github.secrets"]),
+    ("github.set_variable", &["github.variables"]),
+    ("github.delete_variable", &["github.variables"]),
+    (
+        "github.accept_invitation",
+        &[
+            "github.invitations",
+            "github.repos",
+            "github.org_repo_summary",
+            "github.org_access_audit",
+            "github.permissions",
+        ],
+    ),
+    ("github.decline_invitation", &["github.invitations"]),
+    ("github.create_commit", REPO_CONTENT_READS),
+    ("github.put_file", REPO_CONTENT_READS),
+    ("github.sync_fork", REPO_CONTENT_READS),
+    ("github.resolve_thread", REVIEW_READS),
+    ("github.unresolve_thread", REVIEW_READS),
+    ("github.start_review", REVIEW_READS),
+    ("github.add_review_comment", REVIEW_READS),
+    ("github.submit_review", REVIEW_READS),
+    ("github.discard_review", REVIEW_READS),
+    ("github.delete_actions_caches", &["github.actions_caches"]),
+    ("github.bootstrap_repo", &["*"]),
+    ("github.apply", &["*"]),
+];
+
+/// The `[cache]` section of the daemon config file.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct CacheSection {
+    memory_entries: Option<usize>,
+    disk_max_mb: Option<u64>,
+    #[serde(default)]
+    ttl: HashMap<String, TtlValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TtlValue {
+    Seconds(u64),
+    Text(String),
+}
+
+/// Cache sizing and per-method TTLs.
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheConfig {
+    pub memory_entries: usize,
+    /// Size cap of the disk tier in bytes.
+    pub disk_max_bytes: u64,
+    /// TTL in seconds by canonical method name (`github.x`).
+    pub ttls: BTreeMap<String, u64>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            memory_entries: DEFAULT_MEMORY_ENTRIES,
+            disk_max_bytes: DEFAULT_DISK_MAX_MB * 1024 * 1024,
+            ttls: DEFAULT_TTLS
+                .iter()
+                .map(|(method, ttl)| (method.to_string(), *ttl))
+                .collect(),
+        }
+    }
+}
+
+impl CacheConfig {
+    /// Apply the `[cache]` section over the defaults.
+    pub(crate) fn from_section(section: CacheSection) -> Result<Self> {
+        let mut config = Self::default();
+        if let Some(entries) = section.memory_entries {
+            config.memory_entries = entries;
+        }
+        if let Some(mb) = section.disk_max_mb {
+            config.disk_max_bytes = mb * 1024 * 1024;
+        }
+        for (method, ttl) in section.ttl {
+            let seconds = match ttl {
+                TtlValue::Seconds(s) => s,
+                TtlValue::Text(text) => parse_ttl(&text)?,
+            };
+            config.ttls.insert(canonical_method(&method), seconds);
+        }
+        config.ttls.retain(|_, ttl| *ttl > 0);
+        Ok(config)
+    }
+}
+
+/// Hit/miss counters reported by `github.cache_stats`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CacheStats {
+    pub memory_hits: u64,
+    pub disk_hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    /// Entry files removed by sweeps.
+    pub swept: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    method: String,
+    /// Unix time in seconds.
+    expires_at: u64,
+    value: Value,
+}
+
+/// A stored response returned by [`ResponseCache::snapshot`].
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub key: String,
+    pub method: String,
+    /// Whether the TTL has passed.
+    pub stale: bool,
+    pub value: Value,
+}
+
+impl CachedResponse {
+    fn new(key: &str, entry: &Entry) -> Self {
+        Self {
+            key: key.to_string(),
+            method: entry.method.clone(),
+            stale: entry.expires_at <= unix_now(),
+            value: entry.value.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct MemoryEntry {
+    entry: Entry,
+    last_used: u64,
+}
+
+/// Memory LRU with disk persistence.
+#[derive(Debug)]
+pub struct ResponseCache {
+    config: CacheConfig,
+    dir: Option<PathBuf>,
+    memory: HashMap<String, MemoryEntry>,
+    clock: u64,
+    stats: CacheStats,
+    writes_since_sweep: u64,
+}
+
+impl ResponseCache {
+    /// Create a cache persisting to the default cache directory.
+    pub fn new(config: CacheConfig) -> Self {
+        let dir = PathBuf::from(shellexpand::tilde(CACHE_DIR).to_string());
+        let mut cache = Self::with_dir(config, Some(dir));
+        cache.sweep();
+        cache
+    }
+
+    fn with_dir(config: CacheConfig, dir: Option<PathBuf>) -> Self {
+        Self {
+            config,
+            dir,
+            memory: HashMap::new(),
+            clock: 0,
+            stats: CacheStats::default(),
+            writes_since_sweep: 0,
+        }
+    }
+
+    /// Cache key for a call, or `None` if the method isn't cached.
+    pub fn key(&self, method: &str, params: &HashMap<String, Value>) -> Option<String> {
+        let method = canonical_method(method);
+        if !self.config.ttls.contains_key(&method) {
+            return None;
+        }
+        let sorted: BTreeMap<&String, &Value> = params.iter().collect();
+        let params = serde_json::to_string(&sorted).unwrap_or_default();
+        let digest = Sha256::digest(format!("{}\n{}", method, params).as_bytes());
+        let hash: String = digest
+            .iter()
+            .take(16)
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        Some(format!("{}-{}", method, hash))
+    }
+
+    /// Look up a fresh cached response.
+    pub fn get(&mut self, key: &str) -> Option<Value> {
+        self.get_at(key, unix_now())
+    }
+
+    fn get_at(&mut self, key: &str, now: u64) -> Option<Value> {
+        self.clock += 1;
+
+        if let Some(hit) = self.memory.get_mut(key) {
+            if hit.entry.expires_at > now {
+                hit.last_used = self.clock;
+                self.stats.memory_hits += 1;
+                return Some(hit.entry.value.clone());
+            }
+            self.memory.remove(key);
+        }
+
+        let path = self.entry_path(key)?;
+        let entry = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str::<Entry>(&s).ok());
+        match entry {
+            Some(entry) if entry.expires_at > now => {
+                self.stats.disk_hits += 1;
+                let value = entry.value.clone();
+                self.insert_memory(key.to_string(), entry);
+                Some(value)
+            }
+            Some(_) => {
+                let _ = std::fs::remove_file(&path);
+                self.stats.misses += 1;
+                None
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Store a response under `key` using its method's TTL.
+    pub fn put(&mut self, key: &str, value: &Value) {
+        self.put_at(key, value, unix_now());
+    }
+
+    fn put_at(&mut self, key: &str, value: &Value, now: u64) {
+        let Some((method, _)) = key.rsplit_once('-') else {
+            return;
+        };
+        let Some(ttl) = self.config.ttls.get(method) else {
+            return;
+        };
+        let entry = Entry {
+            method: method.to_string(),
+            expires_at: now + ttl,
+            value: value.clone(),
+        };
+
+        if let Some(path) = self.entry_path(key) {
+            if let Err(e) = write_entry(&path, &entry) {
+                tracing::warn!("Failed to write cache entry {}: {}", path.display(), e);
+            }
+            self.writes_since_sweep += 1;
+            if self.writes_since_sweep >= SWEEP_EVERY_WRITES {
+                self.sweep_at(now);
+            }
+        }
+        self.clock += 1;
+        self.insert_memory(key.to_string(), entry);
+    }
+
+    fn insert_memory(&mut self, key: String, entry: Entry) {
+        self.memory.insert(
+            key,
+            MemoryEntry {
+                entry,
+                last_used: self.clock,
+            },
+        );
+        while self.memory.len() > self.config.memory_entries {
+            let Some(oldest) = self
+                .memory
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(k, _)| k.clone())
+            else {
+                break;
+            };
+            self.memory.remove(&oldest);
+            self.stats.evictions += 1;
+        }
+    }
+
+    /// Drop cached entries for one method, or everything. Returns the number
+    /// of entries removed from either tier.
+    pub fn clear(&mut self, method: Option<&str>) -> usize {
+        let prefix = method.map(|m| format!("{}-", canonical_method(m)));
+        let matches = |key: &str| prefix.as_deref().is_none_or(|p| key.starts_with(p));
+
+        let mut removed: Vec<String> = self.memory.keys().filter(|k| matches(k)).cloned().collect();
+        self.memory.retain(|k, _| !matches(k));
+
+        for key in self.disk_keys() {
+            if matches(&key) {
+                if let Some(path) = self.entry_path(&key) {
+                    let _ = std::fs::remove_file(path);
+                }
+                removed.push(key);
+            }
+        }
+
+        removed.sort();
+        removed.dedup();
+        removed.len()
+    }
+
+    /// Remove entry files expired for longer than the stale retention, then
+    /// the least recently written ones until the disk tier fits its cap.
+    /// Returns the number of files removed.
+    pub fn sweep(&mut self) -> usize {
+        self.sweep_at(unix_now())
+    }
+
+    fn sweep_at(&mut self, now: u64) -> usize {
+        self.writes_since_sweep = 0;
+        let mut kept = Vec::new();
+        let mut removed = 0;
+        for key in self.disk_keys() {
+            let Some(path) = self.entry_path(&key) else {
+                continue;
+            };
+            let expires_at = std::fs::read_to_string(&path)
+                .ok()
+                .and_then(|s| serde_json::from_str::<Entry>(&s).ok())
+                .map(|entry| entry.expires_at);
+            let expired = expires_at.is_none_or(|t| t + STALE_RETENTION_SECS <= now);
+            let metadata = std::fs::metadata(&path).ok();
+            match metadata {
+                Some(metadata) if !expired => {
+                    let written = metadata.modified().unwrap_or(UNIX_EPOCH);
+                    kept.push((written, metadata.len(), path));
+                }
+                _ => {
+                    if std::fs::remove_file(&path).is_ok() {
+                        removed += 1;
+                    }
+                }
+            }
+        }
+
+        let mut total: u64 = kept.iter().map(|(_, len, _)| len).sum();
+        kept.sort();
+        for (_, len, path) in kept {
+            if total <= self.config.disk_max_bytes {
+                break;
+            }
+            if std::fs::remove_file(&path).is_ok() {
+                total -= len;
+                removed += 1;
+            }
+        }
+
+        self.stats.swept += removed as u64;
+        removed
+    }
+
+    /// Drop cached reads that a successful call to `method` may have changed.
+    /// Returns the number of entries removed.
+    pub fn invalidate(&mut self, method: &str) -> usize {
+        let method = canonical_method(method);
+        let Some((_, reads)) = INVALIDATIONS.iter().find(|(m, _)| *m == method) else {
+            return 0;
+        };
+        if reads.contains(&"*") {
+            return self.clear(None);
+        }
+        reads.iter().map(|read| self.clear(Some(read))).sum()
+    }
+
+    /// Every stored response, including expired ones still on disk, without
+    /// touching hit counters or recency.
+    pub fn snapshot(&self) -> Vec<CachedResponse> {
+        let mut out: Vec<CachedResponse> = self
+            .memory
+            .iter()
+            .map(|(key, e)| CachedResponse::new(key, &e.entry))
+            .collect();
+
+        for key in self.disk_keys() {
+            if self.memory.contains_key(&key) {
+                continue;
+            }
+            let entry = self
+                .entry_path(&key)
+                .and_then(|path| std::fs::read_to_string(path).ok())
+                .and_then(|s| serde_json::from_str::<Entry>(&s).ok());
+            if let Some(entry) = entry {
+                out.push(CachedResponse::new(&key, &entry));
+            }
+        }
+
+        out.sort_by(|a, b| a.key.cmp(&b.key));
+        out
+    }
+
+    /// Current counters, entry counts, and configuration.
+    pub fn stats(&self) -> Value {
+        serde_json::json!({
+            "memory_entries": self.memory.len(),
+            "memory_capacity": self.config.memory_entries,
+            "disk_entries": self.disk_keys().len(),
+            "memory_hits": self.stats.memory_hits,
+            "disk_hits": self.stats.disk_hits,
+            "misses": self.stats.misses,
+            "evictions": self.stats.evictions,
+            "swept": self.stats.swept,
+            "disk_max_bytes": self.config.disk_max_bytes,
+            "ttls": self.config.ttls,
+        })
+    }
+
+    fn entry_path(&self, key: &str) -> Option<PathBuf> {
+        self.dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{}.json", key)))
+    }
+
+    fn disk_keys(&self) -> Vec<String> {
+        let Some(dir) = &self.dir else {
+            return Vec::new();
+        };
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        entries
+            .filter_map(|e| e.ok())
+            .filter_map(|e| {
+                e.file_name()
+                    .to_str()
+                    .and_then(|name| name.strip_suffix(".json"))
+                    .map(String::from)
+            })
+            .collect()
+    }
+}
+
+/// Write an entry file readable only by the user; responses can hold
+/// private repository data.
+fn write_entry(path: &Path, entry: &Entry) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // `mode` only applies to new files
+    file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    file.write_all(&serde_json::to_vec(entry)?)?;
+    Ok(())
+}
+
+/// Normalize short method names (`user`) to `github.user`.
+fn canonical_method(method: &str) -> String {
+    if method.starts_with("github.") {
+        method.to_string()
+    } else {
+        format!("github.{}", method)
+    }
+}
+
+/// Parse "90", "30s", "10m", "1h" or "2d" into seconds.
+pub(crate) fn parse_ttl(text: &str) -> Result<u64> {
+    let text = text.trim();
+    let (number, unit) = match text.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((idx, _)) => text.split_at(idx),
+        None => (text, "s"),
+    };
+    let number: u64 = number
+        .parse()
+        .with_context(|| format!("Invalid TTL '{}'", text))?;
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => bail!("Invalid TTL unit in '{}': use s, m, h or d", text),
+    };
+    Ok(number * multiplier)
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use serde_json::json;
+
+    /// A cache in its own temp directory, removed on drop.
+    struct TempCache(ResponseCache);
+
+    impl std::ops::Deref for TempCache {
+        type Target = ResponseCache;
+
+        fn deref(&self) -> &ResponseCache {
+            &self.0
+        }
+    }
+
+    impl std::ops::DerefMut for TempCache {
+        fn deref_mut(&mut self) -> &mut ResponseCache {
+            &mut self.0
+        }
+    }
+
+    impl Drop for TempCache {
+        fn drop(&mut self) {
+            if let Some(dir) = &self.0.dir {
+                let _ = std::fs::remove_dir_all(dir);
+            }
+        }
+    }
+
+    fn temp_cache(name: &str, memory_entries: usize) -> TempCache {
+        let dir =
+            std::env::temp_dir().join(format!("fgp-github-cache-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let config = CacheConfig {
+            memory_entries,
+            ..CacheConfig::default()
+        };
+        TempCache(ResponseCache::with_dir(config, Some(dir)))
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let parse = |text: &str| Config::parse(text).map(|c| c.cache);
+        let config = parse(
+            r#"
+            [cache]
+            memory_entries = 16
+
+            [cache.ttl]
+            user = "2h"
+            "github.issues" = 45
+            "github.notifications" = "0s"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.memory_entries, 16);
+        assert_eq!(config.ttls["github.user"], 7200);
+        assert_eq!(config.ttls["github.issues"], 45);
+        assert_eq!(config.ttls["github.repos"], 600);
+        assert!(!config.ttls.contains_key("github.notifications"));
+
+        assert!(parse("[cache.ttl]\nuser = \"5w\"").is_err());
+    }
+
+    #[test]
+    fn test_key_only_for_cached_methods() {
+        let cache = temp_cache("key", 4);
+        let mut a = HashMap::new();
+        a.insert("limit".to_string(), json!(10));
+        a.insert("type".to_string(), json!("owner"));
+        let mut b = HashMap::new();
+        b.insert("type".to_string(), json!("owner"));
+        b.insert("limit".to_string(), json!(10));
+
+        let key = cache.key("repos", &a).unwrap();
+        assert!(key.starts_with("github.repos-"));
+        assert_eq!(cache.key("github.repos", &b), Some(key));
+        assert!(cache.key("github.create_issue", &a).is_none());
+    }
+
+    #[test]
+    fn test_expiry_and_disk_fallback() {
+        let mut cache = temp_cache("tiers", 1);
+        let params = HashMap::new();
+        let user = cache.key("github.user", &params).unwrap();
+        let repos = cache.key("github.repos", &params).unwrap();
+
+        cache.put_at(&user, &json!({"login": "octocat"}), 1000);
+        cache.put_at(&repos, &json!({"count": 3}), 1000);
+        assert_eq!(cache.memory.len(), 1);
+        assert_eq!(cache.stats.evictions, 1);
+
+        // Evicted from memory but still on disk
+        assert_eq!(cache.get_at(&user, 1010), Some(json!({"login": "octocat"})));
+        assert_eq!(cache.stats.disk_hits, 1);
+        assert_eq!(cache.get_at(&user, 1020), Some(json!({"login": "octocat"})));
+        assert_eq!(cache.stats.memory_hits, 1);
+
+        // Past the one-hour TTL
+        assert_eq!(cache.get_at(&user, 1000 + 3600), None);
+        assert_eq!(cache.stats.misses, 1);
+
+        assert_eq!(cache.clear(Some("repos")), 1);
+        assert_eq!(cache.get_at(&repos, 1030), None);
+        assert_eq!(cache.clear(None), 0);
+    }
+
+    #[test]
+    fn test_writes_invalidate_affected_reads() {
+        let mut cache = temp_cache("invalidate", 8);
+        let mut params = HashMap::new();
+        params.insert("repo".to_string(), json!("octocat/hello-world"));
+        let user = cache.key("github.user", &params).unwrap();
+        let repos = cache.key("github.repos", &params).unwrap();
+        cache.put(&user, &json!({"login": "octocat"}));
+        cache.put(&repos, &json!({"count": 3}));
+
+        assert_eq!(cache.invalidate("github.set_labels"), 0);
+        assert_eq!(cache.invalidate("accept_invitation"), 1);
+        assert!(cache.get(&repos).is_none());
+        assert!(cache.get(&user).is_some());
+
+        cache.put(&repos, &json!({"count": 4}));
+        assert_eq!(cache.invalidate("github.apply"), 2);
+        assert_eq!(cache.invalidate("github.repos"), 0);
+    }
+
+    #[test]
+    fn test_conditional_writes_drop_updated_at_reads() {
+        // Reads a caller may take `expected_updated_at` from
+        let sources = [
+            "github.issues",
+            "github.pr",
+            "github.prs",
+            "github.similar_issues",
+        ];
+        for write in ["github.update_issue", "github.set_labels"] {
+            let (_, reads) = INVALIDATIONS.iter().find(|(m, _)| *m == write).unwrap();
+            for read in sources {
+                assert!(reads.contains(&read), "{} must drop {}", write, read);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sweep_expired_and_oversized() {
+        let mut cache = temp_cache("sweep", 4);
+        let params = HashMap::new();
+        let user = cache.key("github.user", &params).unwrap();
+        let repos = cache.key("github.repos", &params).unwrap();
+        cache.put_at(&user, &json!({"login": "octocat"}), 1000);
+        cache.put_at(&repos, &json!({"count": 3}), 5000);
+
+        let path = cache.entry_path(&user).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        // Within the stale retention nothing goes
+        assert_eq!(cache.sweep_at(1000 + 3600), 0);
+        assert_eq!(cache.sweep_at(1000 + 3600 + STALE_RETENTION_SECS), 1);
+        assert!(!path.exists());
+
+        cache.config.disk_max_bytes = 0;
+        assert_eq!(cache.sweep_at(5000), 1);
+        assert_eq!(cache.disk_keys().len(), 0);
+        assert_eq!(cache.stats.swept, 2);
+    }
+}
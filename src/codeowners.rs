@@ -0,0 +1,163 @@
+//! CODEOWNERS parsing and path matching.
+//!
+//! Follows GitHub's rules: the last matching line wins, a leading `/` or an
+//! inner `/` anchors a pattern to the repository root, a trailing `/` only
+//! matches directories, and `**` spans any number of directories.
+
+/// A single CODEOWNERS rule.
+#[derive(Debug, Clone)]
+pub struct OwnerRule {
+    pub pattern: String,
+    pub owners: Vec<String>,
+}
+
+/// Parsed CODEOWNERS file.
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    rules: Vec<OwnerRule>,
+}
+
+impl CodeOwners {
+    /// Parse CODEOWNERS file contents.
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or("").trim())
+            .filter(|line| !line.is_empty())
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?.to_string();
+                Some(OwnerRule {
+                    pattern,
+                    owners: parts.map(String::from).collect(),
+                })
+            })
+            .collect();
+
+        Self { rules }
+    }
+
+    /// Return the rule that owns `path`, if any.
+    pub fn owner_of(&self, path: &str) -> Option<&OwnerRule> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| pattern_matches(&rule.pattern, path))
+    }
+}
+
+/// Whether a CODEOWNERS pattern matches a repository-relative file path.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = pattern.starts_with('/') || trimmed.trim_start_matches('/').contains('/');
+    let dir_only = pattern.ends_with('/');
+
+    let pat: Vec<&str> = trimmed.trim_start_matches('/').split('/').collect();
+    let segs: Vec<&str> = path.split('/').collect();
+
+    if pat == [""] {
+        return false;
+    }
+
+    let starts: Vec<usize> = if anchored {
+        vec![0]
+    } else {
+        (0..segs.len()).collect()
+    };
+
+    starts
+        .into_iter()
+        .any(|start| match_segments(&pat, &segs[start..], dir_only))
+}
+
+/// Match pattern segments against the front of path segments.
+///
+/// A pattern that consumes only a prefix of the path matched a directory, so
+/// the file is inside it — except for a trailing `*`, which GitHub restricts
+/// to files directly in that directory.
+fn match_segments(pat: &[&str], segs: &[&str], dir_only: bool) -> bool {
+    match pat.split_first() {
+        None => !segs.is_empty() || !dir_only,
+        Some((&"**", rest)) => (0..=segs.len()).any(|i| match_segments(rest, &segs[i..], dir_only)),
+        Some((first, rest)) => match segs.split_first() {
+            Some((seg, seg_rest)) if glob_segment(first, seg) => {
+                if rest.is_empty() && *first == "*" && !seg_rest.is_empty() {
+                    return false;
+                }
+                if rest.is_empty() && dir_only {
+                    return !seg_rest.is_empty();
+                }
+                match_segments(rest, seg_rest, dir_only)
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Match a single path segment against a glob with `*` and `?`.
+fn glob_segment(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while ti < t.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == t[ti]) {
+            pi += 1;
+            ti += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            backtrack = Some((pi, ti));
+            pi += 1;
+        } else if let Some((bp, bt)) = backtrack {
+            pi = bp + 1;
+            ti = bt + 1;
+            backtrack = Some((bp, bt + 1));
+        } else {
+            return false;
+        }
+    }
+
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pattern_matching() {
+        assert!(pattern_matches("*", "src/main.rs"));
+        assert!(pattern_matches("*.rs", "src/api/client.rs"));
+        assert!(!pattern_matches("*.rs", "README.md"));
+        assert!(pattern_matches("/docs/", "docs/guide/intro.md"));
+        assert!(!pattern_matches("/docs/", "src/docs/intro.md"));
+        assert!(pattern_matches("docs/", "src/docs/intro.md"));
+        assert!(pattern_matches("src/api", "src/api/client.rs"));
+        assert!(!pattern_matches("src/api", "lib/src/api/client.rs"));
+        assert!(pattern_matches("docs/*", "docs/intro.md"));
+        assert!(!pattern_matches("docs/*", "docs/guide/intro.md"));
+        assert!(pattern_matches("**/logs", "deep/nested/logs/out.txt"));
+        assert!(pattern_matches("apps/**/test_?.py", "apps/a/b/test_1.py"));
+    }
+
+    #[test]
+    fn test_last_match_wins() {
+        let owners = CodeOwners::parse(
+            "# Default owners\n\
+             *       @org/core\n\
+             *.md    @org/docs   # docs team\n\
+             /src/api/ @alice @bob\n",
+        );
+
+        assert_eq!(owners.owner_of("Cargo.toml").unwrap().owners, ["@org/core"]);
+        assert_eq!(
+            owners.owner_of("src/README.md").unwrap().owners,
+            ["@org/docs"]
+        );
+        let api = owners.owner_of("src/api/client.rs").unwrap();
+        assert_eq!(api.pattern, "/src/api/");
+        assert_eq!(api.owners, ["@alice", "@bob"]);
+
+        assert!(CodeOwners::parse("").owner_of("a.rs").is_none());
+    }
+}
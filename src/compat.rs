@@ -0,0 +1,115 @@
+//! Response schema versioning.
+//!
+//! Callers may pass `"schema_version": N` with any method to get responses in
+//! that layout. Methods always build the current layout; when a response
+//! shape changes, bump [`CURRENT_SCHEMA_VERSION`] and register a downgrade in
+//! [`DOWNGRADES`] that rewrites the new layout into the previous one. Older
+//! clients pin the version they were written against and keep working.
+
+use anyhow::{bail, Result};
+use serde_json::Value;
+
+/// Layout produced by the service methods.
+pub const CURRENT_SCHEMA_VERSION: u64 = 1;
+
+/// Oldest layout still served.
+pub const MIN_SCHEMA_VERSION: u64 = 1;
+
+/// Rewrites a method's response from `version` into `version - 1`.
+type Downgrade = fn(&mut Value);
+
+/// Downgrades keyed by the version that introduced the change and the
+/// canonical method name.
+const DOWNGRADES: &[(u64, &str, Downgrade)] = &[];
+
+/// Resolve the requested version, defaulting to the current one.
+pub fn negotiate(requested: Option<&Value>) -> Result<u64> {
+    let Some(requested) = requested else {
+        return Ok(CURRENT_SCHEMA_VERSION);
+    };
+    match requested.as_u64() {
+        Some(v) if (MIN_SCHEMA_VERSION..=CURRENT_SCHEMA_VERSION).contains(&v) => Ok(v),
+        _ => bail!(
+            "Unsupported schema_version {}: this daemon serves {}..={}",
+            requested,
+            MIN_SCHEMA_VERSION,
+            CURRENT_SCHEMA_VERSION
+        ),
+    }
+}
+
+/// Convert a current-layout response into `version`'s layout.
+pub fn render(method: &str, value: Value, version: u64) -> Value {
+    render_with(DOWNGRADES, method, value, version)
+}
+
+fn render_with(
+    downgrades: &[(u64, &str, Downgrade)],
+    method: &str,
+    mut value: Value,
+    version: u64,
+) -> Value {
+    let method = method.strip_prefix("github.").unwrap_or(method);
+    let mut steps: Vec<&(u64, &str, Downgrade)> = downgrades
+        .iter()
+        .filter(|(introduced, m, _)| {
+            *introduced > version && m.strip_prefix("github.").unwrap_or(m) == method
+        })
+        .collect();
+    steps.sort_by_key(|(introduced, _, _)| std::cmp::Reverse(*introduced));
+
+    for (_, _, downgrade) in steps {
+        downgrade(&mut value);
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rename_count(value: &mut Value) {
+        if let Some(obj) = value.as_object_mut() {
+            if let Some(total) = obj.remove("total") {
+                obj.insert("count".into(), total);
+            }
+        }
+    }
+
+    fn drop_cursor(value: &mut Value) {
+        if let Some(obj) = value.as_object_mut() {
+            obj.remove("cursor");
+        }
+    }
+
+    #[test]
+    fn test_negotiate() {
+        assert_eq!(negotiate(None).unwrap(), CURRENT_SCHEMA_VERSION);
+        assert_eq!(
+            negotiate(Some(&json!(MIN_SCHEMA_VERSION))).unwrap(),
+            MIN_SCHEMA_VERSION
+        );
+        assert!(negotiate(Some(&json!(0))).is_err());
+        assert!(negotiate(Some(&json!(CURRENT_SCHEMA_VERSION + 1))).is_err());
+        assert!(negotiate(Some(&json!("1"))).is_err());
+    }
+
+    #[test]
+    fn test_render_applies_downgrades_newest_first() {
+        let table: &[(u64, &str, Downgrade)] =
+            &[(2, "github.repos", rename_count), (3, "repos", drop_cursor)];
+        let v3 = json!({"total": 2, "cursor": "abc"});
+
+        assert_eq!(render_with(table, "repos", v3.clone(), 3), v3);
+        assert_eq!(
+            render_with(table, "github.repos", v3.clone(), 2),
+            json!({"total": 2})
+        );
+        assert_eq!(
+            render_with(table, "github.repos", v3.clone(), 1),
+            json!({"count": 2})
+        );
+        assert_eq!(render_with(table, "github.issues", v3.clone(), 1), v3);
+    }
+}
@@ -0,0 +1,107 @@
+//! Daemon config file.
+//!
+//! `~/.fgp/services/github/config.toml` is read and parsed once at startup.
+//! Each section belongs to the module that uses it:
+//!
+//! - `[notifications]` — routing rules, see `rules.rs`
+//! - `[cache]` — memory size, disk cap and per-method TTLs, see `cache.rs`
+//! - `[poller]` — poll interval bounds, see `poller.rs`
+//! - `[[triggers]]` — event triggers, see `triggers.rs`
+//!
+//! A missing file means all defaults. So does a file that fails to load;
+//! the daemon then reports the error as degraded health instead of refusing
+//! to start.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+use crate::cache::{CacheConfig, CacheSection};
+use crate::poller::{PollerConfig, PollerSection};
+use crate::rules::NotificationRules;
+use crate::triggers::{self, Trigger};
+
+pub(crate) const CONFIG_PATH: &str = "~/.fgp/services/github/config.toml";
+
+#[derive(Debug, Default, Deserialize)]
+struct ConfigFile {
+    #[serde(default)]
+    notifications: NotificationRules,
+    #[serde(default)]
+    cache: CacheSection,
+    #[serde(default)]
+    poller: PollerSection,
+    #[serde(default)]
+    triggers: Vec<Trigger>,
+}
+
+/// Validated daemon configuration.
+#[derive(Debug, Default)]
+pub struct Config {
+    pub rules: NotificationRules,
+    pub cache: CacheConfig,
+    pub poller: PollerConfig,
+    pub triggers: Vec<Trigger>,
+}
+
+impl Config {
+    /// Load the daemon config file, or defaults if it doesn't exist.
+    pub fn load() -> Result<Self> {
+        let path = PathBuf::from(shellexpand::tilde(CONFIG_PATH).to_string());
+        let content = if path.exists() {
+            std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?
+        } else {
+            String::new()
+        };
+        Self::parse(&content).with_context(|| format!("Invalid config in {}", path.display()))
+    }
+
+    pub(crate) fn parse(content: &str) -> Result<Self> {
+        let file: ConfigFile = toml::from_str(content)?;
+        Ok(Self {
+            rules: file.notifications,
+            cache: CacheConfig::from_section(file.cache)?,
+            poller: PollerConfig::from_section(file.poller)?,
+            triggers: triggers::validate(file.triggers)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_parse_together() {
+        let config = Config::parse(
+            r#"
+            [notifications]
+            default_bucket = "later"
+
+            [cache.ttl]
+            user = "2h"
+
+            [poller]
+            min_interval = "30s"
+
+            [[triggers]]
+            name = "deploy"
+            on = "labeled"
+            repo = "my-org/app"
+            emit = "deploy_requested"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.rules.default_bucket, "later");
+        assert_eq!(config.cache.ttls["github.user"], 7200);
+        assert_eq!(config.poller.min_interval.as_secs(), 30);
+        assert_eq!(config.triggers.len(), 1);
+
+        // An empty (or missing) file means defaults everywhere
+        let config = Config::parse("").unwrap();
+        assert_eq!(config.rules.default_bucket, "inbox");
+        assert!(config.rules.rules.is_empty());
+        assert!(config.triggers.is_empty());
+    }
+}
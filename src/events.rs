@@ -0,0 +1,207 @@
+//! Durable event log.
+//!
+//! Events from the background poller and reminder scheduler are appended to
+//! `~/.fgp/services/github/events.jsonl` with increasing sequence numbers, so
+//! clients can call `github.events_since` after either side restarts and
+//! replay anything they missed. The log keeps the most recent
+//! [`MAX_RETAINED_EVENTS`] events; older ones are compacted away.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const LOG_PATH: &str = "~/.fgp/services/github/events.jsonl";
+
+/// Events kept on disk and in memory.
+pub const MAX_RETAINED_EVENTS: usize = 10_000;
+
+/// A recorded event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    pub seq: u64,
+    /// Event type, e.g. "notification" or "reminder".
+    pub kind: String,
+    pub created_at: DateTime<Utc>,
+    pub payload: Value,
+}
+
+/// Append-only event log with sequence numbers.
+#[derive(Debug, Default)]
+pub struct EventLog {
+    path: Option<PathBuf>,
+    events: VecDeque<Event>,
+    last_seq: u64,
+    /// Lines appended since the file was last compacted.
+    appended: usize,
+}
+
+impl EventLog {
+    /// Load the log from the default path.
+    pub fn load() -> Result<Self> {
+        let path = PathBuf::from(shellexpand::tilde(LOG_PATH).to_string());
+        Self::load_from(&path)
+    }
+
+    fn load_from(path: &Path) -> Result<Self> {
+        let mut log = Self {
+            path: Some(path.to_path_buf()),
+            ..Self::default()
+        };
+        if !path.exists() {
+            return Ok(log);
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let mut damaged = !content.is_empty() && !content.ends_with('\n');
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            // A crash mid-write can leave a truncated last line; skip it.
+            match serde_json::from_str::<Event>(line) {
+                Ok(event) => {
+                    log.last_seq = log.last_seq.max(event.seq);
+                    log.events.push_back(event);
+                }
+                Err(e) => {
+                    tracing::warn!("Skipping bad event log line: {}", e);
+                    damaged = true;
+                }
+            }
+        }
+        while log.events.len() > MAX_RETAINED_EVENTS {
+            log.events.pop_front();
+        }
+
+        // Rewrite so new appends don't land on the end of a torn line
+        if damaged {
+            log.compact()?;
+        }
+
+        Ok(log)
+    }
+
+    /// Record an event and persist it before returning.
+    pub fn append(&mut self, kind: &str, payload: Value) -> Result<Event> {
+        self.last_seq += 1;
+        let event = Event {
+            seq: self.last_seq,
+            kind: kind.to_string(),
+            created_at: Utc::now(),
+            payload,
+        };
+
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open {}", path.display()))?;
+            writeln!(file, "{}", serde_json::to_string(&event)?)?;
+        }
+
+        self.events.push_back(event.clone());
+        self.appended += 1;
+        if self.events.len() > MAX_RETAINED_EVENTS {
+            self.events.pop_front();
+        }
+        if self.appended >= MAX_RETAINED_EVENTS {
+            self.compact()?;
+        }
+
+        Ok(event)
+    }
+
+    /// Rewrite the file with only the retained events.
+    fn compact(&mut self) -> Result<()> {
+        self.appended = 0;
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let mut content = String::new();
+        for event in &self.events {
+            content.push_str(&serde_json::to_string(event)?);
+            content.push('\n');
+        }
+        let tmp = path.with_extension("jsonl.tmp");
+        std::fs::write(&tmp, content)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Events with `seq > after`, oldest first, optionally filtered by kind.
+    pub fn since(&self, after: u64, limit: usize, kinds: &[String]) -> Vec<Event> {
+        self.events
+            .iter()
+            .filter(|e| e.seq > after)
+            .filter(|e| kinds.is_empty() || kinds.contains(&e.kind))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Most recent event of a kind.
+    pub fn last_of_kind(&self, kind: &str) -> Option<&Event> {
+        self.events.iter().rev().find(|e| e.kind == kind)
+    }
+
+    /// Highest sequence number assigned so far.
+    pub fn last_seq(&self) -> u64 {
+        self.last_seq
+    }
+
+    /// Lowest sequence number still retained.
+    pub fn oldest_seq(&self) -> Option<u64> {
+        self.events.front().map(|e| e.seq)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_append_and_replay_after_restart() {
+        let path =
+            std::env::temp_dir().join(format!("fgp-github-events-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut log = EventLog::load_from(&path).unwrap();
+        log.append("notification", json!({"id": "1"})).unwrap();
+        log.append("reminder", json!({"name": "stale"})).unwrap();
+        log.append("notification", json!({"id": "2"})).unwrap();
+
+        // Simulate a daemon restart, including a torn final line
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        write!(file, "{{\"seq\": 4, \"ki").unwrap();
+        drop(file);
+
+        let mut log = EventLog::load_from(&path).unwrap();
+        assert_eq!(log.last_seq(), 3);
+        assert_eq!(log.oldest_seq(), Some(1));
+
+        let replay = log.since(1, 10, &[]);
+        assert_eq!(replay.iter().map(|e| e.seq).collect::<Vec<_>>(), [2, 3]);
+        let notifications = log.since(0, 10, &["notification".to_string()]);
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(log.since(0, 1, &[]).len(), 1);
+
+        assert_eq!(log.append("reminder", json!({})).unwrap().seq, 4);
+        assert_eq!(log.last_of_kind("notification").unwrap().seq, 3);
+
+        let log = EventLog::load_from(&path).unwrap();
+        assert_eq!(log.last_seq(), 4);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
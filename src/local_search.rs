@@ -0,0 +1,311 @@
+//! Full-text search over fetched content.
+//!
+//! `github.local_search` walks two sources and matches string fields by
+//! substring or regex, without API calls:
+//!
+//! - the [`SearchIndex`]: issue bodies from `github.issues`, PR descriptions
+//!   from `github.pr`, and file contents from `github.workflow_config` and
+//!   `github.pr_score` (CODEOWNERS), recorded whenever those methods run
+//! - every response in the [`ResponseCache`] (methods with a TTL), memory
+//!   and disk, including entries past their TTL
+//!
+//! The index sits beside the read path, not on it: recording content never
+//! changes what a read returns or how fresh it is. It is memory only and
+//! starts empty after a restart.
+//!
+//! [`ResponseCache`]: crate::cache::ResponseCache
+
+use anyhow::{Context, Result};
+use regex_automata::meta::Regex;
+use regex_automata::util::syntax;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::cache::CachedResponse;
+
+/// Documents kept in the search index before the oldest are dropped.
+const INDEX_MAX_DOCS: usize = 5000;
+
+/// Characters of context kept on each side of a match.
+const SNIPPET_CONTEXT: usize = 60;
+
+/// Fields copied from the enclosing object to identify a hit.
+const CONTEXT_FIELDS: &[&str] = &[
+    "repo",
+    "repo_full_name",
+    "full_name",
+    "name",
+    "number",
+    "title",
+    "path",
+    "url",
+];
+
+/// A matching string in a cached response.
+#[derive(Debug, Clone, Serialize)]
+pub struct LocalHit {
+    pub method: String,
+    /// JSON path of the matching field, e.g. `issues[3].body`.
+    pub path: String,
+    pub snippet: String,
+    /// Identifying fields of the object containing the match.
+    pub context: Map<String, Value>,
+    /// The cached response is past its TTL.
+    pub stale: bool,
+}
+
+/// Content recorded from read methods for `github.local_search`.
+#[derive(Debug, Default)]
+pub struct SearchIndex {
+    /// Documents by ID, with the clock value of their last update.
+    docs: HashMap<String, (u64, CachedResponse)>,
+    clock: u64,
+}
+
+impl SearchIndex {
+    /// Add or replace document `id`, fetched by `method`.
+    pub fn add(&mut self, method: &str, id: String, doc: Value) {
+        self.clock += 1;
+        let entry = CachedResponse {
+            key: id.clone(),
+            method: method.to_string(),
+            stale: false,
+            value: doc,
+        };
+        self.docs.insert(id, (self.clock, entry));
+
+        while self.docs.len() > INDEX_MAX_DOCS {
+            let Some(oldest) = self
+                .docs
+                .iter()
+                .min_by_key(|(_, (updated, _))| *updated)
+                .map(|(id, _)| id.clone())
+            else {
+                break;
+            };
+            self.docs.remove(&oldest);
+        }
+    }
+
+    /// Every indexed document, ordered by ID.
+    pub fn snapshot(&self) -> Vec<CachedResponse> {
+        let mut out: Vec<CachedResponse> = self.docs.values().map(|(_, doc)| doc.clone()).collect();
+        out.sort_by(|a, b| a.key.cmp(&b.key));
+        out
+    }
+}
+
+/// Compile a query. Plain queries match literally.
+pub fn matcher(query: &str, regex: bool, case_sensitive: bool) -> Result<Regex> {
+    let pattern = if regex {
+        query.to_string()
+    } else {
+        escape(query)
+    };
+    Regex::builder()
+        .syntax(syntax::Config::new().case_insensitive(!case_sensitive))
+        .build(&pattern)
+        .with_context(|| format!("Invalid pattern '{}'", query))
+}
+
+/// Search cached responses, stopping after `limit` hits.
+pub fn search(responses: &[CachedResponse], re: &Regex, limit: usize) -> Vec<LocalHit> {
+    let mut hits = Vec::new();
+    for response in responses {
+        let mut path = String::new();
+        walk(
+            &response.value,
+            &mut path,
+            None,
+            &mut |path, text, parent| {
+                if hits.len() >= limit {
+                    return false;
+                }
+                if let Some(m) = re.find(text) {
+                    hits.push(LocalHit {
+                        method: response.method.clone(),
+                        path: path.to_string(),
+                        snippet: snippet(text, m.start(), m.end()),
+                        context: context(parent),
+                        stale: response.stale,
+                    });
+                }
+                true
+            },
+        );
+        if hits.len() >= limit {
+            break;
+        }
+    }
+    hits
+}
+
+/// Called with a string's path, text, and enclosing object; returns false
+/// to stop the walk.
+type Visitor<'a> = dyn FnMut(&str, &str, Option<&Map<String, Value>>) -> bool + 'a;
+
+/// Visit every string in `value` with its path and enclosing object.
+fn walk(
+    value: &Value,
+    path: &mut String,
+    parent: Option<&Map<String, Value>>,
+    visit: &mut Visitor,
+) -> bool {
+    let len = path.len();
+    let keep_going = match value {
+        Value::String(text) => visit(path, text, parent),
+        Value::Array(items) => items.iter().enumerate().all(|(i, item)| {
+            path.push_str(&format!("[{}]", i));
+            let ok = walk(item, path, parent, visit);
+            path.truncate(len);
+            ok
+        }),
+        Value::Object(map) => map.iter().all(|(key, item)| {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(key);
+            let ok = walk(item, path, Some(map), visit);
+            path.truncate(len);
+            ok
+        }),
+        _ => true,
+    };
+    path.truncate(len);
+    keep_going
+}
+
+fn context(parent: Option<&Map<String, Value>>) -> Map<String, Value> {
+    let Some(parent) = parent else {
+        return Map::new();
+    };
+    CONTEXT_FIELDS
+        .iter()
+        .filter_map(|field| {
+            let value = parent.get(*field)?;
+            (value.is_string() || value.is_number()).then(|| (field.to_string(), value.clone()))
+        })
+        .collect()
+}
+
+/// The match with up to [`SNIPPET_CONTEXT`] characters on each side.
+fn snippet(text: &str, start: usize, end: usize) -> String {
+    let before: String = text[..start]
+        .chars()
+        .rev()
+        .take(SNIPPET_CONTEXT)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    let after: String = text[end..].chars().take(SNIPPET_CONTEXT).collect();
+
+    let mut out = String::new();
+    if before.len() < start {
+        out.push('…');
+    }
+    out.push_str(&before);
+    out.push_str(&text[start..end]);
+    out.push_str(&after);
+    if end + after.len() < text.len() {
+        out.push('…');
+    }
+    out.replace('\n', " ")
+}
+
+/// Escape regex metacharacters so `text` matches literally.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.+*?()|[]{}^$#&-~".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn cached(method: &str, value: Value) -> CachedResponse {
+        CachedResponse {
+            key: format!("{}-0", method),
+            method: method.to_string(),
+            stale: false,
+            value,
+        }
+    }
+
+    #[test]
+    fn test_search_literal_and_regex() {
+        let responses = vec![
+            cached(
+                "github.issues",
+                json!({"issues": [
+                    {"number": 1, "title": "Crash on start", "body": "Panics with (null) pointer"},
+                    {"number": 2, "title": "Docs", "body": "Typo in README"}
+                ]}),
+            ),
+            cached(
+                "github.user",
+                json!({"login": "octocat", "bio": "null island"}),
+            ),
+        ];
+
+        let re = matcher("(NULL)", false, false).unwrap();
+        let hits = search(&responses, &re, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].path, "issues[0].body");
+        assert_eq!(hits[0].context["number"], json!(1));
+        assert_eq!(hits[0].snippet, "Panics with (null) pointer");
+
+        let re = matcher(r"null\s\w+", true, true).unwrap();
+        let hits = search(&responses, &re, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].method, "github.user");
+
+        let re = matcher("o", false, false).unwrap();
+        assert_eq!(search(&responses, &re, 2).len(), 2);
+        assert!(matcher("(", true, false).is_err());
+    }
+
+    #[test]
+    fn test_indexed_issue_body_is_searchable() {
+        let mut index = SearchIndex::default();
+        let issue = |body: &str| json!({"repo": "octocat/hello-world", "number": 7, "title": "Crash", "body": body});
+        index.add(
+            "github.issues",
+            "github.issues:octocat/hello-world#7".to_string(),
+            issue("Stack trace: NullPointerException at boot"),
+        );
+
+        let re = matcher("nullpointerexception", false, false).unwrap();
+        let hits = search(&index.snapshot(), &re, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].method, "github.issues");
+        assert_eq!(hits[0].path, "body");
+        assert_eq!(hits[0].context["number"], json!(7));
+        assert!(!hits[0].stale);
+
+        // Refetching replaces the document
+        index.add(
+            "github.issues",
+            "github.issues:octocat/hello-world#7".to_string(),
+            issue("Fixed by restarting"),
+        );
+        assert_eq!(index.snapshot().len(), 1);
+        assert!(search(&index.snapshot(), &re, 10).is_empty());
+    }
+
+    #[test]
+    fn test_snippet_trims_long_text() {
+        let text = format!("{}needle{}", "a".repeat(100), "b".repeat(100));
+        let s = snippet(&text, 100, 106);
+        assert!(s.starts_with('…') && s.ends_with('…'));
+        assert_eq!(s.chars().count(), 2 + 2 * SNIPPET_CONTEXT + 6);
+    }
+}
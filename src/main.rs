@@ -25,6 +25,135 @@
 //! - `github.pr` - Get PR details with reviews and status checks
 //! - `github.notifications` - Get unread notifications
 //! - `github.create_issue` - Create a new issue
+//! - `github.merge_queue` - View a branch's merge queue
+//! - `github.enqueue_pr` - Add a PR to the merge queue
+//! - `github.dequeue_pr` - Remove a PR from the merge queue
+//! - `github.download_archive` - Download a repository tarball/zipball
+//! - `github.codespaces` - List your codespaces
+//! - `github.start_codespace` - Start a codespace
+//! - `github.stop_codespace` - Stop a codespace
+//! - `github.ssh_keys` - List your SSH keys
+//! - `github.add_ssh_key` - Add an SSH key
+//! - `github.remove_ssh_key` - Remove an SSH key
+//! - `github.gpg_keys` - List your GPG keys
+//! - `github.add_gpg_key` - Add a GPG key
+//! - `github.remove_gpg_key` - Remove a GPG key
+//! - `github.secrets` - List Actions secret names
+//! - `github.set_secret` - Create/update an Actions secret
+//! - `github.delete_secret` - Delete an Actions secret
+//! - `github.variables` - List Actions variables
+//! - `github.set_variable` - Create/update an Actions variable
+//! - `github.delete_variable` - Delete an Actions variable
+//! - `github.run_jobs` - Get workflow run jobs with step timings
+//! - `github.service_status` - GitHub platform status (githubstatus.com)
+//! - `github.transfer_issue` - Transfer an issue to another repository
+//! - `github.pin_issue` - Pin an issue
+//! - `github.unpin_issue` - Unpin an issue
+//! - `github.lock` - Lock an issue/PR conversation
+//! - `github.unlock` - Unlock an issue/PR conversation
+//! - `github.invitations` - List pending repository invitations
+//! - `github.accept_invitation` - Accept a repository invitation
+//! - `github.decline_invitation` - Decline a repository invitation
+//! - `github.create_commit` - Create a multi-file, GitHub-signed commit
+//! - `github.review_threads` - List PR review threads
+//! - `github.resolve_thread` - Resolve a PR review thread
+//! - `github.unresolve_thread` - Unresolve a PR review thread
+//! - `github.list_forks` - List forks of a repository
+//! - `github.sync_fork` - Sync a fork branch with upstream
+//! - `github.pr_score` - PR size/risk summary (lines, files, tests, CODEOWNERS)
+//! - `github.pr_links` - Issues a PR closes / PRs closing an issue
+//! - `github.release_notes` - Draft categorized release notes between tags
+//! - `github.ref_gate` - Are required checks/statuses green on a ref
+//! - `github.start_review` - Start a pending PR review
+//! - `github.add_review_comment` - Add an inline comment to a pending review
+//! - `github.submit_review` - Submit a pending review
+//! - `github.discard_review` - Discard a pending review
+//! - `github.sso_status` - SAML SSO authorization state per org
+//! - `github.org_access_audit` - Org members, outside collaborators, and invitations
+//! - `github.org_repo_summary` - Per-repo health summary across an org
+//! - `github.file_history` - Commits that touched a file
+//! - `github.tree` - List a directory (optionally recursive)
+//! - `github.workflow_config` - Inspect workflow triggers, jobs, runners, and actions
+//! - `github.stale_branches` - Stale branch report with optional merged-branch cleanup
+//! - `github.similar_issues` - Find likely duplicate issues
+//! - `github.remind` - Schedule a reminder search
+//! - `github.reminders` - List reminders and latest results
+//! - `github.cancel_reminder` - Cancel a reminder
+//! - `github.cache_stats` - Show response cache statistics
+//! - `github.cache_clear` - Clear cached responses
+//! - `github.update_issue` - Update an issue's title, body, or state
+//! - `github.set_labels` - Replace labels on an issue or PR
+//! - `github.put_file` - Create or update a single file
+//! - `github.permissions` - Effective repo permission and allowed methods
+//! - `github.events_since` - Replay recorded events after a sequence number
+//! - `github.verify_ref` - Check commit signatures in a range
+//! - `github.bootstrap_repo` - Initial .gitignore, LICENSE, README, and labels
+//! - `github.community_profile` - Community health files for repos or an org
+//! - `github.actions_caches` - List Actions caches
+//! - `github.delete_actions_caches` - Delete Actions caches by key or prefix
+//! - `github.review_metrics` - Review latency and per-reviewer load
+//! - `github.local_search` - Search cached responses without API calls
+//! - `github.plan` - Validate a multi-step change (branch, commits, PR, reviewers)
+//! - `github.apply` - Run a plan, reporting progress and rollback hints
+//!
+//! # Conditional writes
+//! `github.update_issue` and `github.set_labels` accept `expected_updated_at`;
+//! `github.put_file` accepts `expected_sha`. If the object changed upstream the
+//! call fails with a `CONFLICT:` error instead of overwriting it. The
+//! precondition is checked against a fresh read, never the response cache,
+//! and both a successful write and a `CONFLICT:` drop the cached reads of the
+//! object, so the next `github.issues` / `github.pr` read returns the
+//! current `updated_at`.
+//!
+//! # Caching
+//! Read-only methods with a TTL are served from an in-memory LRU backed by
+//! `~/.fgp/services/github/cache/`. TTLs are set per method under `[cache.ttl]`
+//! in `~/.fgp/services/github/config.toml`. Successful writes drop the cached
+//! reads they affect. `github.local_search` searches these cached responses,
+//! including expired ones, plus issue bodies, PR descriptions and file
+//! contents the daemon has fetched, without API calls. Gists are not fetched
+//! by any method, so they are not searchable.
+//!
+//! # Plans
+//! `github.plan` checks an ordered list of steps (create branch, commit, open
+//! PR, request reviewers) against the repository; `github.apply` runs them,
+//! appending `plan_step` progress events. If a step fails it stops and returns
+//! which steps completed and how to undo them.
+//!
+//! # Reminders
+//! `github.remind` schedules a GitHub search on local weekdays/times; a
+//! background scheduler runs it and `github.reminders` returns the latest hits.
+//!
+//! # Events
+//! The scheduler thread also polls notifications, more often while activity
+//! is flowing and less when idle or low on rate budget (bounds under
+//! `[poller]` in the config file). New notifications and reminder hits are appended to `~/.fgp/services/github/events.jsonl` with
+//! sequence numbers; `github.events_since` replays them after a reconnect.
+//!
+//! # Triggers
+//! `[[triggers]]` entries in the config file react to events, e.g. a
+//! `deploy` label added to a PR: they append a named event for subscribers
+//! and/or call an FGP method on a local daemon. Repositories named by
+//! `labeled` triggers are polled for label changes.
+//!
+//! # SSO
+//! Requests blocked by an organization's SAML SSO fail with an error starting
+//! with `SSO_REQUIRED:` that includes the URL to authorize the token.
+//!
+//! # Notification rules
+//! `github.notifications` routes notifications into buckets (e.g. urgent,
+//! review, ignore) using rules in `~/.fgp/services/github/config.toml`.
+//! See `rules.rs` for the format.
+//!
+//! # Call metadata
+//! Any method accepts `"include_meta": true`, which adds a `_meta` block to
+//! the result with GraphQL cost, remaining rate budget, and request counts.
+//!
+//! # Schema versions
+//! Any method accepts `"schema_version": N` to receive responses in layout
+//! version N (default: the current version). Consumers that depend on exact
+//! field names should pin the version they were written against; every method
+//! schema advertises the supported range.
 //!
 //! # Test
 //! ```bash
@@ -39,8 +168,22 @@
 //! 01/12/2026 - Initial implementation with gh CLI wrapper (Claude)
 
 mod api;
+mod bootstrap;
+mod cache;
+mod codeowners;
+mod compat;
+mod config;
+mod events;
+mod local_search;
 mod models;
+mod plan;
+mod poller;
+mod reminders;
+mod rules;
 mod service;
+mod similarity;
+mod triggers;
+mod workflows;
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
@@ -120,6 +263,76 @@ fn cmd_start(socket: String, foreground: bool) -> Result<()> {
     println!("  github.pr             - Get PR details with reviews/checks");
     println!("  github.notifications  - Get unread notifications");
     println!("  github.create_issue   - Create a new issue");
+    println!("  github.merge_queue    - View a branch's merge queue");
+    println!("  github.enqueue_pr     - Add a PR to the merge queue");
+    println!("  github.dequeue_pr     - Remove a PR from the merge queue");
+    println!("  github.download_archive - Download a repo tarball/zipball");
+    println!("  github.codespaces     - List your codespaces");
+    println!("  github.start_codespace - Start a codespace");
+    println!("  github.stop_codespace - Stop a codespace");
+    println!("  github.ssh_keys       - List your SSH keys");
+    println!("  github.add_ssh_key    - Add an SSH key");
+    println!("  github.remove_ssh_key - Remove an SSH key");
+    println!("  github.gpg_keys       - List your GPG keys");
+    println!("  github.add_gpg_key    - Add a GPG key");
+    println!("  github.remove_gpg_key - Remove a GPG key");
+    println!("  github.secrets        - List Actions secret names");
+    println!("  github.set_secret     - Create/update an Actions secret");
+    println!("  github.delete_secret  - Delete an Actions secret");
+    println!("  github.variables      - List Actions variables");
+    println!("  github.set_variable   - Create/update an Actions variable");
+    println!("  github.delete_variable - Delete an Actions variable");
+    println!("  github.run_jobs       - Workflow run jobs with step timings");
+    println!("  github.service_status - GitHub platform status");
+    println!("  github.transfer_issue - Move an issue to another repo");
+    println!("  github.pin_issue      - Pin an issue");
+    println!("  github.unpin_issue    - Unpin an issue");
+    println!("  github.lock           - Lock an issue/PR conversation");
+    println!("  github.unlock         - Unlock an issue/PR conversation");
+    println!("  github.invitations    - List pending repo invitations");
+    println!("  github.accept_invitation - Accept a repo invitation");
+    println!("  github.decline_invitation - Decline a repo invitation");
+    println!("  github.create_commit  - Multi-file GitHub-signed commit");
+    println!("  github.review_threads - List PR review threads");
+    println!("  github.resolve_thread - Resolve a review thread");
+    println!("  github.unresolve_thread - Unresolve a review thread");
+    println!("  github.list_forks     - List forks of a repository");
+    println!("  github.sync_fork      - Sync a fork with upstream");
+    println!("  github.pr_score       - PR size and risk summary");
+    println!("  github.pr_links       - Closing links between PRs and issues");
+    println!("  github.release_notes  - Draft release notes between tags");
+    println!("  github.ref_gate       - Checks/statuses gate for a ref");
+    println!("  github.start_review   - Start a pending PR review");
+    println!("  github.add_review_comment - Comment on a pending review");
+    println!("  github.submit_review  - Submit a pending review");
+    println!("  github.discard_review - Discard a pending review");
+    println!("  github.sso_status     - SSO authorization per org");
+    println!("  github.org_access_audit - Org access review report");
+    println!("  github.org_repo_summary - Fleet health across an org");
+    println!("  github.file_history   - Commit history for a file");
+    println!("  github.tree           - Directory listing");
+    println!("  github.workflow_config - Inspect Actions workflow files");
+    println!("  github.stale_branches - Branch staleness report");
+    println!("  github.similar_issues - Find likely duplicate issues");
+    println!("  github.remind         - Schedule a reminder search");
+    println!("  github.reminders      - List reminders and results");
+    println!("  github.cancel_reminder - Cancel a reminder");
+    println!("  github.cache_stats    - Response cache statistics");
+    println!("  github.cache_clear    - Clear cached responses");
+    println!("  github.update_issue   - Update an issue");
+    println!("  github.set_labels     - Replace issue labels");
+    println!("  github.put_file       - Create or update a file");
+    println!("  github.permissions    - Repo permission and allowed methods");
+    println!("  github.events_since   - Replay recorded events");
+    println!("  github.verify_ref     - Check commit signatures");
+    println!("  github.bootstrap_repo - Bootstrap a new repository");
+    println!("  github.community_profile - Community health report");
+    println!("  github.actions_caches - List Actions caches");
+    println!("  github.delete_actions_caches - Delete Actions caches");
+    println!("  github.review_metrics - Review latency and load");
+    println!("  github.local_search   - Search cached responses");
+    println!("  github.plan           - Validate a multi-step change");
+    println!("  github.apply          - Run a multi-step change");
     println!();
     println!("Test with:");
     println!("  fgp call github.user");
@@ -4,6 +4,9 @@
 //! 01/14/2026 - Initial implementation (Claude)
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::codeowners::CodeOwners;
 
 /// GitHub user.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +47,9 @@ pub struct Repository {
 pub struct Issue {
     pub number: i32,
     pub title: String,
+    /// Only returned by `github.issues` with `include_body`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
     pub state: String,
     pub url: String,
     pub created_at: String,
@@ -51,6 +57,12 @@ pub struct Issue {
     pub author: Option<String>,
     pub labels: Vec<String>,
     pub comment_count: i32,
+    #[serde(default)]
+    pub is_pinned: bool,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub lock_reason: Option<String>,
 }
 
 /// GitHub pull request.
@@ -58,6 +70,9 @@ pub struct Issue {
 pub struct PullRequest {
     pub number: i32,
     pub title: String,
+    /// Description; only populated by single-PR lookups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
     pub state: String,
     pub url: String,
     pub is_draft: bool,
@@ -73,6 +88,15 @@ pub struct PullRequest {
     pub commit_count: i32,
     pub comment_count: i32,
     pub reviews: Vec<Review>,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub lock_reason: Option<String>,
+    /// Review thread counts; only populated by single-PR lookups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_threads: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unresolved_threads: Option<i32>,
 }
 
 /// GitHub PR review.
@@ -94,6 +118,1321 @@ pub struct Notification {
     pub subject_url: Option<String>,
     pub repo_full_name: String,
     pub updated_at: String,
+    /// Routing bucket assigned by the notification rules.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bucket: Option<String>,
+}
+
+/// Merge queue for a branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeQueue {
+    pub branch: String,
+    pub url: String,
+    pub merge_method: Option<String>,
+    pub entries: Vec<MergeQueueEntry>,
+}
+
+/// Entry in a merge queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeQueueEntry {
+    pub id: String,
+    pub position: i32,
+    pub state: String,
+    pub pr_number: i32,
+    pub pr_title: String,
+    pub pr_url: String,
+    pub enqueued_at: String,
+    pub enqueuer: Option<String>,
+    /// Estimated seconds until merge, if GitHub can predict it.
+    pub estimated_time_to_merge: Option<i32>,
+    pub jump: bool,
+    pub solo: bool,
+}
+
+/// GitHub codespace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Codespace {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub state: String,
+    pub repo_full_name: String,
+    pub branch: Option<String>,
+    pub machine_type: Option<String>,
+    pub machine_display_name: Option<String>,
+    pub web_url: String,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+    pub idle_timeout_minutes: Option<i32>,
+}
+
+/// SSH public key registered on the authenticated user's account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SshKey {
+    pub id: i64,
+    pub title: Option<String>,
+    /// OpenSSH-style SHA256 fingerprint, computed from the key blob.
+    pub fingerprint: Option<String>,
+    pub key: String,
+    pub created_at: Option<String>,
+    pub verified: bool,
+    pub read_only: bool,
+}
+
+/// GPG public key registered on the authenticated user's account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpgKey {
+    pub id: i64,
+    pub name: Option<String>,
+    pub key_id: String,
+    pub subkey_ids: Vec<String>,
+    pub emails: Vec<String>,
+    pub can_sign: bool,
+    pub created_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub revoked: bool,
+}
+
+/// Actions secret metadata. Secret values are write-only and never returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsSecret {
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Org secrets only: "all", "private", or "selected".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+}
+
+/// Actions configuration variable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsVariable {
+    pub name: String,
+    pub value: String,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Org variables only: "all", "private", or "selected".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+}
+
+/// Entry in a repository's Actions cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionsCache {
+    pub id: i64,
+    pub key: String,
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub version: Option<String>,
+    pub size_in_bytes: i64,
+    pub created_at: String,
+    pub last_accessed_at: String,
+}
+
+/// Job within a workflow run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowJob {
+    pub id: i64,
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub duration_seconds: Option<i64>,
+    pub runner_name: Option<String>,
+    pub runner_group: Option<String>,
+    pub labels: Vec<String>,
+    pub url: Option<String>,
+    pub run_attempt: Option<i32>,
+    pub steps: Vec<JobStep>,
+}
+
+/// Step within a workflow job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobStep {
+    pub number: i32,
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub duration_seconds: Option<i64>,
+}
+
+/// GitHub platform status from githubstatus.com.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceStatus {
+    /// Overall indicator: "none", "minor", "major", or "critical".
+    pub indicator: String,
+    pub description: String,
+    pub updated_at: String,
+    pub components: Vec<ServiceComponent>,
+    pub incidents: Vec<StatusIncident>,
+}
+
+/// githubstatus.com components the daemon's own methods depend on.
+const DAEMON_COMPONENTS: &[&str] = &["API Requests", "Git Operations", "Actions"];
+
+impl ServiceStatus {
+    /// Components that are not fully operational.
+    pub fn degraded_components(&self) -> Vec<&ServiceComponent> {
+        self.components
+            .iter()
+            .filter(|c| c.status != "operational")
+            .collect()
+    }
+
+    /// Degraded components that affect this daemon (not e.g. Pages or
+    /// Codespaces).
+    pub fn daemon_degraded_components(&self) -> Vec<&ServiceComponent> {
+        self.degraded_components()
+            .into_iter()
+            .filter(|c| DAEMON_COMPONENTS.contains(&c.name.as_str()))
+            .collect()
+    }
+}
+
+/// Status of a single GitHub platform component (e.g. "API Requests").
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceComponent {
+    pub name: String,
+    /// "operational", "degraded_performance", "partial_outage", or "major_outage".
+    pub status: String,
+}
+
+/// Unresolved incident on githubstatus.com.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusIncident {
+    pub name: String,
+    pub status: String,
+    pub impact: String,
+    pub url: Option<String>,
+    pub created_at: String,
+}
+
+/// Pending invitation to collaborate on a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoInvitation {
+    pub id: i64,
+    pub repo_full_name: String,
+    pub inviter: Option<String>,
+    /// Offered permission: "read", "triage", "write", "maintain", or "admin".
+    pub permissions: String,
+    pub created_at: String,
+    pub expired: bool,
+    pub url: String,
+}
+
+/// Commit created through the API.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedCommit {
+    pub oid: String,
+    pub url: String,
+    pub branch: String,
+    pub committed_date: Option<String>,
+    pub additions: usize,
+    pub deletions: usize,
+    pub signature: CommitSignature,
+}
+
+/// Signature verification status of a commit or tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitSignature {
+    pub signed: bool,
+    /// GitHub shows the object as Verified.
+    pub verified: bool,
+    /// Verification state, lowercased (e.g. "valid", "unsigned", "unknown_key").
+    pub reason: String,
+    /// GitHub login of the signing key's owner, when known.
+    pub signer: Option<String>,
+    /// "gpg", "ssh" or "smime".
+    pub kind: Option<String>,
+    pub signed_by_github: bool,
+}
+
+impl CommitSignature {
+    pub fn unsigned() -> Self {
+        Self {
+            signed: false,
+            verified: false,
+            reason: "unsigned".to_string(),
+            signer: None,
+            kind: None,
+            signed_by_github: false,
+        }
+    }
+}
+
+/// Review conversation thread on a pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewThread {
+    /// Node ID, used with github.resolve_thread / github.unresolve_thread.
+    pub id: String,
+    pub is_resolved: bool,
+    pub is_outdated: bool,
+    pub path: Option<String>,
+    pub line: Option<i32>,
+    pub resolved_by: Option<String>,
+    pub comment_count: i32,
+    pub author: Option<String>,
+    pub first_comment: Option<String>,
+}
+
+/// Result of syncing a fork branch with its upstream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForkSyncResult {
+    pub message: String,
+    /// "merge", "fast-forward" or "none" when already up to date.
+    pub merge_type: Option<String>,
+    pub base_branch: Option<String>,
+}
+
+/// File changed in a pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrFile {
+    pub path: String,
+    pub additions: i32,
+    pub deletions: i32,
+}
+
+/// Diff statistics for a pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrFiles {
+    /// Branch the PR merges into.
+    pub base_ref: String,
+    pub additions: i32,
+    pub deletions: i32,
+    pub changed_files: i32,
+    /// First 100 files; fewer than `changed_files` on very large PRs.
+    pub files: Vec<PrFile>,
+}
+
+/// CODEOWNERS area touched by a pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodeownerArea {
+    pub pattern: String,
+    pub owners: Vec<String>,
+    pub files: i32,
+}
+
+/// Size and risk summary for a pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrScore {
+    pub number: i32,
+    pub additions: i32,
+    pub deletions: i32,
+    pub lines_changed: i32,
+    pub files_changed: i32,
+    pub test_files: i32,
+    /// Share of changed files that are tests (0.0 - 1.0).
+    pub test_ratio: f64,
+    /// "XS", "S", "M", "L", or "XL".
+    pub size: String,
+    /// "low", "medium", or "high".
+    pub risk: String,
+    pub risk_factors: Vec<String>,
+    pub codeowner_areas: Vec<CodeownerArea>,
+    /// True if the PR has more files than were inspected.
+    pub files_truncated: bool,
+}
+
+impl PrScore {
+    /// Score a PR from its diff stats and (optional) CODEOWNERS rules.
+    pub fn compute(number: i32, diff: &PrFiles, owners: Option<&CodeOwners>) -> Self {
+        let lines_changed = diff.additions + diff.deletions;
+        let test_files = diff.files.iter().filter(|f| is_test_path(&f.path)).count() as i32;
+        let inspected = diff.files.len() as i32;
+        let test_ratio = if inspected > 0 {
+            f64::from(test_files) / f64::from(inspected)
+        } else {
+            0.0
+        };
+
+        let mut codeowner_areas: Vec<CodeownerArea> = Vec::new();
+        if let Some(owners) = owners {
+            for file in &diff.files {
+                if let Some(rule) = owners.owner_of(&file.path) {
+                    match codeowner_areas
+                        .iter_mut()
+                        .find(|a| a.pattern == rule.pattern)
+                    {
+                        Some(area) => area.files += 1,
+                        None => codeowner_areas.push(CodeownerArea {
+                            pattern: rule.pattern.clone(),
+                            owners: rule.owners.clone(),
+                            files: 1,
+                        }),
+                    }
+                }
+            }
+        }
+
+        let size = match lines_changed {
+            0..=9 => "XS",
+            10..=49 => "S",
+            50..=249 => "M",
+            250..=999 => "L",
+            _ => "XL",
+        };
+
+        let mut points = 0;
+        let mut risk_factors = Vec::new();
+        match size {
+            "M" => points += 1,
+            "L" => points += 2,
+            "XL" => points += 3,
+            _ => {}
+        }
+        if points > 0 {
+            risk_factors.push(format!("{} lines changed ({})", lines_changed, size));
+        }
+        if diff.changed_files > 20 {
+            points += 1;
+            risk_factors.push(format!("{} files touched", diff.changed_files));
+        }
+        if test_files == 0 && inspected > 0 {
+            points += 1;
+            risk_factors.push("no test files changed".to_string());
+        }
+        if codeowner_areas.len() > 2 {
+            points += 1;
+            risk_factors.push(format!("spans {} CODEOWNERS areas", codeowner_areas.len()));
+        }
+
+        let risk = match points {
+            0..=1 => "low",
+            2..=3 => "medium",
+            _ => "high",
+        };
+
+        Self {
+            number,
+            additions: diff.additions,
+            deletions: diff.deletions,
+            lines_changed,
+            files_changed: diff.changed_files,
+            test_files,
+            test_ratio,
+            size: size.to_string(),
+            risk: risk.to_string(),
+            risk_factors,
+            codeowner_areas,
+            files_truncated: diff.changed_files > inspected,
+        }
+    }
+}
+
+/// Heuristic for whether a path is a test file.
+fn is_test_path(path: &str) -> bool {
+    let lower = path.to_lowercase();
+    let name = lower.rsplit('/').next().unwrap_or(&lower);
+    lower
+        .split('/')
+        .any(|dir| matches!(dir, "test" | "tests" | "__tests__" | "spec" | "specs"))
+        || name.starts_with("test_")
+        || name.contains("_test.")
+        || name.contains(".test.")
+        || name.contains(".spec.")
+        || name.contains("_spec.")
+}
+
+/// Issue or pull request referenced by a closing link.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkedItem {
+    pub repo: String,
+    pub number: i32,
+    pub title: String,
+    pub state: String,
+    pub url: String,
+}
+
+/// Closing-reference links for an issue or pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemLinks {
+    /// "pull_request" or "issue".
+    pub kind: String,
+    /// Issues this PR will close when merged (PRs only).
+    pub closes: Vec<LinkedItem>,
+    /// PRs that close this issue (issues only).
+    pub closed_by: Vec<LinkedItem>,
+}
+
+/// Merged pull request included in a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleasePr {
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    pub author: Option<String>,
+    pub labels: Vec<String>,
+    pub merged_at: Option<String>,
+}
+
+/// Category of changes in release notes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseNotesSection {
+    pub title: String,
+    pub prs: Vec<ReleasePr>,
+}
+
+/// Categorized release notes draft.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseNotes {
+    pub from_tag: String,
+    pub to_tag: String,
+    pub commit_count: i32,
+    /// Commits with no associated merged PR.
+    pub unlinked_commits: i32,
+    pub sections: Vec<ReleaseNotesSection>,
+    pub contributors: Vec<String>,
+    /// Markdown rendering of the sections.
+    pub markdown: String,
+    /// Body from GitHub's generate-notes endpoint, when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub github_notes: Option<String>,
+}
+
+/// Release notes categories, in display order, with the labels and
+/// conventional-commit prefixes that select them.
+const RELEASE_CATEGORIES: [(&str, &[&str], &[&str]); 5] = [
+    (
+        "Breaking Changes",
+        &["breaking", "breaking-change", "breaking change"],
+        &[],
+    ),
+    ("Features", &["feature", "enhancement"], &["feat"]),
+    ("Bug Fixes", &["bug", "fix", "bugfix"], &["fix"]),
+    ("Documentation", &["documentation", "docs"], &["docs"]),
+    (
+        "Dependencies",
+        &["dependencies", "deps"],
+        &["deps", "build(deps)"],
+    ),
+];
+
+impl ReleaseNotes {
+    /// Group merged PRs into categories by label, falling back to
+    /// conventional-commit title prefixes, then "Other".
+    pub fn build(
+        from_tag: &str,
+        to_tag: &str,
+        commit_count: i32,
+        unlinked_commits: i32,
+        prs: Vec<ReleasePr>,
+    ) -> Self {
+        let mut sections: Vec<ReleaseNotesSection> = RELEASE_CATEGORIES
+            .iter()
+            .map(|(title, _, _)| title.to_string())
+            .chain(std::iter::once("Other".to_string()))
+            .map(|title| ReleaseNotesSection {
+                title,
+                prs: Vec::new(),
+            })
+            .collect();
+
+        let mut contributors: Vec<String> = Vec::new();
+        for pr in prs {
+            if let Some(author) = &pr.author {
+                if !contributors.contains(author) {
+                    contributors.push(author.clone());
+                }
+            }
+            let idx = release_category(&pr).unwrap_or(RELEASE_CATEGORIES.len());
+            sections[idx].prs.push(pr);
+        }
+        sections.retain(|s| !s.prs.is_empty());
+        contributors.sort_by_key(|c| c.to_lowercase());
+
+        let mut markdown = String::new();
+        for section in &sections {
+            markdown.push_str(&format!("## {}\n\n", section.title));
+            for pr in &section.prs {
+                match &pr.author {
+                    Some(author) => markdown.push_str(&format!(
+                        "- {} by @{} in #{}\n",
+                        pr.title, author, pr.number
+                    )),
+                    None => markdown.push_str(&format!("- {} in #{}\n", pr.title, pr.number)),
+                }
+            }
+            markdown.push('\n');
+        }
+        if !contributors.is_empty() {
+            let mentions: Vec<String> = contributors.iter().map(|c| format!("@{}", c)).collect();
+            markdown.push_str(&format!("## Contributors\n\n{}\n", mentions.join(", ")));
+        }
+
+        Self {
+            from_tag: from_tag.to_string(),
+            to_tag: to_tag.to_string(),
+            commit_count,
+            unlinked_commits,
+            sections,
+            contributors,
+            markdown,
+            github_notes: None,
+        }
+    }
+}
+
+/// Index into `RELEASE_CATEGORIES` for a PR, if any category applies.
+fn release_category(pr: &ReleasePr) -> Option<usize> {
+    let labels: Vec<String> = pr.labels.iter().map(|l| l.to_lowercase()).collect();
+    let by_label = RELEASE_CATEGORIES.iter().position(|(_, names, _)| {
+        labels.iter().any(|l| {
+            names
+                .iter()
+                .any(|n| l == n || l.ends_with(&format!(": {}", n)))
+        })
+    });
+    if by_label.is_some() {
+        return by_label;
+    }
+
+    let title = pr.title.to_lowercase();
+    let prefix = title.split(':').next().unwrap_or("");
+    if prefix.ends_with('!') {
+        return Some(0);
+    }
+    RELEASE_CATEGORIES.iter().position(|(_, _, prefixes)| {
+        prefixes
+            .iter()
+            .any(|p| prefix == *p || prefix.starts_with(&format!("{}(", p)))
+    })
+}
+
+/// Check run or commit status on a ref.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefCheck {
+    /// Check run name or status context.
+    pub name: String,
+    /// "check_run" or "status".
+    pub kind: String,
+    /// Normalized: "success", "failure", "pending", "neutral", or "skipped".
+    pub state: String,
+    pub url: Option<String>,
+}
+
+impl RefCheck {
+    /// Whether this check should let a gate pass.
+    pub fn is_passing(&self) -> bool {
+        matches!(self.state.as_str(), "success" | "neutral" | "skipped")
+    }
+}
+
+/// Gate result for one required context.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateContext {
+    pub name: String,
+    /// "missing" if no check or status with this name was reported.
+    pub state: String,
+    pub passing: bool,
+    pub url: Option<String>,
+}
+
+/// Combined checks/statuses verdict for a commit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefGate {
+    pub sha: String,
+    pub passing: bool,
+    pub contexts: Vec<GateContext>,
+    pub failing: Vec<String>,
+    pub pending: Vec<String>,
+    pub missing: Vec<String>,
+}
+
+impl RefGate {
+    /// Evaluate checks against the required contexts.
+    ///
+    /// With no required contexts, every reported check must pass.
+    pub fn evaluate(sha: String, checks: &[RefCheck], required: &[String]) -> Self {
+        let names: Vec<String> = if required.is_empty() {
+            let mut names: Vec<String> = Vec::new();
+            for check in checks {
+                if !names.contains(&check.name) {
+                    names.push(check.name.clone());
+                }
+            }
+            names
+        } else {
+            required.to_vec()
+        };
+
+        let mut gate = Self {
+            sha,
+            passing: true,
+            contexts: Vec::new(),
+            failing: Vec::new(),
+            pending: Vec::new(),
+            missing: Vec::new(),
+        };
+
+        for name in names {
+            // A context can be reported more than once (e.g. a status and a
+            // check run with the same name); the worst one decides.
+            let matching: Vec<&RefCheck> = checks.iter().filter(|c| c.name == name).collect();
+            let worst = matching
+                .iter()
+                .find(|c| c.state == "failure")
+                .or_else(|| matching.iter().find(|c| c.state == "pending"))
+                .or_else(|| matching.first());
+
+            let context = match worst {
+                Some(check) => GateContext {
+                    name: name.clone(),
+                    state: check.state.clone(),
+                    passing: check.is_passing(),
+                    url: check.url.clone(),
+                },
+                None => GateContext {
+                    name: name.clone(),
+                    state: "missing".to_string(),
+                    passing: false,
+                    url: None,
+                },
+            };
+
+            match context.state.as_str() {
+                "missing" => gate.missing.push(name),
+                "pending" => gate.pending.push(name),
+                _ if !context.passing => gate.failing.push(name),
+                _ => {}
+            }
+            gate.passing &= context.passing;
+            gate.contexts.push(context);
+        }
+
+        gate
+    }
+}
+
+/// Pull request review after submission.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmittedReview {
+    pub id: String,
+    /// "APPROVED", "CHANGES_REQUESTED", or "COMMENTED".
+    pub state: String,
+    pub url: String,
+    pub comment_count: i32,
+}
+
+/// SAML SSO authorization state of the token for one organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoOrgStatus {
+    pub org: String,
+    pub authorized: bool,
+    /// Where to authorize the token, when it isn't yet.
+    pub authorization_url: Option<String>,
+    /// Why the org couldn't be checked; `authorized` is then meaningless.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Organization member and their role.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgMember {
+    pub login: String,
+    /// "admin" or "member".
+    pub role: String,
+}
+
+/// Repository an outside collaborator can access.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoAccess {
+    pub repo: String,
+    /// "read", "triage", "write", "maintain", "admin", or a custom role.
+    pub permission: String,
+}
+
+/// Outside collaborator and every org repository they can access.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutsideCollaborator {
+    pub login: String,
+    pub repos: Vec<RepoAccess>,
+}
+
+/// Pending invitation to join an organization.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrgInvitation {
+    pub id: i64,
+    pub login: Option<String>,
+    pub email: Option<String>,
+    /// "direct_member", "admin", "billing_manager", or "reinstate".
+    pub role: String,
+    pub inviter: Option<String>,
+    pub created_at: String,
+}
+
+/// Health snapshot of one repository in an org portfolio.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoHealth {
+    pub name: String,
+    pub is_archived: bool,
+    pub open_issues: i32,
+    pub open_prs: i32,
+    pub pushed_at: Option<String>,
+    pub default_branch: Option<String>,
+    /// Status rollup of the default branch head: SUCCESS, FAILURE, PENDING, ERROR, or EXPECTED.
+    pub ci_state: Option<String>,
+    pub latest_release: Option<String>,
+    /// Commits on the default branch since the latest release.
+    pub unreleased_commits: Option<i32>,
+}
+
+/// Commit that touched a file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCommit {
+    pub sha: String,
+    pub short_sha: String,
+    pub message: String,
+    pub author_name: Option<String>,
+    pub author_login: Option<String>,
+    pub committed_date: String,
+    pub url: String,
+    /// File path at this commit (differs from the requested path after a rename).
+    pub path: String,
+    pub signature: CommitSignature,
+}
+
+/// Entry in a repository tree listing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreeEntry {
+    /// Path from the repository root.
+    pub path: String,
+    /// "blob", "tree", or "commit" (submodule).
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    /// Size in bytes (blobs only).
+    pub size: Option<i64>,
+    pub sha: String,
+    pub mode: String,
+}
+
+/// Job defined in a workflow file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowJobConfig {
+    pub id: String,
+    /// Runner labels from `runs-on` (empty for reusable-workflow jobs).
+    pub runs_on: Vec<String>,
+    /// Reusable workflow called by the job, if any.
+    pub uses: Option<String>,
+    pub step_count: i32,
+}
+
+/// Action or reusable workflow referenced by a `uses:` key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionRef {
+    /// Full `uses:` value.
+    pub uses: String,
+    pub job: String,
+    /// "action", "reusable_workflow", "local", or "docker".
+    pub kind: String,
+    /// Ref after `@`, if any.
+    pub version: Option<String>,
+    /// Pinned to a full commit SHA.
+    pub pinned: bool,
+    /// Not from the `actions` or `github` organizations, and not local.
+    pub third_party: bool,
+}
+
+/// Parsed GitHub Actions workflow file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowConfig {
+    pub path: String,
+    pub name: Option<String>,
+    pub triggers: Vec<String>,
+    pub jobs: Vec<WorkflowJobConfig>,
+    pub actions: Vec<ActionRef>,
+    /// `uses:` values of third-party actions not pinned to a SHA.
+    pub unpinned_third_party: Vec<String>,
+    /// Set when the file is not valid YAML; other fields are then empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_error: Option<String>,
+}
+
+/// Branch with staleness and merge information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BranchInfo {
+    pub name: String,
+    pub last_commit_date: String,
+    pub author: Option<String>,
+    /// Commits on this branch that are not on the default branch.
+    pub ahead_by: i32,
+    /// Commits on the default branch that are not on this branch.
+    pub behind_by: i32,
+    pub open_pr: Option<i32>,
+    pub merged_pr: Option<i32>,
+    pub protected: bool,
+}
+
+impl BranchInfo {
+    /// Fully contained in the default branch, or its PR was merged.
+    pub fn is_merged(&self) -> bool {
+        self.ahead_by == 0 || self.merged_pr.is_some()
+    }
+}
+
+/// Existing issue that may duplicate a new report.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarIssue {
+    pub number: i32,
+    pub title: String,
+    pub state: String,
+    pub url: String,
+    /// Similarity score (0.0 - 1.0).
+    pub score: f64,
+    /// Title terms shared with the new issue.
+    pub matched_terms: Vec<String>,
+}
+
+/// Issue or pull request returned by a search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    /// "issue" or "pull_request".
+    pub kind: String,
+    pub repo: String,
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    pub author: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Result of writing a single file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileWrite {
+    pub path: String,
+    /// New blob SHA; pass as `expected_sha` on the next write.
+    pub sha: String,
+    pub previous_sha: Option<String>,
+    pub created: bool,
+    pub commit_sha: String,
+    pub commit_url: String,
+    pub commit_signature: CommitSignature,
+}
+
+/// Repository permission level, lowest to highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepoPermission {
+    Read,
+    Triage,
+    Write,
+    Maintain,
+    Admin,
+}
+
+impl RepoPermission {
+    /// Parse a GraphQL `RepositoryPermission` value (e.g. "WRITE").
+    pub fn from_graphql(value: &str) -> Option<Self> {
+        match value {
+            "READ" => Some(Self::Read),
+            "TRIAGE" => Some(Self::Triage),
+            "WRITE" => Some(Self::Write),
+            "MAINTAIN" => Some(Self::Maintain),
+            "ADMIN" => Some(Self::Admin),
+            _ => None,
+        }
+    }
+}
+
+/// Minimum permission for each repo-scoped method, and whether it modifies
+/// the repository (and so is unavailable on archived repos).
+const METHOD_PERMISSIONS: [(&str, RepoPermission, bool); 49] = [
+    ("github.issues", RepoPermission::Read, false),
+    ("github.prs", RepoPermission::Read, false),
+    ("github.pr", RepoPermission::Read, false),
+    ("github.merge_queue", RepoPermission::Read, false),
+    ("github.download_archive", RepoPermission::Read, false),
+    ("github.run_jobs", RepoPermission::Read, false),
+    ("github.review_threads", RepoPermission::Read, false),
+    ("github.list_forks", RepoPermission::Read, false),
+    ("github.pr_score", RepoPermission::Read, false),
+    ("github.pr_links", RepoPermission::Read, false),
+    ("github.release_notes", RepoPermission::Read, false),
+    ("github.ref_gate", RepoPermission::Read, false),
+    ("github.file_history", RepoPermission::Read, false),
+    ("github.tree", RepoPermission::Read, false),
+    ("github.workflow_config", RepoPermission::Read, false),
+    ("github.stale_branches", RepoPermission::Read, false),
+    ("github.similar_issues", RepoPermission::Read, false),
+    ("github.verify_ref", RepoPermission::Read, false),
+    ("github.community_profile", RepoPermission::Read, false),
+    ("github.actions_caches", RepoPermission::Read, false),
+    ("github.review_metrics", RepoPermission::Read, false),
+    ("github.plan", RepoPermission::Read, false),
+    ("github.create_issue", RepoPermission::Read, true),
+    ("github.start_review", RepoPermission::Read, true),
+    ("github.add_review_comment", RepoPermission::Read, true),
+    ("github.submit_review", RepoPermission::Read, true),
+    ("github.discard_review", RepoPermission::Read, true),
+    ("github.set_labels", RepoPermission::Triage, true),
+    ("github.lock", RepoPermission::Triage, true),
+    ("github.unlock", RepoPermission::Triage, true),
+    ("github.update_issue", RepoPermission::Write, true),
+    ("github.resolve_thread", RepoPermission::Write, true),
+    ("github.unresolve_thread", RepoPermission::Write, true),
+    ("github.pin_issue", RepoPermission::Write, true),
+    ("github.unpin_issue", RepoPermission::Write, true),
+    ("github.transfer_issue", RepoPermission::Write, true),
+    ("github.create_commit", RepoPermission::Write, true),
+    ("github.put_file", RepoPermission::Write, true),
+    ("github.bootstrap_repo", RepoPermission::Write, true),
+    ("github.delete_actions_caches", RepoPermission::Write, true),
+    ("github.apply", RepoPermission::Write, true),
+    ("github.enqueue_pr", RepoPermission::Write, true),
+    ("github.dequeue_pr", RepoPermission::Write, true),
+    ("github.secrets", RepoPermission::Admin, false),
+    ("github.set_secret", RepoPermission::Admin, true),
+    ("github.delete_secret", RepoPermission::Admin, true),
+    ("github.variables", RepoPermission::Admin, false),
+    ("github.set_variable", RepoPermission::Admin, true),
+    ("github.delete_variable", RepoPermission::Admin, true),
+];
+
+/// A method the caller can't use on a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeniedMethod {
+    pub method: String,
+    pub requires: RepoPermission,
+    /// Why it's denied: "permission" or "archived".
+    pub reason: String,
+}
+
+/// Caller's effective access to a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoPermissions {
+    pub repo: String,
+    /// None if the caller has no access beyond public visibility.
+    pub permission: Option<RepoPermission>,
+    pub is_archived: bool,
+    pub can_merge: bool,
+    pub can_push: bool,
+    pub can_administer: bool,
+    pub allowed: Vec<String>,
+    pub denied: Vec<DeniedMethod>,
+}
+
+impl RepoPermissions {
+    /// Work out which repo-scoped methods will succeed.
+    ///
+    /// A public repo grants read even when `viewerPermission` is null.
+    pub fn build(
+        repo: String,
+        permission: Option<RepoPermission>,
+        is_private: bool,
+        is_archived: bool,
+    ) -> Self {
+        let effective = permission.or(if is_private {
+            None
+        } else {
+            Some(RepoPermission::Read)
+        });
+        let writable = |level: RepoPermission| !is_archived && effective >= Some(level);
+
+        let mut allowed = Vec::new();
+        let mut denied = Vec::new();
+        for (method, requires, mutates) in METHOD_PERMISSIONS {
+            if effective < Some(requires) {
+                denied.push(DeniedMethod {
+                    method: method.to_string(),
+                    requires,
+                    reason: "permission".to_string(),
+                });
+            } else if mutates && is_archived {
+                denied.push(DeniedMethod {
+                    method: method.to_string(),
+                    requires,
+                    reason: "archived".to_string(),
+                });
+            } else {
+                allowed.push(method.to_string());
+            }
+        }
+
+        Self {
+            repo,
+            permission: effective,
+            is_archived,
+            can_merge: writable(RepoPermission::Write),
+            can_push: writable(RepoPermission::Write),
+            can_administer: effective >= Some(RepoPermission::Admin),
+            allowed,
+            denied,
+        }
+    }
+}
+
+/// Commit checked by github.verify_ref.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitVerification {
+    pub sha: String,
+    pub short_sha: String,
+    pub message: String,
+    pub author_login: Option<String>,
+    pub committed_date: String,
+    pub signature: CommitSignature,
+}
+
+/// Signature report for a range of commits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefVerification {
+    pub repo: String,
+    pub base: Option<String>,
+    pub head: String,
+    pub checked: usize,
+    pub verified: usize,
+    /// Every checked commit (and the head tag, if annotated) is verified.
+    pub all_verified: bool,
+    /// The range had more commits than were checked.
+    pub truncated: bool,
+    pub unverified: Vec<CommitVerification>,
+    /// Signature of `head` when it names an annotated tag.
+    pub tag_signature: Option<CommitSignature>,
+}
+
+impl RefVerification {
+    /// Summarize checked commits.
+    pub fn build(
+        repo: String,
+        base: Option<String>,
+        head: String,
+        commits: Vec<CommitVerification>,
+        truncated: bool,
+        tag_signature: Option<CommitSignature>,
+    ) -> Self {
+        let checked = commits.len();
+        let unverified: Vec<CommitVerification> = commits
+            .into_iter()
+            .filter(|c| !c.signature.verified)
+            .collect();
+        let tag_ok = tag_signature.as_ref().is_none_or(|t| t.verified);
+
+        Self {
+            repo,
+            base,
+            head,
+            checked,
+            verified: checked - unverified.len(),
+            all_verified: unverified.is_empty() && tag_ok && !truncated,
+            truncated,
+            unverified,
+            tag_signature,
+        }
+    }
+}
+
+/// Repository state needed to bootstrap it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoSetup {
+    pub name: String,
+    pub description: Option<String>,
+    /// Display name of the owning user or org, falling back to the login.
+    pub owner_name: String,
+    pub is_empty: bool,
+    pub default_branch: Option<String>,
+    pub labels: Vec<String>,
+}
+
+/// Community health files for a repository.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommunityProfile {
+    pub repo: String,
+    /// GitHub's community health score (0-100).
+    pub health_percentage: i32,
+    pub readme: bool,
+    pub code_of_conduct: bool,
+    pub contributing: bool,
+    pub security_policy: bool,
+    pub issue_templates: bool,
+    pub pull_request_template: bool,
+    /// SPDX id of the detected license.
+    pub license: Option<String>,
+    /// Names of the files above that are absent.
+    pub missing: Vec<String>,
+    pub updated_at: Option<String>,
+}
+
+impl CommunityProfile {
+    /// Fill `missing` from the presence flags.
+    pub fn with_missing(mut self) -> Self {
+        let checks = [
+            ("readme", self.readme),
+            ("code_of_conduct", self.code_of_conduct),
+            ("contributing", self.contributing),
+            ("security_policy", self.security_policy),
+            ("issue_templates", self.issue_templates),
+            ("pull_request_template", self.pull_request_template),
+            ("license", self.license.is_some()),
+        ];
+        self.missing = checks
+            .iter()
+            .filter(|(_, present)| !present)
+            .map(|(name, _)| name.to_string())
+            .collect();
+        self
+    }
+}
+
+/// Review submitted on a pull request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewSample {
+    pub reviewer: String,
+    pub submitted_at: String,
+}
+
+/// Pull request timestamps used by github.review_metrics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrReviewSample {
+    pub repo: String,
+    pub number: i32,
+    pub author: Option<String>,
+    pub created_at: String,
+    pub merged_at: Option<String>,
+    /// Submitted reviews, oldest first.
+    pub reviews: Vec<ReviewSample>,
+}
+
+/// Distribution of a duration, in hours.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DurationStats {
+    pub count: usize,
+    pub median_hours: Option<f64>,
+    pub p90_hours: Option<f64>,
+    pub mean_hours: Option<f64>,
+}
+
+impl DurationStats {
+    fn from_hours(mut hours: Vec<f64>) -> Self {
+        if hours.is_empty() {
+            return Self::default();
+        }
+        hours.sort_by(|a, b| a.total_cmp(b));
+        // Nearest-rank percentile
+        let rank =
+            |p: f64| hours[((p * hours.len() as f64).ceil() as usize).clamp(1, hours.len()) - 1];
+        let round = |h: f64| (h * 10.0).round() / 10.0;
+
+        Self {
+            count: hours.len(),
+            median_hours: Some(round(rank(0.5))),
+            p90_hours: Some(round(rank(0.9))),
+            mean_hours: Some(round(hours.iter().sum::<f64>() / hours.len() as f64)),
+        }
+    }
+}
+
+/// Review activity for one reviewer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewerLoad {
+    pub reviewer: String,
+    /// Reviews submitted (several per PR count separately).
+    pub reviews: usize,
+    pub prs_reviewed: usize,
+    /// Time from PR creation to this reviewer's first review.
+    pub median_response_hours: Option<f64>,
+}
+
+/// Review latency and throughput over a set of pull requests.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewMetrics {
+    pub prs: usize,
+    pub merged: usize,
+    /// PRs with no review from anyone but the author.
+    pub unreviewed: usize,
+    pub time_to_first_review: DurationStats,
+    pub time_to_merge: DurationStats,
+    /// Busiest reviewers first.
+    pub reviewers: Vec<ReviewerLoad>,
+}
+
+impl ReviewMetrics {
+    /// Aggregate samples. Self-reviews are ignored.
+    pub fn compute(samples: &[PrReviewSample]) -> Self {
+        fn hours_between(from: &str, to: &str) -> Option<f64> {
+            let from = chrono::DateTime::parse_from_rfc3339(from).ok()?;
+            let to = chrono::DateTime::parse_from_rfc3339(to).ok()?;
+            Some((to - from).num_seconds().max(0) as f64 / 3600.0)
+        }
+
+        let mut first_review = Vec::new();
+        let mut to_merge = Vec::new();
+        let mut unreviewed = 0;
+        // reviewer -> (reviews, response hours per PR)
+        let mut load: HashMap<String, (usize, Vec<f64>)> = HashMap::new();
+
+        for pr in samples {
+            if let Some(merged_at) = &pr.merged_at {
+                to_merge.extend(hours_between(&pr.created_at, merged_at));
+            }
+
+            let reviews: Vec<&ReviewSample> = pr
+                .reviews
+                .iter()
+                .filter(|r| pr.author.as_deref() != Some(r.reviewer.as_str()))
+                .collect();
+            match reviews.first() {
+                Some(first) => {
+                    first_review.extend(hours_between(&pr.created_at, &first.submitted_at))
+                }
+                None => unreviewed += 1,
+            }
+
+            let mut seen: Vec<&str> = Vec::new();
+            for review in reviews {
+                let entry = load.entry(review.reviewer.clone()).or_default();
+                entry.0 += 1;
+                if !seen.contains(&review.reviewer.as_str()) {
+                    seen.push(&review.reviewer);
+                    entry
+                        .1
+                        .extend(hours_between(&pr.created_at, &review.submitted_at));
+                }
+            }
+        }
+
+        let mut reviewers: Vec<ReviewerLoad> = load
+            .into_iter()
+            .map(|(reviewer, (reviews, responses))| ReviewerLoad {
+                reviewer,
+                reviews,
+                prs_reviewed: responses.len(),
+                median_response_hours: DurationStats::from_hours(responses).median_hours,
+            })
+            .collect();
+        reviewers.sort_by(|a, b| {
+            b.prs_reviewed
+                .cmp(&a.prs_reviewed)
+                .then(b.reviews.cmp(&a.reviews))
+                .then_with(|| a.reviewer.cmp(&b.reviewer))
+        });
+
+        Self {
+            prs: samples.len(),
+            merged: to_merge.len(),
+            unreviewed,
+            time_to_first_review: DurationStats::from_hours(first_review),
+            time_to_merge: DurationStats::from_hours(to_merge),
+            reviewers,
+        }
+    }
+}
+
+/// A label added to or removed from an issue or PR.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LabelEvent {
+    /// Issue event ID (increases over time within a repo).
+    pub event_id: i64,
+    /// "labeled" or "unlabeled".
+    pub action: String,
+    pub repo: String,
+    pub number: i32,
+    pub title: String,
+    pub url: String,
+    pub is_pull_request: bool,
+    pub label: String,
+    pub actor: Option<String>,
+    pub created_at: String,
 }
 
 /// GraphQL response wrapper.
@@ -170,6 +1509,7 @@ mod tests {
         let issue = Issue {
             number: 42,
             title: "Found a bug".to_string(),
+            body: Some("Steps to reproduce".to_string()),
             state: "OPEN".to_string(),
             url: "https://github.com/octocat/repo/issues/42".to_string(),
             created_at: "2024-01-14T00:00:00Z".to_string(),
@@ -177,6 +1517,9 @@ mod tests {
             author: Some("octocat".to_string()),
             labels: vec!["bug".to_string(), "help wanted".to_string()],
             comment_count: 5,
+            is_pinned: true,
+            locked: false,
+            lock_reason: None,
         };
 
         let json = serde_json::to_string(&issue).unwrap();
@@ -184,6 +1527,7 @@ mod tests {
 
         assert_eq!(parsed.number, 42);
         assert_eq!(parsed.labels.len(), 2);
+        assert!(parsed.is_pinned);
     }
 
     #[test]
@@ -191,6 +1535,7 @@ mod tests {
         let pr = PullRequest {
             number: 123,
             title: "Add new feature".to_string(),
+            body: None,
             state: "OPEN".to_string(),
             url: "https://github.com/octocat/repo/pull/123".to_string(),
             is_draft: false,
@@ -210,6 +1555,10 @@ mod tests {
                 state: "APPROVED".to_string(),
                 submitted_at: Some("2024-01-14T00:00:00Z".to_string()),
             }],
+            locked: true,
+            lock_reason: Some("TOO_HEATED".to_string()),
+            resolved_threads: Some(2),
+            unresolved_threads: Some(1),
         };
 
         let json = serde_json::to_string(&pr).unwrap();
@@ -218,6 +1567,7 @@ mod tests {
         assert_eq!(parsed.number, 123);
         assert_eq!(parsed.reviews.len(), 1);
         assert_eq!(parsed.reviews[0].state, "APPROVED");
+        assert_eq!(parsed.lock_reason.as_deref(), Some("TOO_HEATED"));
     }
 
     #[test]
@@ -231,6 +1581,7 @@ mod tests {
             subject_url: Some("https://api.github.com/repos/octocat/repo/issues/42".to_string()),
             repo_full_name: "octocat/repo".to_string(),
             updated_at: "2024-01-14T00:00:00Z".to_string(),
+            bucket: None,
         };
 
         let json = serde_json::to_string(&notification).unwrap();
@@ -239,4 +1590,336 @@ mod tests {
         assert_eq!(parsed.id, "12345");
         assert!(parsed.unread);
     }
+
+    #[test]
+    fn test_merge_queue_serialization() {
+        let queue = MergeQueue {
+            branch: "main".to_string(),
+            url: "https://github.com/octocat/repo/queue/main".to_string(),
+            merge_method: Some("SQUASH".to_string()),
+            entries: vec![MergeQueueEntry {
+                id: "MQE_123".to_string(),
+                position: 1,
+                state: "AWAITING_CHECKS".to_string(),
+                pr_number: 42,
+                pr_title: "Add new feature".to_string(),
+                pr_url: "https://github.com/octocat/repo/pull/42".to_string(),
+                enqueued_at: "2024-01-14T00:00:00Z".to_string(),
+                enqueuer: Some("octocat".to_string()),
+                estimated_time_to_merge: Some(600),
+                jump: false,
+                solo: false,
+            }],
+        };
+
+        let json = serde_json::to_string(&queue).unwrap();
+        let parsed: MergeQueue = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.entries.len(), 1);
+        assert_eq!(parsed.entries[0].pr_number, 42);
+        assert_eq!(parsed.entries[0].estimated_time_to_merge, Some(600));
+    }
+
+    #[test]
+    fn test_service_status_degraded_components() {
+        let status = ServiceStatus {
+            indicator: "minor".to_string(),
+            description: "Minor Service Outage".to_string(),
+            updated_at: "2024-01-14T00:00:00Z".to_string(),
+            components: vec![
+                ServiceComponent {
+                    name: "Git Operations".to_string(),
+                    status: "operational".to_string(),
+                },
+                ServiceComponent {
+                    name: "API Requests".to_string(),
+                    status: "degraded_performance".to_string(),
+                },
+                ServiceComponent {
+                    name: "Pages".to_string(),
+                    status: "major_outage".to_string(),
+                },
+            ],
+            incidents: vec![],
+        };
+
+        let degraded = status.degraded_components();
+        assert_eq!(degraded.len(), 2);
+        let affecting = status.daemon_degraded_components();
+        assert_eq!(affecting.len(), 1);
+        assert_eq!(affecting[0].name, "API Requests");
+    }
+
+    #[test]
+    fn test_pr_score() {
+        let file = |path: &str, additions| PrFile {
+            path: path.to_string(),
+            additions,
+            deletions: 0,
+        };
+        let diff = PrFiles {
+            base_ref: "main".to_string(),
+            additions: 300,
+            deletions: 20,
+            changed_files: 4,
+            files: vec![
+                file("src/api/client.rs", 200),
+                file("src/service.rs", 60),
+                file("docs/usage.md", 20),
+                file("tests/client_test.rs", 20),
+            ],
+        };
+        let owners = CodeOwners::parse(
+            "* @org/core
+/src/api/ @org/api
+*.md @org/docs
+",
+        );
+
+        let score = PrScore::compute(7, &diff, Some(&owners));
+        assert_eq!(score.lines_changed, 320);
+        assert_eq!(score.size, "L");
+        assert_eq!(score.test_files, 1);
+        assert!((score.test_ratio - 0.25).abs() < f64::EPSILON);
+        assert_eq!(score.codeowner_areas.len(), 3);
+        assert_eq!(score.risk, "medium");
+        assert!(!score.files_truncated);
+
+        let untested = PrFiles {
+            base_ref: "main".to_string(),
+            files: vec![file("src/main.rs", 5)],
+            additions: 5,
+            deletions: 0,
+            changed_files: 1,
+        };
+        let score = PrScore::compute(8, &untested, None);
+        assert_eq!(score.size, "XS");
+        assert_eq!(score.risk, "low");
+        assert_eq!(score.risk_factors, ["no test files changed"]);
+    }
+
+    #[test]
+    fn test_release_notes_categories() {
+        let pr = |number, title: &str, labels: &[&str], author: &str| ReleasePr {
+            number,
+            title: title.to_string(),
+            url: format!("https://github.com/o/r/pull/{}", number),
+            author: Some(author.to_string()),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            merged_at: None,
+        };
+
+        let notes = ReleaseNotes::build(
+            "v1.0.0",
+            "v1.1.0",
+            6,
+            1,
+            vec![
+                pr(1, "Add dark mode", &["enhancement"], "alice"),
+                pr(2, "fix(api): handle 404", &[], "bob"),
+                pr(3, "feat!: drop v1 endpoints", &[], "alice"),
+                pr(4, "Bump serde", &["type: dependencies"], "dependabot"),
+                pr(5, "Tidy imports", &[], "Carol"),
+            ],
+        );
+
+        let titles: Vec<&str> = notes.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            [
+                "Breaking Changes",
+                "Features",
+                "Bug Fixes",
+                "Dependencies",
+                "Other"
+            ]
+        );
+        assert_eq!(notes.sections[0].prs[0].number, 3);
+        assert_eq!(notes.contributors, ["alice", "bob", "Carol", "dependabot"]);
+        assert!(notes.markdown.contains("- Add dark mode by @alice in #1\n"));
+        assert!(notes
+            .markdown
+            .ends_with("@alice, @bob, @Carol, @dependabot\n"));
+    }
+
+    #[test]
+    fn test_ref_gate() {
+        let check = |name: &str, state: &str| RefCheck {
+            name: name.to_string(),
+            kind: "check_run".to_string(),
+            state: state.to_string(),
+            url: None,
+        };
+        let checks = vec![
+            check("build", "success"),
+            check("lint", "skipped"),
+            check("test", "pending"),
+            check("deploy-preview", "failure"),
+        ];
+
+        let required = vec!["build".to_string(), "lint".to_string()];
+        let gate = RefGate::evaluate("abc".to_string(), &checks, &required);
+        assert!(gate.passing);
+        assert_eq!(gate.contexts.len(), 2);
+
+        let required = vec!["build".to_string(), "test".to_string(), "e2e".to_string()];
+        let gate = RefGate::evaluate("abc".to_string(), &checks, &required);
+        assert!(!gate.passing);
+        assert_eq!(gate.pending, ["test"]);
+        assert_eq!(gate.missing, ["e2e"]);
+
+        let gate = RefGate::evaluate("abc".to_string(), &checks, &[]);
+        assert!(!gate.passing);
+        assert_eq!(gate.failing, ["deploy-preview"]);
+        assert_eq!(gate.contexts.len(), 4);
+    }
+
+    #[test]
+    fn test_repo_permissions() {
+        let triage = RepoPermissions::build(
+            "o/r".to_string(),
+            RepoPermission::from_graphql("TRIAGE"),
+            true,
+            false,
+        );
+        assert_eq!(triage.permission, Some(RepoPermission::Triage));
+        assert!(!triage.can_merge);
+        assert!(triage.allowed.contains(&"github.set_labels".to_string()));
+        let denied = triage
+            .denied
+            .iter()
+            .find(|d| d.method == "github.enqueue_pr")
+            .unwrap();
+        assert_eq!(denied.requires, RepoPermission::Write);
+
+        let archived =
+            RepoPermissions::build("o/r".to_string(), Some(RepoPermission::Admin), false, true);
+        assert!(!archived.can_push);
+        assert!(archived.can_administer);
+        assert!(archived.allowed.contains(&"github.secrets".to_string()));
+        assert!(archived.denied.iter().all(|d| d.reason == "archived"));
+
+        let public = RepoPermissions::build("o/r".to_string(), None, false, false);
+        assert_eq!(public.permission, Some(RepoPermission::Read));
+        assert!(public.allowed.contains(&"github.create_issue".to_string()));
+
+        let hidden = RepoPermissions::build("o/r".to_string(), None, true, false);
+        assert!(hidden.allowed.is_empty());
+    }
+
+    #[test]
+    fn test_ref_verification_build() {
+        let commit = |sha: &str, verified: bool| CommitVerification {
+            sha: sha.to_string(),
+            short_sha: sha.to_string(),
+            message: "msg".to_string(),
+            author_login: None,
+            committed_date: "2024-01-14T10:00:00Z".to_string(),
+            signature: CommitSignature {
+                verified,
+                ..CommitSignature::unsigned()
+            },
+        };
+
+        let report = RefVerification::build(
+            "o/r".to_string(),
+            None,
+            "main".to_string(),
+            vec![commit("a", true), commit("b", false)],
+            false,
+            None,
+        );
+        assert_eq!(report.checked, 2);
+        assert_eq!(report.verified, 1);
+        assert!(!report.all_verified);
+        assert_eq!(report.unverified[0].sha, "b");
+
+        let signed = |verified| {
+            RefVerification::build(
+                "o/r".to_string(),
+                None,
+                "v1".to_string(),
+                vec![commit("a", true)],
+                false,
+                Some(CommitSignature {
+                    verified,
+                    ..CommitSignature::unsigned()
+                }),
+            )
+        };
+        assert!(signed(true).all_verified);
+        assert!(!signed(false).all_verified);
+    }
+
+    #[test]
+    fn test_community_profile_missing() {
+        let profile = CommunityProfile {
+            repo: "o/r".to_string(),
+            health_percentage: 57,
+            readme: true,
+            code_of_conduct: false,
+            contributing: true,
+            security_policy: false,
+            issue_templates: true,
+            pull_request_template: true,
+            license: Some("MIT".to_string()),
+            missing: Vec::new(),
+            updated_at: None,
+        }
+        .with_missing();
+        assert_eq!(profile.missing, ["code_of_conduct", "security_policy"]);
+    }
+
+    #[test]
+    fn test_review_metrics() {
+        let review = |reviewer: &str, at: &str| ReviewSample {
+            reviewer: reviewer.to_string(),
+            submitted_at: at.to_string(),
+        };
+        let samples = vec![
+            PrReviewSample {
+                repo: "o/r".to_string(),
+                number: 1,
+                author: Some("alice".to_string()),
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                merged_at: Some("2024-01-02T00:00:00Z".to_string()),
+                reviews: vec![
+                    review("alice", "2024-01-01T01:00:00Z"),
+                    review("bob", "2024-01-01T02:00:00Z"),
+                    review("bob", "2024-01-01T05:00:00Z"),
+                ],
+            },
+            PrReviewSample {
+                repo: "o/r".to_string(),
+                number: 2,
+                author: Some("bob".to_string()),
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                merged_at: None,
+                reviews: vec![review("carol", "2024-01-01T04:00:00Z")],
+            },
+            PrReviewSample {
+                repo: "o/r".to_string(),
+                number: 3,
+                author: Some("carol".to_string()),
+                created_at: "2024-01-01T00:00:00Z".to_string(),
+                merged_at: None,
+                reviews: vec![review("carol", "2024-01-01T00:30:00Z")],
+            },
+        ];
+
+        let metrics = ReviewMetrics::compute(&samples);
+        assert_eq!(metrics.prs, 3);
+        assert_eq!(metrics.merged, 1);
+        assert_eq!(metrics.unreviewed, 1);
+        assert_eq!(metrics.time_to_first_review.count, 2);
+        assert_eq!(metrics.time_to_first_review.median_hours, Some(2.0));
+        assert_eq!(metrics.time_to_first_review.p90_hours, Some(4.0));
+        assert_eq!(metrics.time_to_merge.median_hours, Some(24.0));
+
+        assert_eq!(metrics.reviewers[0].reviewer, "bob");
+        assert_eq!(metrics.reviewers[0].reviews, 2);
+        assert_eq!(metrics.reviewers[0].prs_reviewed, 1);
+        assert_eq!(metrics.reviewers[0].median_response_hours, Some(2.0));
+        assert!(metrics.reviewers.iter().all(|r| r.reviewer != "alice"));
+    }
 }
@@ -0,0 +1,373 @@
+//! Multi-step mutation plans for `github.plan` and `github.apply`.
+//!
+//! A plan is an ordered list of steps against one repository: create a
+//! branch, commit files, open a pull request, request reviewers. Later steps
+//! default to what earlier ones produced — `commit` and `open_pr` use the last
+//! branch the plan created, `request_reviewers` the pull request it opened —
+//! so a typical flow needs no cross-references. `github.apply` runs the steps
+//! in order, stops at the first failure, and returns rollback hints for the
+//! steps that completed.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Most steps accepted in one plan.
+pub const MAX_STEPS: usize = 20;
+
+/// One mutation in a plan.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case", deny_unknown_fields)]
+pub enum Step {
+    CreateBranch {
+        branch: String,
+        /// Branch to start from; the default branch if omitted.
+        #[serde(default)]
+        from: Option<String>,
+    },
+    Commit {
+        #[serde(default)]
+        branch: Option<String>,
+        message: String,
+        /// `{path, content | content_base64}` objects, as in `github.create_commit`.
+        #[serde(default)]
+        additions: Vec<Value>,
+        #[serde(default)]
+        deletions: Vec<String>,
+    },
+    OpenPr {
+        title: String,
+        #[serde(default)]
+        body: Option<String>,
+        #[serde(default)]
+        head: Option<String>,
+        #[serde(default)]
+        base: Option<String>,
+        #[serde(default)]
+        draft: bool,
+    },
+    RequestReviewers {
+        /// Pull request number; the one opened by the plan if omitted.
+        #[serde(default)]
+        number: Option<i32>,
+        #[serde(default)]
+        reviewers: Vec<String>,
+        #[serde(default)]
+        team_reviewers: Vec<String>,
+    },
+}
+
+impl Step {
+    pub fn op(&self) -> &'static str {
+        match self {
+            Step::CreateBranch { .. } => "create_branch",
+            Step::Commit { .. } => "commit",
+            Step::OpenPr { .. } => "open_pr",
+            Step::RequestReviewers { .. } => "request_reviewers",
+        }
+    }
+
+    /// One-line summary for plan output and progress events.
+    pub fn describe(&self) -> String {
+        match self {
+            Step::CreateBranch { branch, from } => format!(
+                "Create branch '{}' from {}",
+                branch,
+                from.as_deref()
+                    .map(|f| format!("'{}'", f))
+                    .unwrap_or_else(|| "the default branch".to_string())
+            ),
+            Step::Commit {
+                branch,
+                message,
+                additions,
+                deletions,
+            } => format!(
+                "Commit {} addition(s), {} deletion(s) to '{}': {}",
+                additions.len(),
+                deletions.len(),
+                branch.as_deref().unwrap_or_default(),
+                message.lines().next().unwrap_or_default()
+            ),
+            Step::OpenPr {
+                title,
+                head,
+                base,
+                draft,
+                ..
+            } => format!(
+                "Open {}pull request '{}' from '{}' into {}",
+                if *draft { "draft " } else { "" },
+                title,
+                head.as_deref().unwrap_or_default(),
+                base.as_deref()
+                    .map(|b| format!("'{}'", b))
+                    .unwrap_or_else(|| "the default branch".to_string())
+            ),
+            Step::RequestReviewers {
+                number,
+                reviewers,
+                team_reviewers,
+            } => format!(
+                "Request reviews on {} from {}",
+                number
+                    .map(|n| format!("#{}", n))
+                    .unwrap_or_else(|| "the new pull request".to_string()),
+                reviewers
+                    .iter()
+                    .cloned()
+                    .chain(team_reviewers.iter().map(|t| format!("team {}", t)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// Parse and validate a plan's steps, filling in defaults from earlier steps.
+pub fn parse(steps: &Value) -> Result<Vec<Step>> {
+    let items = steps
+        .as_array()
+        .context("'steps' must be an array of step objects")?;
+    if items.is_empty() {
+        bail!("Plan has no steps");
+    }
+    if items.len() > MAX_STEPS {
+        bail!("Plan has {} steps; the limit is {}", items.len(), MAX_STEPS);
+    }
+
+    let mut created: Vec<(String, Option<String>)> = Vec::new();
+    let mut opens_pr = false;
+    let mut parsed = Vec::with_capacity(items.len());
+
+    for (i, item) in items.iter().enumerate() {
+        let n = i + 1;
+        let mut step: Step = serde_json::from_value(item.clone())
+            .map_err(|e| anyhow::anyhow!("Step {}: {}", n, e))?;
+        let last_branch = created.last().map(|(b, _)| b.clone());
+
+        match &mut step {
+            Step::CreateBranch { branch, from } => {
+                if created.iter().any(|(b, _)| b == branch) {
+                    bail!("Step {}: branch '{}' is created twice", n, branch);
+                }
+                created.push((branch.clone(), from.clone()));
+            }
+            Step::Commit {
+                branch,
+                additions,
+                deletions,
+                ..
+            } => {
+                if additions.is_empty() && deletions.is_empty() {
+                    bail!("Step {}: nothing to commit", n);
+                }
+                if branch.is_none() {
+                    *branch = Some(last_branch.with_context(|| {
+                        format!(
+                            "Step {}: commit needs 'branch' (no branch created before it)",
+                            n
+                        )
+                    })?);
+                }
+            }
+            Step::OpenPr { head, base, .. } => {
+                if head.is_none() {
+                    *head = Some(last_branch.with_context(|| {
+                        format!(
+                            "Step {}: open_pr needs 'head' (no branch created before it)",
+                            n
+                        )
+                    })?);
+                }
+                // A branch cut from another branch merges back into it
+                if base.is_none() {
+                    *base = created
+                        .iter()
+                        .find(|(b, _)| Some(b) == head.as_ref())
+                        .and_then(|(_, from)| from.clone());
+                }
+                opens_pr = true;
+            }
+            Step::RequestReviewers {
+                number,
+                reviewers,
+                team_reviewers,
+            } => {
+                if reviewers.is_empty() && team_reviewers.is_empty() {
+                    bail!(
+                        "Step {}: request_reviewers needs 'reviewers' or 'team_reviewers'",
+                        n
+                    );
+                }
+                if number.is_none() && !opens_pr {
+                    bail!(
+                        "Step {}: request_reviewers needs 'number' (no pull request opened before it)",
+                        n
+                    );
+                }
+            }
+        }
+        parsed.push(step);
+    }
+    Ok(parsed)
+}
+
+/// How to undo a completed step.
+#[derive(Debug, Clone, Serialize)]
+pub struct RollbackHint {
+    pub step: usize,
+    pub description: String,
+    /// FGP method that undoes the step, when there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+    /// REST request that undoes the step otherwise, e.g.
+    /// `DELETE /repos/o/r/git/refs/heads/x`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Value>,
+}
+
+/// Rollback hints for the completed steps, most recent first.
+///
+/// `outputs[i]` is what `github.apply` recorded for `steps[i]`. Commits to a
+/// branch the plan created need no hint of their own: deleting the branch
+/// undoes them.
+pub fn rollback(repo: &str, steps: &[Step], outputs: &[Value]) -> Vec<RollbackHint> {
+    let created: Vec<&str> = steps
+        .iter()
+        .zip(outputs)
+        .filter_map(|(step, _)| match step {
+            Step::CreateBranch { branch, .. } => Some(branch.as_str()),
+            _ => None,
+        })
+        .collect();
+    let hint = |step: usize, description: String| RollbackHint {
+        step,
+        description,
+        method: None,
+        params: None,
+        request: None,
+        body: None,
+    };
+
+    let mut hints = Vec::new();
+    for (i, (step, output)) in steps.iter().zip(outputs).enumerate().rev() {
+        let n = i + 1;
+        match step {
+            Step::CreateBranch { branch, .. } => hints.push(RollbackHint {
+                request: Some(format!("DELETE /repos/{}/git/refs/heads/{}", repo, branch)),
+                ..hint(n, format!("Delete branch '{}'", branch))
+            }),
+            Step::Commit { branch, .. } => {
+                let branch = branch.as_deref().unwrap_or_default();
+                if created.contains(&branch) {
+                    continue;
+                }
+                if let Some(previous) = output.get("previous_head").and_then(|v| v.as_str()) {
+                    hints.push(RollbackHint {
+                        request: Some(format!("PATCH /repos/{}/git/refs/heads/{}", repo, branch)),
+                        body: Some(serde_json::json!({ "sha": previous, "force": true })),
+                        ..hint(n, format!("Reset branch '{}' to {}", branch, previous))
+                    });
+                }
+            }
+            Step::OpenPr { .. } => {
+                if let Some(number) = output.get("number").and_then(|v| v.as_i64()) {
+                    hints.push(RollbackHint {
+                        method: Some("github.update_issue".to_string()),
+                        params: Some(serde_json::json!({
+                            "repo": repo,
+                            "number": number,
+                            "state": "closed",
+                        })),
+                        ..hint(n, format!("Close pull request #{}", number))
+                    });
+                }
+            }
+            Step::RequestReviewers {
+                reviewers,
+                team_reviewers,
+                ..
+            } => {
+                if let Some(number) = output.get("number").and_then(|v| v.as_i64()) {
+                    hints.push(RollbackHint {
+                        request: Some(format!(
+                            "DELETE /repos/{}/pulls/{}/requested_reviewers",
+                            repo, number
+                        )),
+                        body: Some(serde_json::json!({
+                            "reviewers": reviewers,
+                            "team_reviewers": team_reviewers,
+                        })),
+                        ..hint(n, format!("Withdraw review requests on #{}", number))
+                    });
+                }
+            }
+        }
+    }
+    hints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_fills_defaults() {
+        let steps = parse(&json!([
+            {"op": "create_branch", "branch": "fix", "from": "release"},
+            {"op": "commit", "message": "Fix", "additions": [{"path": "a", "content": "x"}]},
+            {"op": "open_pr", "title": "Fix"},
+            {"op": "request_reviewers", "reviewers": ["octocat"]}
+        ]))
+        .unwrap();
+
+        assert!(matches!(&steps[1], Step::Commit { branch: Some(b), .. } if b == "fix"));
+        assert!(matches!(
+            &steps[2],
+            Step::OpenPr { head: Some(h), base: Some(b), .. } if h == "fix" && b == "release"
+        ));
+
+        // Missing references and typos are caught before anything runs
+        assert!(parse(&json!([{"op": "commit", "message": "x", "deletions": ["a"]}])).is_err());
+        assert!(parse(&json!([{"op": "request_reviewers", "reviewers": ["a"]}])).is_err());
+        assert!(parse(&json!([{"op": "create_branch", "brnach": "x"}])).is_err());
+        assert!(parse(&json!([{"op": "merge"}])).is_err());
+        assert!(parse(&json!([])).is_err());
+    }
+
+    #[test]
+    fn test_rollback_hints() {
+        let steps = parse(&json!([
+            {"op": "commit", "branch": "main", "message": "Bump", "deletions": ["old"]},
+            {"op": "create_branch", "branch": "fix"},
+            {"op": "commit", "message": "Fix", "deletions": ["a"]},
+            {"op": "open_pr", "title": "Fix"}
+        ]))
+        .unwrap();
+        let outputs = [
+            json!({"previous_head": "abc"}),
+            json!({"branch": "fix"}),
+            json!({"previous_head": "def"}),
+            json!({"number": 7}),
+        ];
+
+        let hints = rollback("o/r", &steps, &outputs);
+        let described: Vec<_> = hints.iter().map(|h| h.step).collect();
+        assert_eq!(described, [4, 2, 1]);
+        assert_eq!(hints[0].method.as_deref(), Some("github.update_issue"));
+        assert_eq!(
+            hints[1].request.as_deref(),
+            Some("DELETE /repos/o/r/git/refs/heads/fix")
+        );
+        assert_eq!(hints[2].body, Some(json!({"sha": "abc", "force": true})));
+
+        // Only completed steps get hints
+        assert_eq!(rollback("o/r", &steps, &outputs[..2]).len(), 2);
+    }
+}
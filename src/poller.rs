@@ -0,0 +1,399 @@
+//! Background notification and label pollers.
+//!
+//! Runs on the scheduler thread and records each new or updated notification
+//! as a `notification` event in the [`EventLog`]. The watermark (latest
+//! `updated_at` seen) is recovered from the log on startup, so a restart
+//! neither replays old notifications nor drops ones that arrived meanwhile.
+//! Repositories named by label triggers are also polled for label changes,
+//! recorded as `labeled` / `unlabeled` events.
+//!
+//! Each poller's interval adapts to traffic: it drops to the minimum as soon as a poll
+//! records something and doubles after each quiet or failed poll, up to the
+//! maximum. While the REST rate budget is below the reserve it stays at the
+//! maximum. Bounds come from the `[poller]` section of
+//! `~/.fgp/services/github/config.toml`:
+//!
+//! ```toml
+//! [poller]
+//! min_interval = "15s"
+//! max_interval = "10m"
+//! reserve_requests = 500
+//! ```
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::runtime::Handle;
+
+use crate::api::{meta, GitHubClient};
+use crate::cache::parse_ttl;
+use crate::events::EventLog;
+use crate::models::Notification;
+use crate::rules::{NotificationRules, IGNORE_BUCKET};
+
+/// Event kind for notifications.
+pub const NOTIFICATION_EVENT: &str = "notification";
+
+/// Event kinds for label changes.
+pub const LABELED_EVENT: &str = "labeled";
+pub const UNLABELED_EVENT: &str = "unlabeled";
+
+/// The `[poller]` section of the daemon config file.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct PollerSection {
+    min_interval: Option<String>,
+    max_interval: Option<String>,
+    reserve_requests: Option<i64>,
+}
+
+/// Poll interval bounds and rate budget reserve.
+#[derive(Debug, Clone)]
+pub struct PollerConfig {
+    pub min_interval: Duration,
+    pub max_interval: Duration,
+    /// Below this many remaining REST requests, poll at `max_interval`.
+    pub reserve_requests: i64,
+}
+
+impl Default for PollerConfig {
+    fn default() -> Self {
+        Self {
+            min_interval: Duration::from_secs(15),
+            max_interval: Duration::from_secs(600),
+            reserve_requests: 500,
+        }
+    }
+}
+
+impl PollerConfig {
+    /// Apply the `[poller]` section over the defaults.
+    pub(crate) fn from_section(section: PollerSection) -> Result<Self> {
+        let mut config = Self::default();
+        if let Some(text) = section.min_interval {
+            config.min_interval = Duration::from_secs(parse_ttl(&text)?);
+        }
+        if let Some(text) = section.max_interval {
+            config.max_interval = Duration::from_secs(parse_ttl(&text)?);
+        }
+        if let Some(reserve) = section.reserve_requests {
+            config.reserve_requests = reserve;
+        }
+        if config.min_interval.is_zero() || config.min_interval > config.max_interval {
+            bail!("poller.min_interval must be non-zero and at most max_interval");
+        }
+        Ok(config)
+    }
+
+    /// Interval after a poll that recorded `recorded` events (`None` if it
+    /// failed) with `remaining` REST requests left.
+    fn next_interval(
+        &self,
+        current: Duration,
+        recorded: Option<usize>,
+        remaining: Option<i64>,
+    ) -> Duration {
+        if remaining.is_some_and(|r| r < self.reserve_requests) {
+            return self.max_interval;
+        }
+        match recorded {
+            Some(n) if n > 0 => self.min_interval,
+            _ => (current * 2).clamp(self.min_interval, self.max_interval),
+        }
+    }
+}
+
+/// Adaptive poll timing shared by the pollers.
+#[derive(Debug)]
+struct Schedule {
+    next_poll: Instant,
+    interval: Duration,
+    config: PollerConfig,
+}
+
+impl Schedule {
+    fn new(config: PollerConfig) -> Self {
+        Self {
+            next_poll: Instant::now(),
+            interval: config.min_interval,
+            config,
+        }
+    }
+
+    /// Run one poll and schedule the next from its outcome.
+    fn run(&mut self, name: &str, poll: impl FnOnce() -> Result<usize>) -> Result<usize> {
+        // The scheduler thread has no call in progress, so collect the rate
+        // budget for this poll ourselves
+        meta::begin();
+        let result = poll();
+        let remaining = meta::finish().and_then(|m| m.rest_remaining);
+
+        self.interval =
+            self.config
+                .next_interval(self.interval, result.as_ref().ok().copied(), remaining);
+        self.next_poll = Instant::now() + self.interval;
+        tracing::debug!("Next {} poll in {:?}", name, self.interval);
+
+        result
+    }
+}
+
+/// Polls notifications and appends new ones to the event log.
+#[derive(Debug)]
+pub struct NotificationPoller {
+    /// Latest `updated_at` already recorded.
+    watermark: Option<String>,
+    schedule: Schedule,
+}
+
+impl NotificationPoller {
+    /// Create a poller resuming from the last recorded notification.
+    pub fn new(events: &EventLog, config: PollerConfig) -> Self {
+        let watermark = events
+            .last_of_kind(NOTIFICATION_EVENT)
+            .and_then(|e| e.payload.get("updated_at"))
+            .and_then(|v| v.as_str())
+            .map(String::from);
+
+        Self {
+            watermark,
+            schedule: Schedule::new(config),
+        }
+    }
+
+    /// Whether a poll is due at `now`.
+    pub fn is_due(&self, now: Instant) -> bool {
+        now >= self.schedule.next_poll
+    }
+
+    /// Time left until the next poll.
+    pub fn until_due(&self, now: Instant) -> Duration {
+        self.schedule.next_poll.saturating_duration_since(now)
+    }
+
+    /// Fetch notifications and record the new ones, then schedule the next
+    /// poll. Returns how many were recorded.
+    pub fn poll(
+        &mut self,
+        client: &GitHubClient,
+        handle: &Handle,
+        rules: &NotificationRules,
+        events: &Mutex<EventLog>,
+    ) -> Result<usize> {
+        let watermark = &mut self.watermark;
+        self.schedule.run("notification", || {
+            fetch_notifications(watermark, client, handle, rules, events)
+        })
+    }
+}
+
+/// Record notifications newer than `watermark`.
+///
+/// With no watermark (first run) the current inbox only seeds it, so
+/// pre-existing notifications aren't reported as new.
+fn fetch_notifications(
+    watermark: &mut Option<String>,
+    client: &GitHubClient,
+    handle: &Handle,
+    rules: &NotificationRules,
+    events: &Mutex<EventLog>,
+) -> Result<usize> {
+    let notifications = handle.block_on(client.get_notifications())?;
+    let fresh = newer_than(notifications, watermark.as_deref());
+
+    if watermark.is_none() {
+        let seed = match fresh.last() {
+            Some(n) => n.updated_at.clone(),
+            None => chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        };
+        *watermark = Some(seed);
+        return Ok(0);
+    }
+    let Some(latest) = fresh.last().map(|n| n.updated_at.clone()) else {
+        return Ok(0);
+    };
+    *watermark = Some(latest);
+
+    let mut log = events.lock().unwrap_or_else(|e| e.into_inner());
+    let mut recorded = 0;
+    for mut n in fresh {
+        let bucket = rules.classify(&n).to_string();
+        if bucket == IGNORE_BUCKET {
+            continue;
+        }
+        n.bucket = Some(bucket);
+        log.append(NOTIFICATION_EVENT, serde_json::json!(n))?;
+        recorded += 1;
+    }
+
+    Ok(recorded)
+}
+
+/// Polls issue events on watched repositories and records label changes
+/// as `labeled` / `unlabeled` events, for label-driven triggers.
+#[derive(Debug)]
+pub struct LabelPoller {
+    repos: Vec<String>,
+    /// Highest issue event ID recorded, per repo.
+    watermarks: HashMap<String, i64>,
+    schedule: Schedule,
+}
+
+impl LabelPoller {
+    /// Create a poller for `repos`, resuming from recorded label events.
+    pub fn new(events: &EventLog, repos: Vec<String>, config: PollerConfig) -> Self {
+        let kinds = [LABELED_EVENT.to_string(), UNLABELED_EVENT.to_string()];
+        let mut watermarks: HashMap<String, i64> = HashMap::new();
+        for event in events.since(0, usize::MAX, &kinds) {
+            let repo = event.payload.get("repo").and_then(|v| v.as_str());
+            let id = event.payload.get("event_id").and_then(|v| v.as_i64());
+            if let (Some(repo), Some(id)) = (repo, id) {
+                let mark = watermarks.entry(repo.to_string()).or_default();
+                *mark = (*mark).max(id);
+            }
+        }
+
+        Self {
+            repos,
+            watermarks,
+            schedule: Schedule::new(config),
+        }
+    }
+
+    /// Whether a poll is due at `now`. Never due with no repos to watch.
+    pub fn is_due(&self, now: Instant) -> bool {
+        !self.repos.is_empty() && now >= self.schedule.next_poll
+    }
+
+    /// Time left until the next poll.
+    pub fn until_due(&self, now: Instant) -> Duration {
+        if self.repos.is_empty() {
+            return Duration::MAX;
+        }
+        self.schedule.next_poll.saturating_duration_since(now)
+    }
+
+    /// Fetch label changes for every watched repo and record the new ones.
+    /// Returns how many were recorded.
+    pub fn poll(
+        &mut self,
+        client: &GitHubClient,
+        handle: &Handle,
+        events: &Mutex<EventLog>,
+    ) -> Result<usize> {
+        let repos = &self.repos;
+        let watermarks = &mut self.watermarks;
+        self.schedule.run("label", || {
+            let mut recorded = 0;
+            for repo in repos {
+                let Some((owner, name)) = repo.split_once('/') else {
+                    continue;
+                };
+                let mut changes = handle.block_on(client.list_label_events(owner, name))?;
+                changes.sort_by_key(|e| e.event_id);
+
+                // First poll of a repo only seeds its watermark
+                let Some(mark) = watermarks.get(repo).copied() else {
+                    let seed = changes.last().map(|e| e.event_id).unwrap_or(0);
+                    watermarks.insert(repo.clone(), seed);
+                    continue;
+                };
+
+                let mut log = events.lock().unwrap_or_else(|e| e.into_inner());
+                for change in changes.into_iter().filter(|e| e.event_id > mark) {
+                    watermarks.insert(repo.clone(), change.event_id);
+                    log.append(&change.action.clone(), serde_json::json!(change))?;
+                    recorded += 1;
+                }
+            }
+            Ok(recorded)
+        })
+    }
+}
+
+/// Notifications updated after `watermark`, oldest first.
+fn newer_than(mut notifications: Vec<Notification>, watermark: Option<&str>) -> Vec<Notification> {
+    if let Some(watermark) = watermark {
+        notifications.retain(|n| n.updated_at.as_str() > watermark);
+    }
+    notifications.sort_by(|a, b| a.updated_at.cmp(&b.updated_at));
+    notifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn notification(id: &str, updated_at: &str) -> Notification {
+        Notification {
+            id: id.to_string(),
+            unread: true,
+            reason: "mention".to_string(),
+            subject_title: "Title".to_string(),
+            subject_type: "Issue".to_string(),
+            subject_url: None,
+            repo_full_name: "o/r".to_string(),
+            updated_at: updated_at.to_string(),
+            bucket: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let parse = |text: &str| Config::parse(text).map(|c| c.poller);
+        let config = parse(
+            r#"
+            [poller]
+            min_interval = "30s"
+            max_interval = "1h"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.min_interval, Duration::from_secs(30));
+        assert_eq!(config.max_interval, Duration::from_secs(3600));
+        assert_eq!(config.reserve_requests, 500);
+
+        assert!(parse("[poller]\nmin_interval = \"2h\"").is_err());
+        assert!(parse("").is_ok());
+    }
+
+    #[test]
+    fn test_next_interval() {
+        let config = PollerConfig::default();
+        let min = config.min_interval;
+
+        assert_eq!(config.next_interval(min, Some(0), Some(4000)), min * 2);
+        assert_eq!(config.next_interval(min * 8, Some(3), Some(4000)), min);
+        assert_eq!(config.next_interval(min * 4, None, None), min * 8);
+        assert_eq!(
+            config.next_interval(config.max_interval, Some(0), None),
+            config.max_interval
+        );
+        // Low budget overrides activity
+        assert_eq!(
+            config.next_interval(min, Some(5), Some(100)),
+            config.max_interval
+        );
+    }
+
+    #[test]
+    fn test_newer_than_watermark() {
+        let list = vec![
+            notification("3", "2024-01-14T12:00:00Z"),
+            notification("1", "2024-01-14T10:00:00Z"),
+            notification("2", "2024-01-14T11:00:00Z"),
+        ];
+
+        let all = newer_than(list.clone(), None);
+        assert_eq!(
+            all.iter().map(|n| n.id.as_str()).collect::<Vec<_>>(),
+            ["1", "2", "3"]
+        );
+
+        let fresh = newer_than(list, Some("2024-01-14T11:00:00Z"));
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].id, "3");
+    }
+}
@@ -0,0 +1,277 @@
+//! Scheduled reminder subscriptions.
+//!
+//! A reminder pairs a GitHub search (e.g. `is:pr is:open assignee:@me`) with a
+//! local-time schedule. The service's scheduler thread runs due reminders and
+//! keeps the latest hits for `github.reminders`. Subscriptions persist in
+//! `~/.fgp/services/github/reminders.json`.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Datelike, NaiveTime, TimeZone, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::models::SearchHit;
+
+const STORE_PATH: &str = "~/.fgp/services/github/reminders.json";
+
+/// Suffix keeping IDs of reminders created in the same millisecond apart.
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A scheduled search subscription.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: String,
+    pub name: String,
+    /// GitHub search query, e.g. `is:pr is:open review-requested:@me`.
+    pub query: String,
+    /// Only match items created more than this many days ago.
+    pub older_than_days: Option<i32>,
+    /// Lowercase weekday abbreviations ("mon".."sun").
+    pub days: Vec<String>,
+    /// Local time of day, "HH:MM".
+    pub time: String,
+    pub created_at: DateTime<Utc>,
+    pub last_run: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_results: Vec<SearchHit>,
+    pub last_error: Option<String>,
+}
+
+impl Reminder {
+    /// Create a reminder, validating the schedule.
+    pub fn new(
+        name: &str,
+        query: &str,
+        older_than_days: Option<i32>,
+        days: &[String],
+        time: &str,
+    ) -> Result<Self> {
+        let days = parse_days(days)?;
+        parse_time(time)?;
+
+        let created_at = Utc::now();
+        Ok(Self {
+            id: format!(
+                "rem_{}_{}",
+                created_at.timestamp_millis(),
+                NEXT_ID.fetch_add(1, Ordering::Relaxed)
+            ),
+            name: name.to_string(),
+            query: query.to_string(),
+            older_than_days,
+            days,
+            time: time.to_string(),
+            created_at,
+            last_run: None,
+            last_results: Vec::new(),
+            last_error: None,
+        })
+    }
+
+    /// Search query with the age filter applied as of `now`.
+    pub fn search_query(&self, now: DateTime<Utc>) -> String {
+        match self.older_than_days {
+            Some(days) => {
+                let cutoff = now - chrono::Duration::days(i64::from(days));
+                format!("{} created:<{}", self.query, cutoff.format("%Y-%m-%d"))
+            }
+            None => self.query.clone(),
+        }
+    }
+
+    /// Whether the reminder should run at `now` (local time).
+    ///
+    /// Due once per scheduled day, at or after the scheduled time. A new
+    /// reminder waits for the first scheduled time after it was created.
+    pub fn is_due<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> bool {
+        let Ok(time) = parse_time(&self.time) else {
+            return false;
+        };
+        if !self.days.contains(&weekday_name(now.weekday()).to_string()) {
+            return false;
+        }
+        let Some(scheduled) = now
+            .timezone()
+            .from_local_datetime(&now.date_naive().and_time(time))
+            .earliest()
+        else {
+            return false;
+        };
+
+        let scheduled = scheduled.with_timezone(&Utc);
+        now.with_timezone(&Utc) >= scheduled && self.last_run.unwrap_or(self.created_at) < scheduled
+    }
+}
+
+/// Reminder subscriptions persisted to disk.
+#[derive(Debug, Default)]
+pub struct ReminderStore {
+    path: Option<PathBuf>,
+    pub reminders: Vec<Reminder>,
+}
+
+impl ReminderStore {
+    /// Load subscriptions from the default store file.
+    pub fn load() -> Result<Self> {
+        let path = PathBuf::from(shellexpand::tilde(STORE_PATH).to_string());
+        let reminders = if path.exists() {
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            serde_json::from_str(&content)
+                .with_context(|| format!("Invalid reminders file {}", path.display()))?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            path: Some(path),
+            reminders,
+        })
+    }
+
+    /// Write subscriptions back to disk.
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(&self.reminders)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    pub fn get_mut(&mut self, id: &str) -> Option<&mut Reminder> {
+        self.reminders.iter_mut().find(|r| r.id == id)
+    }
+
+    /// Remove a reminder, returning whether it existed.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.reminders.len();
+        self.reminders.retain(|r| r.id != id);
+        self.reminders.len() != before
+    }
+}
+
+/// Expand "weekdays", "weekends", "daily" and weekday names into abbreviations.
+fn parse_days(days: &[String]) -> Result<Vec<String>> {
+    const ALL: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+    let mut result: Vec<String> = Vec::new();
+    for day in days {
+        let day = day.trim().to_lowercase();
+        let expanded: &[&str] = match day.as_str() {
+            "daily" | "everyday" => &ALL,
+            "weekdays" => &ALL[..5],
+            "weekends" => &ALL[5..],
+            _ => match ALL.iter().find(|d| day.starts_with(*d)) {
+                Some(d) => std::slice::from_ref(d),
+                None => bail!(
+                    "Invalid day '{}': use mon..sun, weekdays, weekends or daily",
+                    day
+                ),
+            },
+        };
+        for d in expanded {
+            if !result.iter().any(|r| r == d) {
+                result.push(d.to_string());
+            }
+        }
+    }
+
+    if result.is_empty() {
+        bail!("At least one day is required");
+    }
+    Ok(result)
+}
+
+fn parse_time(time: &str) -> Result<NaiveTime> {
+    NaiveTime::parse_from_str(time, "%H:%M")
+        .with_context(|| format!("Invalid time '{}': expected HH:MM", time))
+}
+
+fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "mon",
+        Weekday::Tue => "tue",
+        Weekday::Wed => "wed",
+        Weekday::Thu => "thu",
+        Weekday::Fri => "fri",
+        Weekday::Sat => "sat",
+        Weekday::Sun => "sun",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn at(s: &str) -> DateTime<FixedOffset> {
+        DateTime::parse_from_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn test_parse_days() {
+        let days = parse_days(&["weekdays".to_string(), "Saturday".to_string()]).unwrap();
+        assert_eq!(days, ["mon", "tue", "wed", "thu", "fri", "sat"]);
+        assert!(parse_days(&["someday".to_string()]).is_err());
+        assert!(parse_days(&[]).is_err());
+    }
+
+    #[test]
+    fn test_is_due_once_per_day() {
+        let mut reminder = Reminder::new(
+            "stale PRs",
+            "is:pr is:open assignee:@me",
+            Some(2),
+            &["weekdays".to_string()],
+            "09:00",
+        )
+        .unwrap();
+        reminder.created_at = at("2024-01-14T12:00:00+01:00").with_timezone(&Utc);
+
+        // 2024-01-15 is a Monday
+        assert!(!reminder.is_due(&at("2024-01-15T08:59:00+01:00")));
+        assert!(reminder.is_due(&at("2024-01-15T09:00:00+01:00")));
+
+        reminder.last_run = Some(at("2024-01-15T09:00:30+01:00").with_timezone(&Utc));
+        assert!(!reminder.is_due(&at("2024-01-15T17:00:00+01:00")));
+        assert!(reminder.is_due(&at("2024-01-16T09:05:00+01:00")));
+
+        // Saturday
+        assert!(!reminder.is_due(&at("2024-01-20T10:00:00+01:00")));
+
+        // Created after today's time: waits for the next scheduled day
+        reminder.last_run = None;
+        reminder.created_at = at("2024-01-16T10:00:00+01:00").with_timezone(&Utc);
+        assert!(!reminder.is_due(&at("2024-01-16T10:00:30+01:00")));
+        assert!(reminder.is_due(&at("2024-01-17T09:00:00+01:00")));
+    }
+
+    #[test]
+    fn test_ids_are_unique() {
+        let days = ["daily".to_string()];
+        let a = Reminder::new("a", "is:pr", None, &days, "09:00").unwrap();
+        let b = Reminder::new("b", "is:pr", None, &days, "09:00").unwrap();
+        assert_ne!(a.id, b.id);
+    }
+
+    #[test]
+    fn test_search_query_age_filter() {
+        let reminder = Reminder::new(
+            "old",
+            "is:pr is:open",
+            Some(2),
+            &["daily".to_string()],
+            "09:00",
+        )
+        .unwrap();
+        let now = at("2024-01-15T09:00:00Z").with_timezone(&Utc);
+        assert_eq!(
+            reminder.search_query(now),
+            "is:pr is:open created:<2024-01-13"
+        );
+    }
+}
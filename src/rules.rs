@@ -0,0 +1,204 @@
+//! Notification routing rules.
+//!
+//! Rules come from the `[notifications]` section of the daemon config file
+//! (see `config.rs`) and classify
+//! notifications into named buckets by reason, repository, and title:
+//!
+//! ```toml
+//! [notifications]
+//! default_bucket = "inbox"
+//!
+//! [[notifications.rules]]
+//! bucket = "urgent"
+//! reasons = ["security_alert", "ci_activity"]
+//! repos = ["my-org/*"]
+//!
+//! [[notifications.rules]]
+//! bucket = "review"
+//! reasons = ["review_requested"]
+//!
+//! [[notifications.rules]]
+//! bucket = "ignore"
+//! title_contains = ["[bot]", "chore(deps)"]
+//! ```
+//!
+//! Rules are evaluated in order and the first match wins. Within a rule every
+//! listed field must match; a field matches if any of its entries match.
+//! Notifications routed to the `ignore` bucket are dropped from results.
+
+use serde::Deserialize;
+
+use crate::models::Notification;
+
+/// Bucket whose notifications are hidden and never pushed.
+pub const IGNORE_BUCKET: &str = "ignore";
+
+/// Ordered notification routing rules.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationRules {
+    #[serde(default = "default_bucket")]
+    pub default_bucket: String,
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+/// A single routing rule.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rule {
+    pub bucket: String,
+    /// Notification reasons (e.g. "review_requested", "mention").
+    #[serde(default)]
+    pub reasons: Vec<String>,
+    /// Repository patterns; `*` matches any run of characters.
+    #[serde(default)]
+    pub repos: Vec<String>,
+    /// Case-insensitive substrings of the subject title.
+    #[serde(default)]
+    pub title_contains: Vec<String>,
+}
+
+fn default_bucket() -> String {
+    "inbox".to_string()
+}
+
+impl Default for NotificationRules {
+    fn default() -> Self {
+        Self {
+            default_bucket: default_bucket(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl NotificationRules {
+    /// Return the bucket a notification belongs to.
+    pub fn classify(&self, notification: &Notification) -> &str {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(notification))
+            .map(|rule| rule.bucket.as_str())
+            .unwrap_or(&self.default_bucket)
+    }
+}
+
+impl Rule {
+    fn matches(&self, notification: &Notification) -> bool {
+        let reason_ok = self.reasons.is_empty()
+            || self
+                .reasons
+                .iter()
+                .any(|r| r.eq_ignore_ascii_case(&notification.reason));
+        let repo_ok = self.repos.is_empty()
+            || self
+                .repos
+                .iter()
+                .any(|p| wildcard_match(p, &notification.repo_full_name));
+        let title = notification.subject_title.to_lowercase();
+        let title_ok = self.title_contains.is_empty()
+            || self
+                .title_contains
+                .iter()
+                .any(|t| title.contains(&t.to_lowercase()));
+
+        reason_ok && repo_ok && title_ok
+    }
+}
+
+/// Case-insensitive match where `*` matches any run of characters.
+pub(crate) fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let text = text.to_lowercase();
+    let parts: Vec<&str> = pattern.split('*').collect();
+
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+
+    let first = parts[0];
+    let last = parts[parts.len() - 1];
+    // Check both ends before slicing: a matched prefix and suffix put the
+    // slice bounds on character boundaries
+    if !text.starts_with(first) || !text.ends_with(last) || text.len() < first.len() + last.len() {
+        return false;
+    }
+
+    let mut rest = &text[first.len()..text.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn notification(reason: &str, repo: &str, title: &str) -> Notification {
+        Notification {
+            id: "1".to_string(),
+            unread: true,
+            reason: reason.to_string(),
+            subject_title: title.to_string(),
+            subject_type: "PullRequest".to_string(),
+            subject_url: None,
+            repo_full_name: repo.to_string(),
+            updated_at: "2024-01-14T00:00:00Z".to_string(),
+            bucket: None,
+        }
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("my-org/*", "my-org/api"));
+        assert!(wildcard_match("*/docs", "Other/Docs"));
+        assert!(wildcard_match("a*c*e", "abcde"));
+        assert!(!wildcard_match("my-org/*", "other/api"));
+        assert!(!wildcard_match("ab*ba", "aba"));
+        assert!(wildcard_match("exact/repo", "exact/repo"));
+        assert!(!wildcard_match("a*b", "aé"));
+        assert!(!wildcard_match("é*b", "éa"));
+        assert!(wildcard_match("*é", "café"));
+        assert!(wildcard_match("my-org/*-ü*", "My-Org/Ünïcode-ü-docs"));
+    }
+
+    #[test]
+    fn test_classify_first_match_wins() {
+        let rules = Config::parse(
+            r#"
+            [notifications]
+            default_bucket = "later"
+
+            [[notifications.rules]]
+            bucket = "ignore"
+            title_contains = ["chore(deps)"]
+
+            [[notifications.rules]]
+            bucket = "urgent"
+            reasons = ["security_alert"]
+            repos = ["my-org/*"]
+
+            [[notifications.rules]]
+            bucket = "review"
+            reasons = ["review_requested"]
+            "#,
+        )
+        .unwrap()
+        .rules;
+
+        let n = notification("security_alert", "my-org/api", "Vulnerability found");
+        assert_eq!(rules.classify(&n), "urgent");
+
+        let n = notification("security_alert", "other/api", "Vulnerability found");
+        assert_eq!(rules.classify(&n), "later");
+
+        let n = notification("review_requested", "my-org/api", "Chore(deps): bump serde");
+        assert_eq!(rules.classify(&n), IGNORE_BUCKET);
+
+        let n = notification("review_requested", "my-org/api", "Add login flow");
+        assert_eq!(rules.classify(&n), "review");
+    }
+}
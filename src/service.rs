@@ -9,16 +9,100 @@ use fgp_daemon::schema::SchemaBuilder;
 use fgp_daemon::service::{HealthStatus, MethodInfo};
 use fgp_daemon::FgpService;
 use serde_json::{json, Value};
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::runtime::Runtime;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::runtime::{Handle, Runtime};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
-use crate::api::GitHubClient;
+use crate::api::{meta, ActionsScope, Conflict, GitHubClient, LazyClient};
+use crate::bootstrap::{self, LabelSpec};
+use crate::cache::ResponseCache;
+use crate::codeowners::CodeOwners;
+use crate::compat::{self, CURRENT_SCHEMA_VERSION, MIN_SCHEMA_VERSION};
+use crate::config::Config;
+use crate::events::EventLog;
+use crate::local_search::{self, SearchIndex};
+use crate::models::{
+    CommunityProfile, OutsideCollaborator, PrScore, RefGate, RefVerification, ReleaseNotes,
+    RepoAccess, ReviewMetrics, ServiceStatus, SimilarIssue, SsoOrgStatus,
+};
+use crate::plan::{self, Step};
+use crate::poller::{LabelPoller, NotificationPoller, PollerConfig};
+use crate::reminders::{Reminder, ReminderStore};
+use crate::rules::{NotificationRules, IGNORE_BUCKET};
+use crate::similarity::{score_candidate, tokenize};
+use crate::triggers::{self, Trigger, TriggerRunner};
+use crate::workflows::parse_workflow;
+
+/// Maximum concurrent per-repository requests in org-wide fan-outs.
+const ORG_FANOUT_CONCURRENCY: usize = 8;
+
+/// Search results scored by `github.similar_issues`.
+const SIMILAR_ISSUE_CANDIDATES: i32 = 30;
+
+/// Renames followed by `github.file_history` before giving up.
+const MAX_FOLLOWED_RENAMES: usize = 5;
+
+/// Longest the scheduler thread sleeps between checks for due reminders.
+const SCHEDULER_TICK: Duration = Duration::from_secs(30);
+
+/// Maximum hits kept per reminder run.
+const REMINDER_RESULT_LIMIT: i32 = 50;
+
+/// Event kind for reminder runs that found matches.
+const REMINDER_EVENT: &str = "reminder";
+
+/// Event kind for github.apply progress.
+const PLAN_STEP_EVENT: &str = "plan_step";
+
+/// Default and maximum events returned by github.events_since.
+const EVENTS_PAGE_DEFAULT: i32 = 100;
+const EVENTS_PAGE_MAX: i32 = 1000;
+
+/// Default and maximum repositories scanned by github.org_repo_summary.
+const ORG_REPO_SUMMARY_DEFAULT: i32 = 50;
+const ORG_REPO_SUMMARY_MAX: i32 = 1000;
+
+/// Default and maximum entries returned by github.actions_caches.
+const ACTIONS_CACHES_DEFAULT: i32 = 100;
+const ACTIONS_CACHES_MAX: i32 = 1000;
+
+/// Default and maximum look-back window for github.review_metrics.
+const REVIEW_METRICS_DAYS_DEFAULT: i32 = 7;
+const REVIEW_METRICS_DAYS_MAX: i32 = 90;
+
+/// Default and maximum hits returned by github.local_search.
+const LOCAL_SEARCH_DEFAULT: i32 = 50;
+const LOCAL_SEARCH_MAX: i32 = 500;
+
+/// Commits checked by github.verify_ref unless `limit` is given.
+const VERIFY_REF_DEFAULT: i32 = 250;
+const VERIFY_REF_MAX: i32 = 1000;
+
+/// Outcome of the background credential check started in `on_start`.
+#[derive(Debug, Clone)]
+enum Verification {
+    Pending,
+    Verified,
+    Failed(String),
+}
 
 /// FGP service for GitHub operations.
 pub struct GitHubService {
-    client: Arc<GitHubClient>,
+    client: Arc<LazyClient>,
+    verification: Arc<Mutex<Verification>>,
     runtime: Runtime,
+    rules: NotificationRules,
+    reminders: Arc<Mutex<ReminderStore>>,
+    cache: Mutex<ResponseCache>,
+    index: Mutex<SearchIndex>,
+    events: Arc<Mutex<EventLog>>,
+    poller_config: PollerConfig,
+    triggers: Vec<Trigger>,
+    /// State files that failed to load at startup, reported by health checks.
+    load_errors: Vec<(&'static str, String)>,
 }
 
 impl GitHubService {
@@ -27,16 +111,94 @@ impl GitHubService {
     /// Token is resolved from:
     /// 1. GITHUB_TOKEN environment variable
     /// 2. gh CLI config (~/.config/gh/hosts.yml)
+    ///
+    /// Resolution is deferred to the first API call, so the daemon still
+    /// starts (and reports the problem in health checks) without a token.
+    /// Likewise a config, reminders or event log file that fails to load is
+    /// replaced by defaults and reported as degraded; the in-memory fallback
+    /// stores never write over the file, so it can be fixed and reloaded.
     pub fn new(token: Option<String>) -> Result<Self> {
-        let client = GitHubClient::new(token)?;
         let runtime = Runtime::new()?;
+        let mut load_errors = Vec::new();
+        let config = Config::load().unwrap_or_else(|e| {
+            tracing::error!("Using default config: {:#}", e);
+            load_errors.push(("config", format!("{:#}", e)));
+            Config::default()
+        });
+        let reminders = ReminderStore::load().unwrap_or_else(|e| {
+            tracing::error!("Reminders won't be saved: {:#}", e);
+            load_errors.push(("reminders", format!("{:#}", e)));
+            ReminderStore::default()
+        });
+        let cache = ResponseCache::new(config.cache);
+        let events = EventLog::load().unwrap_or_else(|e| {
+            tracing::error!("Events won't be saved: {:#}", e);
+            load_errors.push(("events", format!("{:#}", e)));
+            EventLog::default()
+        });
 
         Ok(Self {
-            client: Arc::new(client),
+            client: Arc::new(LazyClient::new(token)),
+            verification: Arc::new(Mutex::new(Verification::Pending)),
             runtime,
+            rules: config.rules,
+            reminders: Arc::new(Mutex::new(reminders)),
+            cache: Mutex::new(cache),
+            index: Mutex::new(SearchIndex::default()),
+            events: Arc::new(Mutex::new(events)),
+            poller_config: config.poller,
+            triggers: config.triggers,
+            load_errors,
         })
     }
 
+    /// The API client, created on first use.
+    fn client(&self) -> Result<Arc<GitHubClient>> {
+        self.client.get()
+    }
+
+    /// Record fetched content for github.local_search.
+    fn index_content(&self, method: &str, docs: Vec<(String, Value)>) {
+        let mut index = self.index.lock().unwrap_or_else(|e| e.into_inner());
+        for (id, doc) in docs {
+            index.add(method, id, doc);
+        }
+    }
+
+    /// Add the parameters every method accepts to a method's schema.
+    fn with_common_params(mut schema: Value) -> Value {
+        let common = [
+            (
+                "schema_version",
+                SchemaBuilder::integer()
+                    .minimum(MIN_SCHEMA_VERSION as i64)
+                    .maximum(CURRENT_SCHEMA_VERSION as i64)
+                    .default_value(json!(CURRENT_SCHEMA_VERSION))
+                    .description("Response layout version; pin this to keep a stable shape")
+                    .build(),
+            ),
+            (
+                "include_meta",
+                SchemaBuilder::boolean()
+                    .default_value(json!(false))
+                    .description("Add a _meta block with API cost and rate budget")
+                    .build(),
+            ),
+        ];
+
+        if let Some(obj) = schema.as_object_mut() {
+            let properties = obj
+                .entry("properties")
+                .or_insert_with(|| Value::Object(Default::default()));
+            if let Some(properties) = properties.as_object_mut() {
+                for (name, property) in common {
+                    properties.insert(name.to_string(), property);
+                }
+            }
+        }
+        schema
+    }
+
     /// Helper to get a string parameter.
     fn get_str<'a>(params: &'a HashMap<String, Value>, key: &str) -> Option<&'a str> {
         params.get(key).and_then(|v| v.as_str())
@@ -51,6 +213,133 @@ impl GitHubService {
             .unwrap_or(default)
     }
 
+    /// Helper to get a bool parameter with default.
+    fn get_bool(params: &HashMap<String, Value>, key: &str, default: bool) -> bool {
+        params.get(key).and_then(|v| v.as_bool()).unwrap_or(default)
+    }
+
+    /// Helper to get a required i64 ID parameter.
+    fn require_id(params: &HashMap<String, Value>, key: &str) -> Result<i64> {
+        params
+            .get(key)
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: {}", key))
+    }
+
+    /// Resolve the secret/variable scope from `repo`, `org`, and `environment` params.
+    fn parse_actions_scope(params: &HashMap<String, Value>) -> Result<ActionsScope> {
+        let repo = Self::get_str(params, "repo");
+        let org = Self::get_str(params, "org");
+        let environment = Self::get_str(params, "environment");
+
+        match (repo, org, environment) {
+            (Some(repo_str), None, None) => {
+                let (owner, repo) = Self::parse_repo(repo_str)?;
+                Ok(ActionsScope::Repo {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                })
+            }
+            (Some(repo_str), None, Some(env)) => {
+                let (owner, repo) = Self::parse_repo(repo_str)?;
+                Ok(ActionsScope::Environment {
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                    environment: env.to_string(),
+                })
+            }
+            (None, Some(org), None) => Ok(ActionsScope::Org {
+                org: org.to_string(),
+            }),
+            (None, Some(_), Some(_)) => {
+                anyhow::bail!("Parameter 'environment' requires 'repo', not 'org'")
+            }
+            (Some(_), Some(_), _) => anyhow::bail!("Specify either 'repo' or 'org', not both"),
+            (None, None, _) => anyhow::bail!("Missing required parameter: repo or org"),
+        }
+    }
+
+    /// Helper to get an optional list of i64 IDs.
+    fn get_id_list(params: &HashMap<String, Value>, key: &str) -> Option<Vec<i64>> {
+        params
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|a| a.iter().filter_map(|v| v.as_i64()).collect())
+    }
+
+    /// Describe a GitHub-side incident affecting the API, Git, or Actions,
+    /// if any, for inclusion in health messages.
+    fn incident_note(status: &ServiceStatus) -> Option<String> {
+        let degraded = status.daemon_degraded_components();
+        if degraded.is_empty() {
+            return None;
+        }
+
+        let mut parts: Vec<String> = degraded
+            .iter()
+            .map(|c| format!("{}: {}", c.name, c.status))
+            .collect();
+        parts.extend(status.incidents.iter().map(|i| i.name.clone()));
+        Some(format!("GitHub incident ({})", parts.join("; ")))
+    }
+
+    /// Parse `additions` entries of `{path, content}` or `{path, content_base64}`.
+    fn parse_file_additions(params: &HashMap<String, Value>) -> Result<Vec<(String, Vec<u8>)>> {
+        match params.get("additions").and_then(|v| v.as_array()) {
+            Some(items) => Self::parse_additions(items),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Decode `{path, content | content_base64}` objects.
+    fn parse_additions(items: &[Value]) -> Result<Vec<(String, Vec<u8>)>> {
+        use base64::Engine;
+
+        items
+            .iter()
+            .map(|item| {
+                let path = item
+                    .get("path")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Each addition needs a 'path'"))?;
+                let contents = if let Some(text) = item.get("content").and_then(|v| v.as_str()) {
+                    text.as_bytes().to_vec()
+                } else if let Some(b64) = item.get("content_base64").and_then(|v| v.as_str()) {
+                    base64::engine::general_purpose::STANDARD
+                        .decode(b64)
+                        .map_err(|e| {
+                            anyhow::anyhow!("Invalid content_base64 for {}: {}", path, e)
+                        })?
+                } else {
+                    anyhow::bail!("Addition for {} needs 'content' or 'content_base64'", path);
+                };
+                Ok((path.to_string(), contents))
+            })
+            .collect()
+    }
+
+    /// Split a commit message into headline and body the way git does.
+    fn split_message(message: &str) -> (String, Option<String>) {
+        match message.split_once("\n\n") {
+            Some((h, b)) => (h.trim().to_string(), Some(b.trim().to_string())),
+            None => (message.trim().to_string(), None),
+        }
+    }
+
+    /// Helper to get a list of strings.
+    fn get_str_list(params: &HashMap<String, Value>, key: &str) -> Vec<String> {
+        params
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|a| {
+                a.iter()
+                    .filter_map(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// Parse owner/repo from "owner/repo" format.
     fn parse_repo(repo_str: &str) -> Result<(&str, &str)> {
         let parts: Vec<&str> = repo_str.split('/').collect();
@@ -68,7 +357,7 @@ impl GitHubService {
     // ========================================================================
 
     fn health(&self) -> Result<Value> {
-        let client = self.client.clone();
+        let client = self.client()?;
         let ok = self.runtime.block_on(async move { client.ping().await })?;
 
         Ok(serde_json::json!({
@@ -79,7 +368,7 @@ impl GitHubService {
     }
 
     fn get_user(&self) -> Result<Value> {
-        let client = self.client.clone();
+        let client = self.client()?;
         let user = self
             .runtime
             .block_on(async move { client.get_user().await })?;
@@ -89,7 +378,7 @@ impl GitHubService {
 
     fn list_repos(&self, params: HashMap<String, Value>) -> Result<Value> {
         let limit = Self::get_i32(&params, "limit", 10);
-        let client = self.client.clone();
+        let client = self.client()?;
 
         let repos = self
             .runtime
@@ -107,17 +396,40 @@ impl GitHubService {
         let (owner, repo) = Self::parse_repo(repo_str)?;
         let state = Self::get_str(&params, "state").unwrap_or("open");
         let limit = Self::get_i32(&params, "limit", 10);
+        let include_body = Self::get_bool(&params, "include_body", false);
 
-        let client = self.client.clone();
+        let client = self.client()?;
         let owner = owner.to_string();
         let repo = repo.to_string();
         let state = state.to_string();
         let state_for_response = state.clone();
 
-        let issues = self
+        let mut issues = self
             .runtime
             .block_on(async move { client.list_issues(&owner, &repo, &state, limit).await })?;
 
+        // Bodies always go to the search index; listings only carry them on request
+        let docs = issues
+            .iter()
+            .filter_map(|issue| {
+                let body = issue.body.as_ref()?;
+                let doc = json!({
+                    "repo": repo_str,
+                    "number": issue.number,
+                    "title": issue.title,
+                    "url": issue.url,
+                    "body": body,
+                });
+                Some((format!("github.issues:{}#{}", repo_str, issue.number), doc))
+            })
+            .collect();
+        self.index_content("github.issues", docs);
+        if !include_body {
+            for issue in &mut issues {
+                issue.body = None;
+            }
+        }
+
         Ok(serde_json::json!({
             "repo": repo_str,
             "state": state_for_response,
@@ -133,7 +445,7 @@ impl GitHubService {
         let state = Self::get_str(&params, "state").unwrap_or("open");
         let limit = Self::get_i32(&params, "limit", 10);
 
-        let client = self.client.clone();
+        let client = self.client()?;
         let owner = owner.to_string();
         let repo = repo.to_string();
         let state = state.to_string();
@@ -160,7 +472,7 @@ impl GitHubService {
             anyhow::bail!("Missing required parameter: number");
         }
 
-        let client = self.client.clone();
+        let client = self.client()?;
         let owner = owner.to_string();
         let repo = repo.to_string();
 
@@ -168,19 +480,63 @@ impl GitHubService {
             .runtime
             .block_on(async move { client.get_pr(&owner, &repo, number).await })?;
 
+        if let Some(body) = &pr.body {
+            let doc = json!({
+                "repo": repo_str,
+                "number": pr.number,
+                "title": pr.title,
+                "url": pr.url,
+                "body": body,
+            });
+            self.index_content(
+                "github.pr",
+                vec![(format!("github.pr:{}#{}", repo_str, pr.number), doc)],
+            );
+        }
+
         Ok(serde_json::json!(pr))
     }
 
-    fn get_notifications(&self, _params: HashMap<String, Value>) -> Result<Value> {
-        let client = self.client.clone();
+    fn get_notifications(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let group = Self::get_bool(&params, "group", false);
+        let include_ignored = Self::get_bool(&params, "include_ignored", false);
+        let client = self.client()?;
 
-        let notifications = self
+        let mut notifications = self
             .runtime
             .block_on(async move { client.get_notifications().await })?;
 
+        for n in &mut notifications {
+            n.bucket = Some(self.rules.classify(n).to_string());
+        }
+        let ignored_count = notifications
+            .iter()
+            .filter(|n| n.bucket.as_deref() == Some(IGNORE_BUCKET))
+            .count();
+        if !include_ignored {
+            notifications.retain(|n| n.bucket.as_deref() != Some(IGNORE_BUCKET));
+        }
+        let unread_count = notifications.iter().filter(|n| n.unread).count();
+
+        if group {
+            let mut groups: HashMap<String, Vec<_>> = HashMap::new();
+            for n in notifications {
+                groups
+                    .entry(n.bucket.clone().unwrap_or_default())
+                    .or_default()
+                    .push(n);
+            }
+            return Ok(serde_json::json!({
+                "groups": groups,
+                "unread_count": unread_count,
+                "ignored_count": ignored_count,
+            }));
+        }
+
         Ok(serde_json::json!({
             "notifications": notifications,
-            "unread_count": notifications.iter().filter(|n| n.unread).count(),
+            "unread_count": unread_count,
+            "ignored_count": ignored_count,
         }))
     }
 
@@ -192,7 +548,7 @@ impl GitHubService {
             .ok_or_else(|| anyhow::anyhow!("Missing required parameter: title"))?;
         let body = Self::get_str(&params, "body");
 
-        let client = self.client.clone();
+        let client = self.client()?;
         let owner = owner.to_string();
         let repo = repo.to_string();
         let title = title.to_string();
@@ -209,266 +565,6022 @@ impl GitHubService {
             "issue": issue,
         }))
     }
-}
 
-impl FgpService for GitHubService {
-    fn name(&self) -> &str {
-        "github"
+    fn get_merge_queue(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let branch = Self::get_str(&params, "branch").map(|s| s.to_string());
+        let limit = Self::get_i32(&params, "limit", 50);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let queue = self.runtime.block_on(async move {
+            client
+                .get_merge_queue(&owner, &repo, branch.as_deref(), limit)
+                .await
+        })?;
+
+        match queue {
+            Some(queue) => Ok(serde_json::json!({
+                "repo": repo_str,
+                "enabled": true,
+                "branch": queue.branch,
+                "url": queue.url,
+                "merge_method": queue.merge_method,
+                "entries": queue.entries,
+                "count": queue.entries.len(),
+            })),
+            None => Ok(serde_json::json!({
+                "repo": repo_str,
+                "enabled": false,
+                "entries": [],
+                "count": 0,
+            })),
+        }
     }
 
-    fn version(&self) -> &str {
-        env!("CARGO_PKG_VERSION")
+    fn enqueue_pr(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let jump = Self::get_bool(&params, "jump", false);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let entry = self
+            .runtime
+            .block_on(async move { client.enqueue_pr(&owner, &repo, number, jump).await })?;
+
+        Ok(serde_json::json!({
+            "enqueued": true,
+            "entry": entry,
+        }))
     }
 
-    fn dispatch(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
-        match method {
-            "health" => self.health(),
-            "user" | "github.user" => self.get_user(),
-            "repos" | "github.repos" => self.list_repos(params),
-            "issues" | "github.issues" => self.list_issues(params),
-            "prs" | "github.prs" => self.list_prs(params),
-            "pr" | "github.pr" => self.get_pr(params),
-            "notifications" | "github.notifications" => self.get_notifications(params),
-            "create_issue" | "github.create_issue" => self.create_issue(params),
-            _ => anyhow::bail!("Unknown method: {}", method),
+    fn dequeue_pr(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
         }
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        self.runtime
+            .block_on(async move { client.dequeue_pr(&owner, &repo, number).await })?;
+
+        Ok(serde_json::json!({
+            "dequeued": true,
+            "repo": repo_str,
+            "number": number,
+        }))
     }
 
-    fn method_list(&self) -> Vec<MethodInfo> {
-        vec![
-            // github.user - Get current authenticated user
-            MethodInfo::new("github.user", "Get current authenticated user info")
-                .schema(SchemaBuilder::object().build())
-                .returns(
-                    SchemaBuilder::object()
-                        .property("login", SchemaBuilder::string().description("GitHub username"))
-                        .property("name", SchemaBuilder::string().description("Display name"))
-                        .property("email", SchemaBuilder::string().format("email"))
-                        .property("avatar_url", SchemaBuilder::string().format("uri"))
-                        .build(),
-                )
-                .example("Get current user", json!({})),
+    fn download_archive(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let dest_path = Self::get_str(&params, "dest_path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: dest_path"))?;
+        let git_ref = Self::get_str(&params, "ref").map(|s| s.to_string());
+        let format = match Self::get_str(&params, "format").unwrap_or("tarball") {
+            "tarball" | "tar" | "tar.gz" => "tarball",
+            "zipball" | "zip" => "zipball",
+            other => anyhow::bail!(
+                "Invalid format '{}'. Expected 'tarball' or 'zipball'",
+                other
+            ),
+        };
 
-            // github.repos - List repositories
-            MethodInfo::new("github.repos", "List your repositories")
-                .schema(
-                    SchemaBuilder::object()
-                        .property(
-                            "limit",
-                            SchemaBuilder::integer()
-                                .minimum(1)
-                                .maximum(100)
-                                .default_value(json!(10))
-                                .description("Maximum number of repos to return"),
-                        )
-                        .build(),
-                )
-                .returns(
-                    SchemaBuilder::object()
-                        .property(
-                            "repos",
-                            SchemaBuilder::array()
-                                .items(
-                                    SchemaBuilder::object()
-                                        .property("name", SchemaBuilder::string())
-                                        .property("full_name", SchemaBuilder::string())
-                                        .property("url", SchemaBuilder::string().format("uri"))
-                                        .property("description", SchemaBuilder::string())
-                                        .property("stargazers_count", SchemaBuilder::integer()),
-                                )
-                                .description("List of repositories"),
-                        )
-                        .property("count", SchemaBuilder::integer())
-                        .build(),
-                )
-                .example("List top 5 repos", json!({"limit": 5})),
+        // The daemon's working directory isn't the caller's, so a relative
+        // path would land somewhere unexpected
+        let dest = std::path::PathBuf::from(shellexpand::tilde(dest_path).to_string());
+        if !dest.is_absolute() {
+            anyhow::bail!(
+                "dest_path must be absolute or start with '~', got '{}'",
+                dest_path
+            );
+        }
 
-            // github.issues - List issues
-            MethodInfo::new("github.issues", "List issues for a repository")
-                .schema(
-                    SchemaBuilder::object()
-                        .property(
-                            "repo",
-                            SchemaBuilder::string()
-                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
-                                .description("Repository in 'owner/repo' format"),
-                        )
-                        .property(
-                            "state",
-                            SchemaBuilder::string()
-                                .enum_values(&["open", "closed", "all"])
-                                .default_value(json!("open"))
-                                .description("Issue state filter"),
-                        )
-                        .property(
-                            "limit",
-                            SchemaBuilder::integer()
-                                .minimum(1)
-                                .maximum(100)
-                                .default_value(json!(10))
-                                .description("Maximum issues to return"),
-                        )
-                        .required(&["repo"])
-                        .build(),
-                )
-                .returns(
-                    SchemaBuilder::object()
-                        .property("repo", SchemaBuilder::string())
-                        .property("state", SchemaBuilder::string())
-                        .property(
-                            "issues",
-                            SchemaBuilder::array().items(
-                                SchemaBuilder::object()
-                                    .property("number", SchemaBuilder::integer())
-                                    .property("title", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string())
-                                    .property("created_at", SchemaBuilder::string().format("date-time"))
-                                    .property("url", SchemaBuilder::string().format("uri")),
-                            ),
-                        )
-                        .property("count", SchemaBuilder::integer())
-                        .build(),
-                )
-                .example(
-                    "List open issues",
-                    json!({"repo": "fast-gateway-protocol/daemon"}),
-                )
-                .example(
-                    "List closed issues",
-                    json!({"repo": "fast-gateway-protocol/daemon", "state": "closed", "limit": 5}),
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let dest_for_download = dest.clone();
+        let ref_for_response = git_ref.clone();
+
+        let size = self.runtime.block_on(async move {
+            client
+                .download_archive(
+                    &owner,
+                    &repo,
+                    git_ref.as_deref(),
+                    format,
+                    &dest_for_download,
                 )
-                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+                .await
+        })?;
 
-            // github.prs - List pull requests
-            MethodInfo::new("github.prs", "List pull requests for a repository")
-                .schema(
-                    SchemaBuilder::object()
-                        .property(
-                            "repo",
-                            SchemaBuilder::string()
-                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "ref": ref_for_response,
+            "format": format,
+            "path": dest.display().to_string(),
+            "size": size,
+        }))
+    }
+
+    fn list_codespaces(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let state_filter = Self::get_str(&params, "state").map(|s| s.to_lowercase());
+        let client = self.client()?;
+
+        let mut codespaces = self
+            .runtime
+            .block_on(async move { client.list_codespaces().await })?;
+
+        if let Some(state) = &state_filter {
+            codespaces.retain(|c| c.state.to_lowercase() == *state);
+        }
+
+        Ok(serde_json::json!({
+            "codespaces": codespaces,
+            "count": codespaces.len(),
+        }))
+    }
+
+    fn start_codespace(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+        let client = self.client()?;
+
+        let codespace = self
+            .runtime
+            .block_on(async move { client.start_codespace(&name).await })?;
+
+        Ok(serde_json::json!({
+            "started": true,
+            "codespace": codespace,
+        }))
+    }
+
+    fn stop_codespace(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+        let client = self.client()?;
+
+        let codespace = self
+            .runtime
+            .block_on(async move { client.stop_codespace(&name).await })?;
+
+        Ok(serde_json::json!({
+            "stopped": true,
+            "codespace": codespace,
+        }))
+    }
+
+    fn list_ssh_keys(&self) -> Result<Value> {
+        let client = self.client()?;
+
+        let keys = self
+            .runtime
+            .block_on(async move { client.list_ssh_keys().await })?;
+
+        Ok(serde_json::json!({
+            "keys": keys,
+            "count": keys.len(),
+        }))
+    }
+
+    fn add_ssh_key(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let title = Self::get_str(&params, "title")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: title"))?
+            .to_string();
+        let key = Self::get_str(&params, "key")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: key"))?
+            .trim()
+            .to_string();
+
+        let client = self.client()?;
+
+        let key = self
+            .runtime
+            .block_on(async move { client.add_ssh_key(&title, &key).await })?;
+
+        Ok(serde_json::json!({
+            "added": true,
+            "key": key,
+        }))
+    }
+
+    fn remove_ssh_key(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let id = Self::require_id(&params, "id")?;
+        let client = self.client()?;
+
+        self.runtime
+            .block_on(async move { client.remove_ssh_key(id).await })?;
+
+        Ok(serde_json::json!({
+            "removed": true,
+            "id": id,
+        }))
+    }
+
+    fn list_gpg_keys(&self) -> Result<Value> {
+        let client = self.client()?;
+
+        let keys = self
+            .runtime
+            .block_on(async move { client.list_gpg_keys().await })?;
+
+        Ok(serde_json::json!({
+            "keys": keys,
+            "count": keys.len(),
+        }))
+    }
+
+    fn add_gpg_key(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let armored = Self::get_str(&params, "armored_public_key")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: armored_public_key"))?
+            .to_string();
+        let name = Self::get_str(&params, "name").map(|s| s.to_string());
+
+        let client = self.client()?;
+
+        let key = self
+            .runtime
+            .block_on(async move { client.add_gpg_key(name.as_deref(), &armored).await })?;
+
+        Ok(serde_json::json!({
+            "added": true,
+            "key": key,
+        }))
+    }
+
+    fn remove_gpg_key(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let id = Self::require_id(&params, "id")?;
+        let client = self.client()?;
+
+        self.runtime
+            .block_on(async move { client.remove_gpg_key(id).await })?;
+
+        Ok(serde_json::json!({
+            "removed": true,
+            "id": id,
+        }))
+    }
+
+    fn list_secrets(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = Self::parse_actions_scope(&params)?;
+        let client = self.client()?;
+
+        let secrets = self
+            .runtime
+            .block_on(async move { client.list_secrets(&scope).await })?;
+
+        Ok(serde_json::json!({
+            "secrets": secrets,
+            "count": secrets.len(),
+        }))
+    }
+
+    fn set_secret(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = Self::parse_actions_scope(&params)?;
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+        let value = Self::get_str(&params, "value")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: value"))?
+            .to_string();
+        let visibility = Self::get_str(&params, "visibility").map(|s| s.to_string());
+        let selected = Self::get_id_list(&params, "selected_repository_ids");
+
+        let client = self.client()?;
+        let name_for_response = name.clone();
+
+        self.runtime.block_on(async move {
+            client
+                .set_secret(
+                    &scope,
+                    &name,
+                    &value,
+                    visibility.as_deref(),
+                    selected.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "set": true,
+            "name": name_for_response,
+        }))
+    }
+
+    fn delete_secret(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = Self::parse_actions_scope(&params)?;
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+
+        let client = self.client()?;
+        let name_for_response = name.clone();
+
+        self.runtime
+            .block_on(async move { client.delete_secret(&scope, &name).await })?;
+
+        Ok(serde_json::json!({
+            "deleted": true,
+            "name": name_for_response,
+        }))
+    }
+
+    fn list_variables(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = Self::parse_actions_scope(&params)?;
+        let client = self.client()?;
+
+        let variables = self
+            .runtime
+            .block_on(async move { client.list_variables(&scope).await })?;
+
+        Ok(serde_json::json!({
+            "variables": variables,
+            "count": variables.len(),
+        }))
+    }
+
+    fn set_variable(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = Self::parse_actions_scope(&params)?;
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+        let value = Self::get_str(&params, "value")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: value"))?
+            .to_string();
+        let visibility = Self::get_str(&params, "visibility").map(|s| s.to_string());
+        let selected = Self::get_id_list(&params, "selected_repository_ids");
+
+        let client = self.client()?;
+        let name_for_response = name.clone();
+
+        let created = self.runtime.block_on(async move {
+            client
+                .set_variable(
+                    &scope,
+                    &name,
+                    &value,
+                    visibility.as_deref(),
+                    selected.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "set": true,
+            "created": created,
+            "name": name_for_response,
+        }))
+    }
+
+    fn delete_variable(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let scope = Self::parse_actions_scope(&params)?;
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?
+            .to_string();
+
+        let client = self.client()?;
+        let name_for_response = name.clone();
+
+        self.runtime
+            .block_on(async move { client.delete_variable(&scope, &name).await })?;
+
+        Ok(serde_json::json!({
+            "deleted": true,
+            "name": name_for_response,
+        }))
+    }
+
+    fn actions_caches(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let key = Self::get_str(&params, "key").map(String::from);
+        let git_ref = Self::get_str(&params, "ref").map(String::from);
+        let limit =
+            Self::get_i32(&params, "limit", ACTIONS_CACHES_DEFAULT).clamp(1, ACTIONS_CACHES_MAX);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let (caches, total_count) = self.runtime.block_on(async move {
+            client
+                .list_actions_caches(
+                    &owner,
+                    &repo,
+                    key.as_deref(),
+                    git_ref.as_deref(),
+                    limit as usize,
+                )
+                .await
+        })?;
+
+        let listed_bytes: i64 = caches.iter().map(|c| c.size_in_bytes).sum();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "caches": caches,
+            "count": caches.len(),
+            "total_count": total_count,
+            "listed_size_in_bytes": listed_bytes,
+        }))
+    }
+
+    fn delete_actions_caches(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let key = Self::get_str(&params, "key").map(String::from);
+        let prefix = Self::get_str(&params, "prefix").map(String::from);
+        let git_ref = Self::get_str(&params, "ref").map(String::from);
+        let dry_run = Self::get_bool(&params, "dry_run", false);
+
+        let (filter, exact) = match (key, prefix) {
+            (Some(_), Some(_)) => anyhow::bail!("Pass either 'key' or 'prefix', not both"),
+            (None, None) => anyhow::bail!("Missing required parameter: key or prefix"),
+            (Some(key), None) => (key, true),
+            (None, Some(prefix)) if prefix.is_empty() => {
+                anyhow::bail!("'prefix' must not be empty")
+            }
+            (None, Some(prefix)) => (prefix, false),
+        };
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let (matched, deleted, errors) = self.runtime.block_on(async move {
+            // The list endpoint matches keys by prefix
+            let (mut caches, _) = client
+                .list_actions_caches(&owner, &repo, Some(&filter), git_ref.as_deref(), usize::MAX)
+                .await?;
+            if exact {
+                caches.retain(|c| c.key == filter);
+            }
+
+            let mut deleted = Vec::new();
+            let mut errors = Vec::new();
+            if !dry_run {
+                for cache in &caches {
+                    match client.delete_actions_cache(&owner, &repo, cache.id).await {
+                        Ok(()) => deleted.push(cache.id),
+                        Err(e) => errors.push(
+                            json!({"id": cache.id, "key": cache.key, "error": e.to_string()}),
+                        ),
+                    }
+                }
+            }
+
+            anyhow::Ok((caches, deleted, errors))
+        })?;
+
+        let freed: i64 = matched
+            .iter()
+            .filter(|c| deleted.contains(&c.id))
+            .map(|c| c.size_in_bytes)
+            .sum();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "dry_run": dry_run,
+            "matched": matched,
+            "matched_count": matched.len(),
+            "deleted_count": deleted.len(),
+            "freed_bytes": freed,
+            "errors": errors,
+        }))
+    }
+
+    fn list_run_jobs(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let run_id = Self::require_id(&params, "run_id")?;
+        let filter = match Self::get_str(&params, "filter").unwrap_or("latest") {
+            "latest" => "latest",
+            "all" => "all",
+            other => anyhow::bail!("Invalid filter '{}'. Expected 'latest' or 'all'", other),
+        };
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let jobs = self
+            .runtime
+            .block_on(async move { client.list_run_jobs(&owner, &repo, run_id, filter).await })?;
+
+        // Flatten steps across jobs to surface the slowest ones directly
+        let mut steps: Vec<Value> = jobs
+            .iter()
+            .flat_map(|job| {
+                job.steps.iter().filter_map(move |step| {
+                    step.duration_seconds.map(|secs| {
+                        serde_json::json!({
+                            "job": job.name,
+                            "step": step.name,
+                            "duration_seconds": secs,
+                            "conclusion": step.conclusion,
+                        })
+                    })
+                })
+            })
+            .collect();
+        steps.sort_by_key(|s| std::cmp::Reverse(s["duration_seconds"].as_i64().unwrap_or(0)));
+        steps.truncate(5);
+
+        let failed_jobs = jobs
+            .iter()
+            .filter(|j| j.conclusion.as_deref() == Some("failure"))
+            .count();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "run_id": run_id,
+            "jobs": jobs,
+            "count": jobs.len(),
+            "failed_count": failed_jobs,
+            "slowest_steps": steps,
+        }))
+    }
+
+    fn get_service_status(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let filter: Option<Vec<String>> =
+            params
+                .get("components")
+                .and_then(|v| v.as_array())
+                .map(|a| {
+                    a.iter()
+                        .filter_map(|v| v.as_str())
+                        .map(|s| s.to_lowercase())
+                        .collect()
+                });
+        let client = self.client()?;
+
+        let mut status = self
+            .runtime
+            .block_on(async move { client.get_service_status().await })?;
+
+        if let Some(filter) = &filter {
+            status
+                .components
+                .retain(|c| filter.iter().any(|f| c.name.to_lowercase().starts_with(f)));
+        }
+
+        let degraded: Vec<&str> = status
+            .degraded_components()
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+
+        Ok(serde_json::json!({
+            "indicator": status.indicator,
+            "description": status.description,
+            "all_operational": degraded.is_empty(),
+            "degraded": degraded,
+            "components": status.components,
+            "incidents": status.incidents,
+            "updated_at": status.updated_at,
+        }))
+    }
+
+    fn transfer_issue(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let target_str = Self::get_str(&params, "target_repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: target_repo"))?;
+        let (target_owner, target_repo) = Self::parse_repo(target_str)?;
+        let create_labels = Self::get_bool(&params, "create_labels", false);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let target_owner = target_owner.to_string();
+        let target_repo = target_repo.to_string();
+
+        let issue = self.runtime.block_on(async move {
+            client
+                .transfer_issue(
+                    &owner,
+                    &repo,
+                    number,
+                    &target_owner,
+                    &target_repo,
+                    create_labels,
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "transferred": true,
+            "from": {
+                "repo": repo_str,
+                "number": number,
+            },
+            "to": {
+                "repo": target_str,
+                "number": issue.number,
+                "url": issue.url,
+            },
+            "issue": issue,
+        }))
+    }
+
+    fn set_issue_pinned(&self, params: HashMap<String, Value>, pinned: bool) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        self.runtime.block_on(async move {
+            client.set_issue_pinned(&owner, &repo, number, pinned).await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "number": number,
+            "pinned": pinned,
+        }))
+    }
+
+    fn lock_conversation(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let reason = match Self::get_str(&params, "reason") {
+            None => None,
+            Some(r) => match r.to_lowercase().replace(['-', ' '], "_").as_str() {
+                "off_topic" => Some("OFF_TOPIC"),
+                "too_heated" => Some("TOO_HEATED"),
+                "resolved" => Some("RESOLVED"),
+                "spam" => Some("SPAM"),
+                _ => anyhow::bail!(
+                    "Invalid reason '{}'. Expected off_topic, too_heated, resolved, or spam",
+                    r
+                ),
+            },
+        };
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        self.runtime.block_on(async move {
+            client
+                .lock_conversation(&owner, &repo, number, reason)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "number": number,
+            "locked": true,
+            "reason": reason,
+        }))
+    }
+
+    fn unlock_conversation(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        self.runtime
+            .block_on(async move { client.unlock_conversation(&owner, &repo, number).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "number": number,
+            "locked": false,
+        }))
+    }
+
+    fn list_invitations(&self) -> Result<Value> {
+        let client = self.client()?;
+
+        let invitations = self
+            .runtime
+            .block_on(async move { client.list_invitations().await })?;
+
+        Ok(serde_json::json!({
+            "invitations": invitations,
+            "count": invitations.len(),
+        }))
+    }
+
+    fn respond_to_invitation(&self, params: HashMap<String, Value>, accept: bool) -> Result<Value> {
+        let id = Self::require_id(&params, "id")?;
+        let client = self.client()?;
+
+        self.runtime.block_on(async move {
+            if accept {
+                client.accept_invitation(id).await
+            } else {
+                client.decline_invitation(id).await
+            }
+        })?;
+
+        Ok(serde_json::json!({
+            "id": id,
+            "accepted": accept,
+        }))
+    }
+
+    /// Serve from the response cache when the method has a TTL. A successful
+    /// write drops the cached reads it may have changed, and so does a write
+    /// refused with CONFLICT: the caller's view is stale, and the re-read
+    /// that follows must not come back from the cache.
+    fn dispatch_cached(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
+        let key = {
+            let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+            let key = cache.key(method, &params);
+            if let Some(value) = key.as_deref().and_then(|k| cache.get(k)) {
+                meta::mark_cached();
+                return Ok(value);
+            }
+            key
+        };
+
+        let result = self.dispatch_method(method, params);
+        let conflict = result
+            .as_ref()
+            .is_err_and(|e| e.chain().any(|cause| cause.is::<Conflict>()));
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        match key {
+            Some(key) => {
+                if let Ok(value) = &result {
+                    cache.put(&key, value);
+                }
+            }
+            None if result.is_ok() || conflict => {
+                let removed = cache.invalidate(method);
+                if removed > 0 {
+                    tracing::debug!("{} invalidated {} cached responses", method, removed);
+                }
+            }
+            None => {}
+        }
+        result
+    }
+
+    /// Route a method call to its implementation.
+    fn dispatch_method(&self, method: &str, params: HashMap<String, Value>) -> Result<Value> {
+        match method {
+            "health" => self.health(),
+            "user" | "github.user" => self.get_user(),
+            "repos" | "github.repos" => self.list_repos(params),
+            "issues" | "github.issues" => self.list_issues(params),
+            "prs" | "github.prs" => self.list_prs(params),
+            "pr" | "github.pr" => self.get_pr(params),
+            "notifications" | "github.notifications" => self.get_notifications(params),
+            "create_issue" | "github.create_issue" => self.create_issue(params),
+            "merge_queue" | "github.merge_queue" => self.get_merge_queue(params),
+            "enqueue_pr" | "github.enqueue_pr" => self.enqueue_pr(params),
+            "dequeue_pr" | "github.dequeue_pr" => self.dequeue_pr(params),
+            "download_archive" | "github.download_archive" => self.download_archive(params),
+            "codespaces" | "github.codespaces" => self.list_codespaces(params),
+            "start_codespace" | "github.start_codespace" => self.start_codespace(params),
+            "stop_codespace" | "github.stop_codespace" => self.stop_codespace(params),
+            "ssh_keys" | "github.ssh_keys" => self.list_ssh_keys(),
+            "add_ssh_key" | "github.add_ssh_key" => self.add_ssh_key(params),
+            "remove_ssh_key" | "github.remove_ssh_key" => self.remove_ssh_key(params),
+            "gpg_keys" | "github.gpg_keys" => self.list_gpg_keys(),
+            "add_gpg_key" | "github.add_gpg_key" => self.add_gpg_key(params),
+            "remove_gpg_key" | "github.remove_gpg_key" => self.remove_gpg_key(params),
+            "secrets" | "github.secrets" => self.list_secrets(params),
+            "set_secret" | "github.set_secret" => self.set_secret(params),
+            "delete_secret" | "github.delete_secret" => self.delete_secret(params),
+            "variables" | "github.variables" => self.list_variables(params),
+            "set_variable" | "github.set_variable" => self.set_variable(params),
+            "delete_variable" | "github.delete_variable" => self.delete_variable(params),
+            "run_jobs" | "github.run_jobs" => self.list_run_jobs(params),
+            "service_status" | "github.service_status" => self.get_service_status(params),
+            "transfer_issue" | "github.transfer_issue" => self.transfer_issue(params),
+            "pin_issue" | "github.pin_issue" => self.set_issue_pinned(params, true),
+            "unpin_issue" | "github.unpin_issue" => self.set_issue_pinned(params, false),
+            "lock" | "github.lock" => self.lock_conversation(params),
+            "unlock" | "github.unlock" => self.unlock_conversation(params),
+            "invitations" | "github.invitations" => self.list_invitations(),
+            "accept_invitation" | "github.accept_invitation" => {
+                self.respond_to_invitation(params, true)
+            }
+            "decline_invitation" | "github.decline_invitation" => {
+                self.respond_to_invitation(params, false)
+            }
+            "create_commit" | "github.create_commit" => self.create_commit(params),
+            "review_threads" | "github.review_threads" => self.list_review_threads(params),
+            "resolve_thread" | "github.resolve_thread" => self.set_thread_resolved(params, true),
+            "unresolve_thread" | "github.unresolve_thread" => {
+                self.set_thread_resolved(params, false)
+            }
+            "list_forks" | "github.list_forks" => self.list_forks(params),
+            "sync_fork" | "github.sync_fork" => self.sync_fork(params),
+            "pr_score" | "github.pr_score" => self.pr_score(params),
+            "pr_links" | "github.pr_links" => self.pr_links(params),
+            "release_notes" | "github.release_notes" => self.release_notes(params),
+            "ref_gate" | "github.ref_gate" => self.ref_gate(params),
+            "start_review" | "github.start_review" => self.start_review(params),
+            "add_review_comment" | "github.add_review_comment" => self.add_review_comment(params),
+            "submit_review" | "github.submit_review" => self.submit_review(params),
+            "discard_review" | "github.discard_review" => self.discard_review(params),
+            "sso_status" | "github.sso_status" => self.sso_status(params),
+            "org_access_audit" | "github.org_access_audit" => self.org_access_audit(params),
+            "org_repo_summary" | "github.org_repo_summary" => self.org_repo_summary(params),
+            "file_history" | "github.file_history" => self.file_history(params),
+            "tree" | "github.tree" => self.list_tree(params),
+            "workflow_config" | "github.workflow_config" => self.workflow_config(params),
+            "stale_branches" | "github.stale_branches" => self.stale_branches(params),
+            "similar_issues" | "github.similar_issues" => self.similar_issues(params),
+            "remind" | "github.remind" => self.remind(params),
+            "reminders" | "github.reminders" => self.list_reminders(params),
+            "cancel_reminder" | "github.cancel_reminder" => self.cancel_reminder(params),
+            "cache_stats" | "github.cache_stats" => self.cache_stats(),
+            "local_search" | "github.local_search" => self.local_search(params),
+            "cache_clear" | "github.cache_clear" => self.cache_clear(params),
+            "update_issue" | "github.update_issue" => self.update_issue(params),
+            "set_labels" | "github.set_labels" => self.set_labels(params),
+            "put_file" | "github.put_file" => self.put_file(params),
+            "permissions" | "github.permissions" => self.permissions(params),
+            "events_since" | "github.events_since" => self.events_since(params),
+            "verify_ref" | "github.verify_ref" => self.verify_ref(params),
+            "bootstrap_repo" | "github.bootstrap_repo" => self.bootstrap_repo(params),
+            "community_profile" | "github.community_profile" => self.community_profile(params),
+            "actions_caches" | "github.actions_caches" => self.actions_caches(params),
+            "delete_actions_caches" | "github.delete_actions_caches" => {
+                self.delete_actions_caches(params)
+            }
+            "review_metrics" | "github.review_metrics" => self.review_metrics(params),
+            "plan" | "github.plan" => self.plan(params),
+            "apply" | "github.apply" => self.apply(params),
+            _ => anyhow::bail!("Unknown method: {}", method),
+        }
+    }
+
+    fn create_commit(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let branch = Self::get_str(&params, "branch")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: branch"))?
+            .to_string();
+        let message = Self::get_str(&params, "message")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: message"))?;
+        let additions = Self::parse_file_additions(&params)?;
+        let deletions = Self::get_str_list(&params, "deletions");
+        let expected_head = Self::get_str(&params, "expected_head_oid").map(|s| s.to_string());
+
+        if additions.is_empty() && deletions.is_empty() {
+            anyhow::bail!("Nothing to commit: provide 'additions' and/or 'deletions'");
+        }
+
+        let (headline, body) = Self::split_message(message);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let commit = self.runtime.block_on(async move {
+            client
+                .create_commit(
+                    &owner,
+                    &repo,
+                    &branch,
+                    &headline,
+                    body.as_deref(),
+                    &additions,
+                    &deletions,
+                    expected_head.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "created": true,
+            "repo": repo_str,
+            "commit": commit,
+        }))
+    }
+
+    fn list_review_threads(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let unresolved_only = Self::get_bool(&params, "unresolved_only", false);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let mut threads = self
+            .runtime
+            .block_on(async move { client.list_review_threads(&owner, &repo, number).await })?;
+
+        let resolved = threads.iter().filter(|t| t.is_resolved).count();
+        let unresolved = threads.len() - resolved;
+        if unresolved_only {
+            threads.retain(|t| !t.is_resolved);
+        }
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "number": number,
+            "threads": threads,
+            "resolved_count": resolved,
+            "unresolved_count": unresolved,
+        }))
+    }
+
+    fn set_thread_resolved(&self, params: HashMap<String, Value>, resolved: bool) -> Result<Value> {
+        let thread_id = Self::get_str(&params, "thread_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: thread_id"))?
+            .to_string();
+
+        let client = self.client()?;
+        let id_for_response = thread_id.clone();
+
+        let is_resolved = self
+            .runtime
+            .block_on(async move { client.set_thread_resolved(&thread_id, resolved).await })?;
+
+        Ok(serde_json::json!({
+            "thread_id": id_for_response,
+            "is_resolved": is_resolved,
+        }))
+    }
+
+    fn list_forks(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let sort = Self::get_str(&params, "sort").unwrap_or("updated");
+        let order_by = match sort {
+            "updated" => "UPDATED_AT",
+            "pushed" => "PUSHED_AT",
+            "stars" => "STARGAZERS",
+            "created" => "CREATED_AT",
+            other => anyhow::bail!(
+                "Invalid sort '{}': expected updated, pushed, stars or created",
+                other
+            ),
+        };
+        let limit = Self::get_i32(&params, "limit", 30);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let forks = self
+            .runtime
+            .block_on(async move { client.list_forks(&owner, &repo, order_by, limit).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "sort": sort,
+            "forks": forks,
+            "count": forks.len(),
+        }))
+    }
+
+    fn sync_fork(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let branch = Self::get_str(&params, "branch")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: branch"))?;
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let branch = branch.to_string();
+        let branch_for_response = branch.clone();
+
+        let result = self
+            .runtime
+            .block_on(async move { client.sync_fork(&owner, &repo, &branch).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "branch": branch_for_response,
+            "synced": true,
+            "message": result.message,
+            "merge_type": result.merge_type,
+            "base_branch": result.base_branch,
+        }))
+    }
+
+    fn pr_score(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let (diff, codeowners) = self.runtime.block_on(async move {
+            let diff = client.get_pr_files(&owner, &repo, number).await?;
+            // Owners come from the branch the PR merges into, as on GitHub
+            let codeowners = client.get_codeowners(&owner, &repo, &diff.base_ref).await?;
+            anyhow::Ok((diff, codeowners))
+        })?;
+
+        if let Some(text) = &codeowners {
+            let doc = json!({
+                "repo": repo_str,
+                "ref": diff.base_ref,
+                "path": "CODEOWNERS",
+                "content": text,
+            });
+            self.index_content(
+                "github.pr_score",
+                vec![(
+                    format!("github.pr_score:{}@{}:CODEOWNERS", repo_str, diff.base_ref),
+                    doc,
+                )],
+            );
+        }
+
+        let owners = codeowners.as_deref().map(CodeOwners::parse);
+        let score = PrScore::compute(number, &diff, owners.as_ref());
+
+        let mut result = serde_json::json!(score);
+        result["repo"] = json!(repo_str);
+        result["has_codeowners"] = json!(owners.is_some());
+        Ok(result)
+    }
+
+    fn pr_links(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let links = self
+            .runtime
+            .block_on(async move { client.get_links(&owner, &repo, number).await })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "number": number,
+            "kind": links.kind,
+            "closes": links.closes,
+            "closed_by": links.closed_by,
+        }))
+    }
+
+    fn release_notes(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let from_tag = Self::get_str(&params, "from_tag")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: from_tag"))?;
+        let to_tag = Self::get_str(&params, "to_tag")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: to_tag"))?;
+        let use_github_notes = Self::get_bool(&params, "use_github_notes", false);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let from = from_tag.to_string();
+        let to = to_tag.to_string();
+
+        let (prs, unlinked, total_commits, github_notes) = self.runtime.block_on(async move {
+            let (shas, total_commits) = client.compare_commits(&owner, &repo, &from, &to).await?;
+            let (prs, unlinked) = client.merged_prs_for_commits(&owner, &repo, &shas).await?;
+            let github_notes = if use_github_notes {
+                Some(
+                    client
+                        .generate_release_notes(&owner, &repo, &to, &from)
+                        .await?,
+                )
+            } else {
+                None
+            };
+            anyhow::Ok((prs, unlinked, total_commits, github_notes))
+        })?;
+
+        let mut notes = ReleaseNotes::build(from_tag, to_tag, total_commits, unlinked, prs);
+        notes.github_notes = github_notes;
+
+        let mut result = serde_json::json!(notes);
+        result["repo"] = json!(repo_str);
+        result["pr_count"] = json!(notes.sections.iter().map(|s| s.prs.len()).sum::<usize>());
+        Ok(result)
+    }
+
+    fn ref_gate(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let git_ref = Self::get_str(&params, "ref")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: ref"))?;
+        let required = Self::get_str_list(&params, "required_contexts");
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let ref_owned = git_ref.to_string();
+
+        let (sha, checks) = self
+            .runtime
+            .block_on(async move { client.get_ref_checks(&owner, &repo, &ref_owned).await })?;
+
+        let gate = RefGate::evaluate(sha, &checks, &required);
+
+        let mut result = serde_json::json!(gate);
+        result["repo"] = json!(repo_str);
+        result["ref"] = json!(git_ref);
+        Ok(result)
+    }
+
+    fn start_review(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let body = Self::get_str(&params, "body").map(|s| s.to_string());
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let review_id = self.runtime.block_on(async move {
+            client
+                .start_review(&owner, &repo, number, body.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "number": number,
+            "review_id": review_id,
+            "state": "PENDING",
+        }))
+    }
+
+    fn add_review_comment(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let review_id = Self::get_str(&params, "review_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: review_id"))?
+            .to_string();
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?
+            .to_string();
+        let line = Self::get_i32(&params, "line", 0);
+        if line == 0 {
+            anyhow::bail!("Missing required parameter: line");
+        }
+        let body = Self::get_str(&params, "body")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: body"))?
+            .to_string();
+        let side = Self::get_str(&params, "side")
+            .unwrap_or("RIGHT")
+            .to_uppercase();
+        if side != "RIGHT" && side != "LEFT" {
+            anyhow::bail!("Invalid side '{}': expected LEFT or RIGHT", side);
+        }
+        let start_line = params
+            .get("start_line")
+            .and_then(|v| v.as_i64())
+            .map(|v| v as i32);
+
+        let client = self.client()?;
+        let path_for_response = path.clone();
+        let review_for_response = review_id.clone();
+
+        let thread_id = self.runtime.block_on(async move {
+            client
+                .add_review_comment(&review_id, &path, line, &body, &side, start_line)
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "review_id": review_for_response,
+            "thread_id": thread_id,
+            "path": path_for_response,
+            "line": line,
+        }))
+    }
+
+    fn submit_review(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let review_id = Self::get_str(&params, "review_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: review_id"))?
+            .to_string();
+        let event = Self::get_str(&params, "event")
+            .unwrap_or("COMMENT")
+            .to_uppercase();
+        if !matches!(event.as_str(), "APPROVE" | "REQUEST_CHANGES" | "COMMENT") {
+            anyhow::bail!(
+                "Invalid event '{}': expected APPROVE, REQUEST_CHANGES or COMMENT",
+                event
+            );
+        }
+        let body = Self::get_str(&params, "body").map(|s| s.to_string());
+
+        let client = self.client()?;
+
+        let review = self.runtime.block_on(async move {
+            client
+                .submit_review(&review_id, &event, body.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!(review))
+    }
+
+    fn discard_review(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let review_id = Self::get_str(&params, "review_id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: review_id"))?
+            .to_string();
+
+        let client = self.client()?;
+        let id_for_response = review_id.clone();
+
+        self.runtime
+            .block_on(async move { client.discard_review(&review_id).await })?;
+
+        Ok(serde_json::json!({
+            "review_id": id_for_response,
+            "discarded": true,
+        }))
+    }
+
+    fn sso_status(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let orgs = Self::get_str_list(&params, "orgs");
+        let client = self.client()?;
+
+        let statuses = self.runtime.block_on(async move {
+            let orgs = if orgs.is_empty() {
+                client.list_user_orgs().await?
+            } else {
+                orgs
+            };
+
+            // One org failing to answer shouldn't hide the others
+            let mut statuses = Vec::with_capacity(orgs.len());
+            for org in &orgs {
+                let status = client
+                    .get_sso_status(org)
+                    .await
+                    .unwrap_or_else(|e| SsoOrgStatus {
+                        org: org.clone(),
+                        authorized: false,
+                        authorization_url: None,
+                        error: Some(e.to_string()),
+                    });
+                statuses.push(status);
+            }
+            anyhow::Ok(statuses)
+        })?;
+
+        let unauthorized: Vec<&str> = statuses
+            .iter()
+            .filter(|s| !s.authorized && s.error.is_none())
+            .map(|s| s.org.as_str())
+            .collect();
+        let failed: Vec<&str> = statuses
+            .iter()
+            .filter(|s| s.error.is_some())
+            .map(|s| s.org.as_str())
+            .collect();
+
+        Ok(serde_json::json!({
+            "orgs": statuses,
+            "all_authorized": unauthorized.is_empty() && failed.is_empty(),
+            "unauthorized": unauthorized,
+            "failed": failed,
+        }))
+    }
+
+    fn org_access_audit(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+
+        let client = self.client()?;
+        let org_owned = org.clone();
+
+        let (members, repo_names, invitations, collaborators, repo_errors) =
+            self.runtime.block_on(async move {
+                let (members, repos, invitations) = tokio::join!(
+                    client.list_org_members(&org_owned),
+                    client.list_org_repo_names(&org_owned),
+                    client.list_org_invitations(&org_owned)
+                );
+                let (members, repos, invitations) = (members?, repos?, invitations?);
+
+                // Fan out per-repo collaborator lookups with bounded concurrency
+                let semaphore = Arc::new(Semaphore::new(ORG_FANOUT_CONCURRENCY));
+                let mut tasks = JoinSet::new();
+                for repo in repos.clone() {
+                    let client = client.clone();
+                    let org = org_owned.clone();
+                    let semaphore = semaphore.clone();
+                    tasks.spawn(meta::scope(async move {
+                        let _permit = semaphore.acquire_owned().await;
+                        let result = client.list_outside_collaborators(&org, &repo).await;
+                        (repo, result)
+                    }));
+                }
+
+                let mut collaborators: HashMap<String, Vec<RepoAccess>> = HashMap::new();
+                let mut repo_errors = Vec::new();
+                while let Some(joined) = tasks.join_next().await {
+                    let (repo, result) = joined?;
+                    match result {
+                        Ok(list) => {
+                            for (login, permission) in list {
+                                collaborators.entry(login).or_default().push(RepoAccess {
+                                    repo: repo.clone(),
+                                    permission,
+                                });
+                            }
+                        }
+                        Err(e) => repo_errors.push(json!({"repo": repo, "error": e.to_string()})),
+                    }
+                }
+
+                anyhow::Ok((members, repos, invitations, collaborators, repo_errors))
+            })?;
+
+        let mut outside_collaborators: Vec<OutsideCollaborator> = collaborators
+            .into_iter()
+            .map(|(login, mut repos)| {
+                repos.sort_by(|a, b| a.repo.cmp(&b.repo));
+                OutsideCollaborator { login, repos }
+            })
+            .collect();
+        outside_collaborators.sort_by_key(|c| c.login.to_lowercase());
+
+        let admin_count = members.iter().filter(|m| m.role == "admin").count();
+
+        Ok(serde_json::json!({
+            "org": org,
+            "members": members,
+            "member_count": members.len(),
+            "admin_count": admin_count,
+            "outside_collaborators": outside_collaborators,
+            "outside_collaborator_count": outside_collaborators.len(),
+            "pending_invitations": invitations,
+            "repo_count": repo_names.len(),
+            "repo_errors": repo_errors,
+        }))
+    }
+
+    fn org_repo_summary(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let org = Self::get_str(&params, "org")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: org"))?
+            .to_string();
+        let limit = Self::get_i32(&params, "limit", ORG_REPO_SUMMARY_DEFAULT)
+            .clamp(1, ORG_REPO_SUMMARY_MAX);
+        let include_archived = Self::get_bool(&params, "include_archived", false);
+
+        let client = self.client()?;
+        let org_owned = org.clone();
+
+        let repos = self.runtime.block_on(async move {
+            client
+                .org_repo_summary(&org_owned, limit, include_archived)
+                .await
+        })?;
+
+        let failing: Vec<&str> = repos
+            .iter()
+            .filter(|r| matches!(r.ci_state.as_deref(), Some("FAILURE") | Some("ERROR")))
+            .map(|r| r.name.as_str())
+            .collect();
+
+        Ok(serde_json::json!({
+            "org": org,
+            "repos": repos,
+            "count": repos.len(),
+            "failing_ci": failing,
+        }))
+    }
+
+    fn file_history(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?;
+        let git_ref = Self::get_str(&params, "ref").unwrap_or("HEAD");
+        let limit = Self::get_i32(&params, "limit", 20);
+        let follow_renames = Self::get_bool(&params, "follow_renames", false);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let path_owned = path.to_string();
+        let ref_owned = git_ref.to_string();
+
+        let (commits, renames) = self.runtime.block_on(async move {
+            let mut commits = Vec::new();
+            let mut renames = Vec::new();
+            let mut path = path_owned;
+            let mut from_ref = ref_owned;
+
+            // Follow at most a handful of renames so one call stays bounded
+            for _ in 0..=MAX_FOLLOWED_RENAMES {
+                let remaining = limit - commits.len() as i32;
+                let batch = client
+                    .file_history(&owner, &repo, &path, &from_ref, remaining)
+                    .await?;
+                let exhausted = (batch.len() as i32) < remaining;
+                let oldest = batch.last().map(|c| c.sha.clone());
+                commits.extend(batch);
+
+                if !follow_renames || !exhausted {
+                    break;
+                }
+                let Some(oldest) = oldest else { break };
+                match client.renamed_from(&owner, &repo, &oldest, &path).await? {
+                    Some(previous) => {
+                        renames.push(json!({"commit": oldest, "from": previous, "to": path}));
+                        path = previous;
+                        from_ref = format!("{}^", oldest);
+                    }
+                    None => break,
+                }
+            }
+
+            anyhow::Ok((commits, renames))
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "path": path,
+            "ref": git_ref,
+            "commits": commits,
+            "count": commits.len(),
+            "renames": renames,
+        }))
+    }
+
+    fn list_tree(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let git_ref = Self::get_str(&params, "ref").unwrap_or("HEAD");
+        let path = Self::get_str(&params, "path").unwrap_or("");
+        let recursive = Self::get_bool(&params, "recursive", false);
+        let max_depth = Self::get_i32(&params, "max_depth", 3).max(1) as usize;
+        let max_entries = Self::get_i32(&params, "max_entries", 1000).max(1) as usize;
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let ref_owned = git_ref.to_string();
+        let path_owned = path.to_string();
+
+        let (mut entries, github_truncated) = self.runtime.block_on(async move {
+            client
+                .list_tree(&owner, &repo, &ref_owned, &path_owned, recursive, max_depth)
+                .await
+        })?;
+
+        let total = entries.len();
+        entries.truncate(max_entries);
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "ref": git_ref,
+            "path": path,
+            "entries": entries,
+            "count": entries.len(),
+            "truncated": github_truncated || total > max_entries,
+        }))
+    }
+
+    fn workflow_config(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let git_ref = Self::get_str(&params, "ref").unwrap_or("HEAD");
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let ref_owned = git_ref.to_string();
+
+        let files = self
+            .runtime
+            .block_on(async move { client.get_workflow_files(&owner, &repo, &ref_owned).await })?;
+
+        let docs = files
+            .iter()
+            .map(|(path, text)| {
+                let doc = json!({"repo": repo_str, "ref": git_ref, "path": path, "content": text});
+                (
+                    format!("github.workflow_config:{}@{}:{}", repo_str, git_ref, path),
+                    doc,
+                )
+            })
+            .collect();
+        self.index_content("github.workflow_config", docs);
+
+        let workflows: Vec<_> = files
+            .iter()
+            .map(|(path, text)| parse_workflow(path, text))
+            .collect();
+        let unpinned: usize = workflows.iter().map(|w| w.unpinned_third_party.len()).sum();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "ref": git_ref,
+            "workflows": workflows,
+            "count": workflows.len(),
+            "unpinned_third_party_count": unpinned,
+        }))
+    }
+
+    fn stale_branches(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let threshold_days = Self::get_i32(&params, "threshold_days", 90);
+        let delete_merged = Self::get_bool(&params, "delete_merged", false);
+        let dry_run = Self::get_bool(&params, "dry_run", true);
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(i64::from(threshold_days));
+
+        let (default_branch, stale, deleted, would_delete, delete_errors) =
+            self.runtime.block_on(async move {
+                let default_branch = client.get_default_branch(&owner, &repo).await?;
+                let branches = client.list_branches(&owner, &repo, &default_branch).await?;
+
+                let mut stale: Vec<_> = branches
+                    .into_iter()
+                    .filter(|b| {
+                        chrono::DateTime::parse_from_rfc3339(&b.last_commit_date)
+                            .map(|d| d < cutoff)
+                            .unwrap_or(false)
+                    })
+                    .collect();
+                stale.sort_by(|a, b| a.last_commit_date.cmp(&b.last_commit_date));
+
+                let deletable: Vec<String> = stale
+                    .iter()
+                    .filter(|b| b.is_merged() && b.open_pr.is_none() && !b.protected)
+                    .map(|b| b.name.clone())
+                    .collect();
+
+                let mut deleted = Vec::new();
+                let mut would_delete = Vec::new();
+                let mut delete_errors = Vec::new();
+                if delete_merged {
+                    for branch in deletable {
+                        if dry_run {
+                            would_delete.push(branch);
+                            continue;
+                        }
+                        match client.delete_branch(&owner, &repo, &branch).await {
+                            Ok(()) => deleted.push(branch),
+                            Err(e) => delete_errors
+                                .push(json!({"branch": branch, "error": e.to_string()})),
+                        }
+                    }
+                }
+
+                anyhow::Ok((default_branch, stale, deleted, would_delete, delete_errors))
+            })?;
+
+        let now = chrono::Utc::now();
+        let branches: Vec<Value> = stale
+            .iter()
+            .map(|b| {
+                let mut entry = json!(b);
+                entry["merged"] = json!(b.is_merged());
+                entry["days_since_commit"] =
+                    json!(chrono::DateTime::parse_from_rfc3339(&b.last_commit_date)
+                        .map(|d| (now - d.with_timezone(&chrono::Utc)).num_days())
+                        .ok());
+                entry
+            })
+            .collect();
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "default_branch": default_branch,
+            "threshold_days": threshold_days,
+            "branches": branches,
+            "count": branches.len(),
+            "dry_run": dry_run,
+            "deleted": deleted,
+            "would_delete": would_delete,
+            "delete_errors": delete_errors,
+        }))
+    }
+
+    fn similar_issues(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let title = Self::get_str(&params, "title")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: title"))?;
+        let body = Self::get_str(&params, "body").unwrap_or("");
+        let limit = Self::get_i32(&params, "limit", 5).max(1) as usize;
+        let min_score = params
+            .get("min_score")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.2);
+
+        let title_tokens = tokenize(title);
+        let body_tokens = tokenize(body);
+        if title_tokens.is_empty() {
+            anyhow::bail!("Title has no searchable terms");
+        }
+
+        // Longest terms are usually the most specific; GitHub search allows 5 ORs
+        let mut terms: Vec<&String> = title_tokens.iter().collect();
+        terms.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        let search_terms = terms
+            .iter()
+            .take(6)
+            .map(|t| t.as_str())
+            .collect::<Vec<_>>()
+            .join(" OR ");
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let candidates = self.runtime.block_on(async move {
+            client
+                .search_issues(&owner, &repo, &search_terms, SIMILAR_ISSUE_CANDIDATES)
+                .await
+        })?;
+
+        let mut similar: Vec<SimilarIssue> = candidates
+            .into_iter()
+            .map(|(number, title, body, state, url)| {
+                let (score, matched_terms) =
+                    score_candidate(&title_tokens, &body_tokens, &title, &body);
+                SimilarIssue {
+                    number,
+                    title,
+                    state,
+                    url,
+                    score: (score * 1000.0).round() / 1000.0,
+                    matched_terms,
+                }
+            })
+            .filter(|s| s.score >= min_score)
+            .collect();
+        similar.sort_by(|a, b| b.score.total_cmp(&a.score));
+        similar.truncate(limit);
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "similar": similar,
+            "count": similar.len(),
+            "likely_duplicate": similar.first().is_some_and(|s| s.score >= 0.5),
+        }))
+    }
+
+    fn remind(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let name = Self::get_str(&params, "name")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: name"))?;
+        let query = Self::get_str(&params, "query")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: query"))?;
+        let time = Self::get_str(&params, "time").unwrap_or("09:00");
+        let mut days = Self::get_str_list(&params, "days");
+        if days.is_empty() {
+            days.push("weekdays".to_string());
+        }
+        let older_than_days = params
+            .get("older_than_days")
+            .and_then(|v| v.as_i64())
+            .map(|v| v as i32);
+
+        let reminder = Reminder::new(name, query, older_than_days, &days, time)?;
+
+        let mut store = self.reminders.lock().unwrap_or_else(|e| e.into_inner());
+        store.reminders.push(reminder.clone());
+        store.save()?;
+
+        Ok(serde_json::json!(reminder))
+    }
+
+    fn list_reminders(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let run_now = Self::get_str(&params, "run_now");
+
+        if let Some(id) = run_now {
+            let query = {
+                let store = self.reminders.lock().unwrap_or_else(|e| e.into_inner());
+                store
+                    .reminders
+                    .iter()
+                    .find(|r| r.id == id)
+                    .map(|r| r.search_query(chrono::Utc::now()))
+                    .ok_or_else(|| anyhow::anyhow!("Reminder not found: {}", id))?
+            };
+            let client = self.client()?;
+            run_reminder(
+                &client,
+                self.runtime.handle(),
+                &self.reminders,
+                &self.events,
+                id,
+                &query,
+            );
+        }
+
+        let store = self.reminders.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(serde_json::json!({
+            "reminders": store.reminders,
+            "count": store.reminders.len(),
+        }))
+    }
+
+    fn cancel_reminder(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let id = Self::get_str(&params, "id")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: id"))?;
+
+        let mut store = self.reminders.lock().unwrap_or_else(|e| e.into_inner());
+        if !store.remove(id) {
+            anyhow::bail!("Reminder not found: {}", id);
+        }
+        store.save()?;
+
+        Ok(serde_json::json!({
+            "id": id,
+            "cancelled": true,
+        }))
+    }
+
+    fn cache_stats(&self) -> Result<Value> {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(cache.stats())
+    }
+
+    fn local_search(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let query = Self::get_str(&params, "query")
+            .filter(|q| !q.is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: query"))?;
+        let regex = Self::get_bool(&params, "regex", false);
+        let case_sensitive = Self::get_bool(&params, "case_sensitive", false);
+        let include_stale = Self::get_bool(&params, "include_stale", true);
+        let methods: Vec<String> = Self::get_str_list(&params, "methods")
+            .iter()
+            .map(|m| {
+                if m.starts_with("github.") {
+                    m.clone()
+                } else {
+                    format!("github.{}", m)
+                }
+            })
+            .collect();
+        let limit =
+            Self::get_i32(&params, "limit", LOCAL_SEARCH_DEFAULT).clamp(1, LOCAL_SEARCH_MAX);
+
+        let re = local_search::matcher(query, regex, case_sensitive)?;
+        let mut responses = self
+            .index
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .snapshot();
+        responses.extend(
+            self.cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .snapshot(),
+        );
+        responses.retain(|r| {
+            (include_stale || !r.stale) && (methods.is_empty() || methods.contains(&r.method))
+        });
+
+        let hits = local_search::search(&responses, &re, limit as usize);
+
+        Ok(serde_json::json!({
+            "query": query,
+            "hits": hits,
+            "count": hits.len(),
+            "searched_responses": responses.len(),
+            "truncated": hits.len() == limit as usize,
+        }))
+    }
+
+    fn cache_clear(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let method = Self::get_str(&params, "method");
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        let removed = cache.clear(method);
+
+        Ok(serde_json::json!({
+            "method": method,
+            "removed": removed,
+        }))
+    }
+
+    fn update_issue(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        let expected = Self::get_str(&params, "expected_updated_at").map(|s| s.to_string());
+
+        let mut changes = serde_json::Map::new();
+        for field in ["title", "body"] {
+            if let Some(value) = Self::get_str(&params, field) {
+                changes.insert(field.to_string(), Value::from(value));
+            }
+        }
+        if let Some(state) = Self::get_str(&params, "state") {
+            let state = state.to_lowercase();
+            if state != "open" && state != "closed" {
+                anyhow::bail!("Invalid state '{}': use 'open' or 'closed'", state);
+            }
+            changes.insert("state".to_string(), Value::from(state));
+        }
+        if changes.is_empty() {
+            anyhow::bail!("Nothing to update: provide 'title', 'body', and/or 'state'");
+        }
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let changes = Value::Object(changes);
+
+        let issue = self.runtime.block_on(async move {
+            client
+                .update_issue(&owner, &repo, number, &changes, expected.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "updated": true,
+            "repo": repo_str,
+            "issue": issue,
+        }))
+    }
+
+    fn set_labels(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let number = Self::get_i32(&params, "number", 0);
+        if number == 0 {
+            anyhow::bail!("Missing required parameter: number");
+        }
+        if !params.contains_key("labels") {
+            anyhow::bail!("Missing required parameter: labels");
+        }
+        let labels = Self::get_str_list(&params, "labels");
+        let expected = Self::get_str(&params, "expected_updated_at").map(|s| s.to_string());
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let labels = self.runtime.block_on(async move {
+            client
+                .set_labels(&owner, &repo, number, &labels, expected.as_deref())
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "number": number,
+            "labels": labels,
+            "count": labels.len(),
+        }))
+    }
+
+    fn put_file(&self, params: HashMap<String, Value>) -> Result<Value> {
+        use base64::Engine;
+
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let path = Self::get_str(&params, "path")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: path"))?
+            .trim_start_matches('/')
+            .to_string();
+        let message = Self::get_str(&params, "message")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: message"))?
+            .to_string();
+        let content = if let Some(text) = Self::get_str(&params, "content") {
+            text.as_bytes().to_vec()
+        } else if let Some(b64) = Self::get_str(&params, "content_base64") {
+            base64::engine::general_purpose::STANDARD
+                .decode(b64)
+                .map_err(|e| anyhow::anyhow!("Invalid content_base64: {}", e))?
+        } else {
+            anyhow::bail!("Missing required parameter: content or content_base64");
+        };
+        let branch = Self::get_str(&params, "branch").map(|s| s.to_string());
+        let expected_sha = Self::get_str(&params, "expected_sha").map(|s| s.to_string());
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let write = self.runtime.block_on(async move {
+            client
+                .put_file(
+                    &owner,
+                    &repo,
+                    &path,
+                    &content,
+                    &message,
+                    branch.as_deref(),
+                    expected_sha.as_deref(),
+                )
+                .await
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "file": write,
+        }))
+    }
+
+    fn permissions(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        let permissions = self
+            .runtime
+            .block_on(async move { client.get_repo_permissions(&owner, &repo).await })?;
+
+        Ok(serde_json::json!(permissions))
+    }
+
+    fn events_since(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let after = params.get("seq").and_then(|v| v.as_u64()).unwrap_or(0);
+        let limit = Self::get_i32(&params, "limit", EVENTS_PAGE_DEFAULT).clamp(1, EVENTS_PAGE_MAX);
+        let kinds = Self::get_str_list(&params, "kinds");
+
+        let log = self.events.lock().unwrap_or_else(|e| e.into_inner());
+        let events = log.since(after, limit as usize, &kinds);
+        let latest_seq = log.last_seq();
+        let oldest_seq = log.oldest_seq();
+        let next_seq = events
+            .last()
+            .map(|e| e.seq)
+            .unwrap_or(after.min(latest_seq));
+
+        Ok(serde_json::json!({
+            "events": events,
+            "count": events.len(),
+            "next_seq": next_seq,
+            "latest_seq": latest_seq,
+            "oldest_seq": oldest_seq,
+            // Events between `seq` and `oldest_seq` were compacted away
+            "gap": oldest_seq.is_some_and(|oldest| oldest > after + 1),
+        }))
+    }
+
+    fn verify_ref(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let head = Self::get_str(&params, "head")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: head"))?
+            .to_string();
+        let base = Self::get_str(&params, "base").map(String::from);
+        let limit = Self::get_i32(&params, "limit", VERIFY_REF_DEFAULT).clamp(1, VERIFY_REF_MAX);
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let repo_full = format!("{}/{}", owner, repo);
+
+        let (commits, truncated, tag_signature) = self.runtime.block_on({
+            let base = base.clone();
+            let head = head.clone();
+            async move {
+                client
+                    .verify_commits(&owner, &repo, base.as_deref(), &head, limit as usize)
+                    .await
+            }
+        })?;
+
+        Ok(serde_json::json!(RefVerification::build(
+            repo_full,
+            base,
+            head,
+            commits,
+            truncated,
+            tag_signature,
+        )))
+    }
+
+    fn bootstrap_repo(&self, params: HashMap<String, Value>) -> Result<Value> {
+        use chrono::Datelike;
+
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let gitignore = Self::get_str(&params, "gitignore").map(String::from);
+        let license = Self::get_str(&params, "license").map(String::from);
+        let with_readme = Self::get_bool(&params, "readme", true);
+        let description = Self::get_str(&params, "description").map(String::from);
+        let holder = Self::get_str(&params, "holder").map(String::from);
+        let branch = Self::get_str(&params, "branch").map(String::from);
+        let message = Self::get_str(&params, "message")
+            .unwrap_or("Initial commit")
+            .to_string();
+        let dry_run = Self::get_bool(&params, "dry_run", false);
+        let labels = match params.get("labels") {
+            Some(v) => serde_json::from_value::<Vec<LabelSpec>>(v.clone())
+                .map_err(|e| anyhow::anyhow!("Invalid 'labels': {}", e))?
+                .into_iter()
+                .map(|l| LabelSpec {
+                    color: l.color.trim_start_matches('#').to_string(),
+                    ..l
+                })
+                .collect(),
+            None => bootstrap::default_labels(),
+        };
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+
+        self.runtime.block_on(async move {
+            let setup = client.get_repo_setup(&owner, &repo).await?;
+            let branch = branch
+                .or(setup.default_branch.clone())
+                .unwrap_or_else(|| "main".to_string());
+
+            let license = match &license {
+                Some(key) => Some(client.get_license_template(key).await?),
+                None => None,
+            };
+
+            let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+            if with_readme {
+                let readme = bootstrap::readme(
+                    &setup.name,
+                    description.as_deref().or(setup.description.as_deref()),
+                    license.as_ref().map(|(name, _)| name.as_str()),
+                );
+                files.push(("README.md".to_string(), readme.into_bytes()));
+            }
+            if let Some(template) = &gitignore {
+                let source = client.get_gitignore_template(template).await?;
+                files.push((".gitignore".to_string(), source.into_bytes()));
+            }
+            if let Some((_, body)) = &license {
+                let holder = holder.as_deref().unwrap_or(&setup.owner_name);
+                let text = bootstrap::fill_license(body, chrono::Utc::now().year(), holder);
+                files.push(("LICENSE".to_string(), text.into_bytes()));
+            }
+
+            let to_create = bootstrap::missing_labels(labels, &setup.labels);
+            if files.is_empty() && to_create.is_empty() {
+                anyhow::bail!("Nothing to bootstrap: every requested label already exists and no files were requested");
+            }
+
+            let paths: Vec<&str> = files.iter().map(|(p, _)| p.as_str()).collect();
+            if dry_run {
+                let preview: Vec<Value> = files
+                    .iter()
+                    .map(|(path, contents)| {
+                        json!({"path": path, "content": String::from_utf8_lossy(contents)})
+                    })
+                    .collect();
+                return anyhow::Ok(json!({
+                    "repo": repo_str,
+                    "branch": branch,
+                    "was_empty": setup.is_empty,
+                    "dry_run": true,
+                    "files": preview,
+                    "labels_to_create": to_create,
+                }));
+            }
+
+            // createCommitOnBranch needs an existing branch, so an empty repo
+            // gets its first file through the contents API.
+            let mut rest = files.as_slice();
+            let mut seed = None;
+            if setup.is_empty {
+                if let Some(((path, contents), tail)) = files.split_first() {
+                    seed = Some(
+                        client
+                            .put_file(&owner, &repo, path, contents, &message, Some(&branch), None)
+                            .await?,
+                    );
+                    rest = tail;
+                }
+            }
+            let commit = if rest.is_empty() {
+                None
+            } else {
+                Some(
+                    client
+                        .create_commit(&owner, &repo, &branch, &message, None, rest, &[], None)
+                        .await?,
+                )
+            };
+
+            let mut labels_created = Vec::new();
+            let mut label_errors = Vec::new();
+            for label in to_create {
+                match client.create_label(&owner, &repo, &label).await {
+                    Ok(()) => labels_created.push(label.name),
+                    Err(e) => {
+                        label_errors.push(json!({"label": label.name, "error": e.to_string()}))
+                    }
+                }
+            }
+
+            anyhow::Ok(json!({
+                "repo": repo_str,
+                "branch": branch,
+                "was_empty": setup.is_empty,
+                "dry_run": false,
+                "files": paths,
+                "seed": seed,
+                "commit": commit,
+                "labels_created": labels_created,
+                "label_errors": label_errors,
+            }))
+        })
+    }
+
+    fn community_profile(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let mut repos = Self::get_str_list(&params, "repos");
+        if let Some(repo) = Self::get_str(&params, "repo") {
+            repos.push(repo.to_string());
+        }
+        let org = Self::get_str(&params, "org").map(String::from);
+        if repos.is_empty() && org.is_none() {
+            anyhow::bail!("Missing required parameter: repo, repos, or org");
+        }
+        for repo in &repos {
+            Self::parse_repo(repo)?;
+        }
+
+        let client = self.client()?;
+
+        let (mut profiles, errors) = self.runtime.block_on(async move {
+            if let Some(org) = &org {
+                let names = client.list_org_repo_names(org).await?;
+                repos.extend(names.into_iter().map(|name| format!("{}/{}", org, name)));
+            }
+            repos.sort();
+            repos.dedup();
+
+            let semaphore = Arc::new(Semaphore::new(ORG_FANOUT_CONCURRENCY));
+            let mut tasks = JoinSet::new();
+            for full_name in repos {
+                let client = client.clone();
+                let semaphore = semaphore.clone();
+                tasks.spawn(meta::scope(async move {
+                    let _permit = semaphore.acquire_owned().await;
+                    let (owner, repo) = full_name.split_once('/').unwrap_or_default();
+                    let result = client.get_community_profile(owner, repo).await;
+                    (full_name, result)
+                }));
+            }
+
+            let mut profiles: Vec<CommunityProfile> = Vec::new();
+            let mut errors = Vec::new();
+            while let Some(joined) = tasks.join_next().await {
+                let (repo, result) = joined?;
+                match result {
+                    Ok(profile) => profiles.push(profile),
+                    Err(e) => errors.push(json!({"repo": repo, "error": e.to_string()})),
+                }
+            }
+
+            anyhow::Ok((profiles, errors))
+        })?;
+
+        profiles.sort_by(|a, b| {
+            a.health_percentage
+                .cmp(&b.health_percentage)
+                .then_with(|| a.repo.cmp(&b.repo))
+        });
+
+        let mut missing_counts: BTreeMap<String, usize> = BTreeMap::new();
+        for profile in &profiles {
+            for file in &profile.missing {
+                *missing_counts.entry(file.clone()).or_default() += 1;
+            }
+        }
+        let average_health = if profiles.is_empty() {
+            None
+        } else {
+            let total: i32 = profiles.iter().map(|p| p.health_percentage).sum();
+            Some(total as f64 / profiles.len() as f64)
+        };
+        let compliant = profiles.iter().filter(|p| p.missing.is_empty()).count();
+
+        Ok(serde_json::json!({
+            "profiles": profiles,
+            "count": profiles.len(),
+            "compliant_count": compliant,
+            "average_health": average_health,
+            "missing_counts": missing_counts,
+            "errors": errors,
+        }))
+    }
+
+    fn review_metrics(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo = Self::get_str(&params, "repo").map(String::from);
+        let org = Self::get_str(&params, "org").map(String::from);
+        let days = Self::get_i32(&params, "days", REVIEW_METRICS_DAYS_DEFAULT)
+            .clamp(1, REVIEW_METRICS_DAYS_MAX);
+        if let Some(repo) = &repo {
+            Self::parse_repo(repo)?;
+        }
+        let scope = match (&repo, &org) {
+            (Some(repo), None) => repo.clone(),
+            (None, Some(org)) => org.clone(),
+            _ => anyhow::bail!("Pass exactly one of 'repo' or 'org'"),
+        };
+        let since = (chrono::Utc::now() - chrono::Duration::days(i64::from(days)))
+            .format("%Y-%m-%d")
+            .to_string();
+
+        let client = self.client()?;
+        let since_owned = since.clone();
+
+        let (samples, repo_count, errors) = self.runtime.block_on(async move {
+            let repos = match (repo, org) {
+                (Some(repo), _) => vec![repo],
+                (None, Some(org)) => client
+                    .list_org_repo_names(&org)
+                    .await?
+                    .into_iter()
+                    .map(|name| format!("{}/{}", org, name))
+                    .collect(),
+                (None, None) => Vec::new(),
+            };
+            let repo_count = repos.len();
+
+            let semaphore = Arc::new(Semaphore::new(ORG_FANOUT_CONCURRENCY));
+            let mut tasks = JoinSet::new();
+            for full_name in repos {
+                let client = client.clone();
+                let semaphore = semaphore.clone();
+                let since = since_owned.clone();
+                tasks.spawn(meta::scope(async move {
+                    let _permit = semaphore.acquire_owned().await;
+                    let (owner, repo) = full_name.split_once('/').unwrap_or_default();
+                    let result = client.pr_review_samples(owner, repo, &since).await;
+                    (full_name, result)
+                }));
+            }
+
+            let mut samples = Vec::new();
+            let mut errors = Vec::new();
+            while let Some(joined) = tasks.join_next().await {
+                let (repo, result) = joined?;
+                match result {
+                    Ok(batch) => samples.extend(batch),
+                    Err(e) => errors.push(json!({"repo": repo, "error": e.to_string()})),
+                }
+            }
+
+            anyhow::Ok((samples, repo_count, errors))
+        })?;
+
+        Ok(serde_json::json!({
+            "scope": scope,
+            "days": days,
+            "since": since,
+            "repos": repo_count,
+            "metrics": ReviewMetrics::compute(&samples),
+            "errors": errors,
+        }))
+    }
+
+    fn plan(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?;
+        let (owner, repo) = Self::parse_repo(repo_str)?;
+        let steps = plan::parse(
+            params
+                .get("steps")
+                .ok_or_else(|| anyhow::anyhow!("Missing required parameter: steps"))?,
+        )?;
+        for step in &steps {
+            if let Step::Commit { additions, .. } = step {
+                Self::parse_additions(additions)?;
+            }
+        }
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let checked = steps.clone();
+
+        // Check the branches each step needs against the repo as it is now
+        let (default_branch, problems) = self.runtime.block_on(async move {
+            let default_branch = client.get_default_branch(&owner, &repo).await?;
+            let mut problems = Vec::new();
+            let mut created: Vec<&str> = Vec::new();
+
+            for (i, step) in checked.iter().enumerate() {
+                let n = i + 1;
+                // Existing branch the step builds on, if any
+                let needs = match step {
+                    Step::CreateBranch { branch, from } => {
+                        if client.get_branch_head(&owner, &repo, branch).await.is_ok() {
+                            problems
+                                .push(format!("Step {}: branch '{}' already exists", n, branch));
+                        }
+                        Some(from.as_deref().unwrap_or(&default_branch))
+                    }
+                    Step::Commit { branch, .. } => branch.as_deref(),
+                    Step::OpenPr { head, .. } => head.as_deref(),
+                    Step::RequestReviewers { .. } => None,
+                };
+                if let Some(branch) = needs.filter(|b| !created.contains(b)) {
+                    if let Err(e) = client.get_branch_head(&owner, &repo, branch).await {
+                        problems.push(format!("Step {}: {:#}", n, e));
+                    }
+                }
+                if let Step::CreateBranch { branch, .. } = step {
+                    created.push(branch);
+                }
+            }
+            anyhow::Ok((default_branch, problems))
+        })?;
+
+        Ok(serde_json::json!({
+            "repo": repo_str,
+            "default_branch": default_branch,
+            "valid": problems.is_empty(),
+            "problems": problems,
+            "summary": steps.iter().map(Step::describe).collect::<Vec<_>>(),
+            "steps": steps,
+        }))
+    }
+
+    fn apply(&self, params: HashMap<String, Value>) -> Result<Value> {
+        let repo_str = Self::get_str(&params, "repo")
+            .ok_or_else(|| anyhow::anyhow!("Missing required parameter: repo"))?
+            .to_string();
+        let (owner, repo) = Self::parse_repo(&repo_str)?;
+        let steps = plan::parse(
+            params
+                .get("steps")
+                .ok_or_else(|| anyhow::anyhow!("Missing required parameter: steps"))?,
+        )?;
+        let files = steps
+            .iter()
+            .map(|step| match step {
+                Step::Commit { additions, .. } => Self::parse_additions(additions),
+                _ => Ok(vec![]),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let client = self.client()?;
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let events = self.events.clone();
+        let run_id = format!("apply_{}", chrono::Utc::now().timestamp_millis());
+        let total = steps.len();
+
+        let record = |n: usize, step: &Step, status: &str, detail: (&str, Value)| {
+            let mut payload = serde_json::json!({
+                "run_id": run_id,
+                "repo": repo_str,
+                "step": n,
+                "total": total,
+                "op": step.op(),
+                "description": step.describe(),
+                "status": status,
+            });
+            if !detail.1.is_null() {
+                payload[detail.0] = detail.1;
+            }
+            let mut events = events.lock().unwrap_or_else(|e| e.into_inner());
+            if let Err(e) = events.append(PLAN_STEP_EVENT, payload) {
+                tracing::warn!("Failed to record plan progress: {}", e);
+            }
+        };
+
+        let (outputs, failure) = self.runtime.block_on(async {
+            let default_branch = client.get_default_branch(&owner, &repo).await?;
+            let mut pr_number = None;
+            let mut outputs = Vec::new();
+
+            for (i, (step, files)) in steps.iter().zip(&files).enumerate() {
+                let n = i + 1;
+                record(n, step, "started", ("", Value::Null));
+
+                let result = async {
+                    let output = match step {
+                        Step::CreateBranch { branch, from } => {
+                            let from = from.as_deref().unwrap_or(&default_branch);
+                            let sha = client.get_branch_head(&owner, &repo, from).await?;
+                            client.create_branch(&owner, &repo, branch, &sha).await?;
+                            serde_json::json!({ "branch": branch, "from": from, "sha": sha })
+                        }
+                        Step::Commit {
+                            branch,
+                            message,
+                            deletions,
+                            ..
+                        } => {
+                            let branch = branch.as_deref().unwrap_or_default();
+                            let previous = client.get_branch_head(&owner, &repo, branch).await?;
+                            let (headline, body) = Self::split_message(message);
+                            let commit = client
+                                .create_commit(
+                                    &owner,
+                                    &repo,
+                                    branch,
+                                    &headline,
+                                    body.as_deref(),
+                                    files,
+                                    deletions,
+                                    Some(&previous),
+                                )
+                                .await?;
+                            serde_json::json!({ "previous_head": previous, "commit": commit })
+                        }
+                        Step::OpenPr {
+                            title,
+                            body,
+                            head,
+                            base,
+                            draft,
+                        } => {
+                            let head = head.as_deref().unwrap_or_default();
+                            let base = base.as_deref().unwrap_or(&default_branch);
+                            let (number, url) = client
+                                .create_pull_request(
+                                    &owner,
+                                    &repo,
+                                    title,
+                                    body.as_deref(),
+                                    head,
+                                    base,
+                                    *draft,
+                                )
+                                .await?;
+                            pr_number = Some(number);
+                            serde_json::json!({
+                                "number": number,
+                                "url": url,
+                                "head": head,
+                                "base": base,
+                            })
+                        }
+                        Step::RequestReviewers {
+                            number,
+                            reviewers,
+                            team_reviewers,
+                        } => {
+                            let number = number.or(pr_number).ok_or_else(|| {
+                                anyhow::anyhow!("No pull request to request reviews on")
+                            })?;
+                            client
+                                .request_reviewers(&owner, &repo, number, reviewers, team_reviewers)
+                                .await?;
+                            serde_json::json!({
+                                "number": number,
+                                "reviewers": reviewers,
+                                "team_reviewers": team_reviewers,
+                            })
+                        }
+                    };
+                    anyhow::Ok(output)
+                }
+                .await;
+
+                match result {
+                    Ok(output) => {
+                        record(n, step, "completed", ("output", output.clone()));
+                        outputs.push(output);
+                    }
+                    Err(e) => {
+                        let error = format!("{:#}", e);
+                        record(n, step, "failed", ("error", Value::from(error.as_str())));
+                        return anyhow::Ok((outputs, Some((n, error))));
+                    }
+                }
+            }
+            anyhow::Ok((outputs, None))
+        })?;
+
+        let results: Vec<Value> = steps
+            .iter()
+            .zip(&outputs)
+            .enumerate()
+            .map(|(i, (step, output))| {
+                serde_json::json!({
+                    "step": i + 1,
+                    "op": step.op(),
+                    "description": step.describe(),
+                    "output": output,
+                })
+            })
+            .collect();
+
+        let mut response = serde_json::json!({
+            "repo": repo_str,
+            "run_id": run_id,
+            "success": failure.is_none(),
+            "completed": outputs.len(),
+            "total": total,
+            "results": results,
+        });
+        if let Some((step, error)) = failure {
+            response["failed_step"] = Value::from(step);
+            response["error"] = Value::from(error);
+            response["rollback"] = serde_json::json!(plan::rollback(&repo_str, &steps, &outputs));
+        }
+        Ok(response)
+    }
+}
+
+/// Run one reminder's search and store the outcome.
+fn run_reminder(
+    client: &GitHubClient,
+    handle: &Handle,
+    store: &Mutex<ReminderStore>,
+    events: &Mutex<EventLog>,
+    id: &str,
+    query: &str,
+) {
+    let result = handle.block_on(client.search(query, REMINDER_RESULT_LIMIT));
+
+    let mut store = store.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(reminder) = store.get_mut(id) {
+        reminder.last_run = Some(chrono::Utc::now());
+        match result {
+            Ok(hits) => {
+                tracing::info!(
+                    "Reminder '{}' matched {} item(s)",
+                    reminder.name,
+                    hits.len()
+                );
+                if !hits.is_empty() {
+                    let payload = serde_json::json!({
+                        "id": reminder.id,
+                        "name": reminder.name,
+                        "count": hits.len(),
+                        "hits": hits,
+                    });
+                    let mut events = events.lock().unwrap_or_else(|e| e.into_inner());
+                    if let Err(e) = events.append(REMINDER_EVENT, payload) {
+                        tracing::warn!("Failed to record reminder event: {}", e);
+                    }
+                }
+                reminder.last_results = hits;
+                reminder.last_error = None;
+            }
+            Err(e) => {
+                tracing::warn!("Reminder '{}' failed: {}", reminder.name, e);
+                reminder.last_error = Some(e.to_string());
+            }
+        }
+    }
+    if let Err(e) = store.save() {
+        tracing::warn!("Failed to save reminders: {}", e);
+    }
+}
+
+/// Background loop that polls notifications and label changes, runs
+/// reminders when they come due, and fires triggers on new events.
+fn run_scheduler(
+    lazy_client: Arc<LazyClient>,
+    handle: Handle,
+    store: Arc<Mutex<ReminderStore>>,
+    events: Arc<Mutex<EventLog>>,
+    rules: NotificationRules,
+    poller_config: PollerConfig,
+    triggers: Vec<Trigger>,
+) {
+    let (mut poller, mut label_poller, mut trigger_runner) = {
+        let events = events.lock().unwrap_or_else(|e| e.into_inner());
+        let watched = triggers::watched_repos(&triggers);
+        (
+            NotificationPoller::new(&events, poller_config.clone()),
+            LabelPoller::new(&events, watched, poller_config),
+            TriggerRunner::new(triggers, &events),
+        )
+    };
+
+    loop {
+        let client = match lazy_client.get() {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::debug!("Scheduler idle, no GitHub client: {}", e);
+                std::thread::sleep(SCHEDULER_TICK);
+                continue;
+            }
+        };
+
+        if poller.is_due(std::time::Instant::now()) {
+            match poller.poll(&client, &handle, &rules, &events) {
+                Ok(0) => {}
+                Ok(n) => tracing::info!("Recorded {} notification event(s)", n),
+                Err(e) => tracing::warn!("Notification poll failed: {}", e),
+            }
+        }
+        if label_poller.is_due(std::time::Instant::now()) {
+            match label_poller.poll(&client, &handle, &events) {
+                Ok(0) => {}
+                Ok(n) => tracing::info!("Recorded {} label event(s)", n),
+                Err(e) => tracing::warn!("Label poll failed: {}", e),
+            }
+        }
+
+        let now = chrono::Local::now();
+        let due: Vec<(String, String)> = {
+            let store = store.lock().unwrap_or_else(|e| e.into_inner());
+            store
+                .reminders
+                .iter()
+                .filter(|r| r.is_due(&now))
+                .map(|r| {
+                    (
+                        r.id.clone(),
+                        r.search_query(now.with_timezone(&chrono::Utc)),
+                    )
+                })
+                .collect()
+        };
+
+        for (id, query) in due {
+            run_reminder(&client, &handle, &store, &events, &id, &query);
+        }
+
+        trigger_runner.run(&events);
+
+        let now = std::time::Instant::now();
+        let wait = poller.until_due(now).min(label_poller.until_due(now));
+        std::thread::sleep(wait.min(SCHEDULER_TICK));
+    }
+}
+
+impl FgpService for GitHubService {
+    fn name(&self) -> &str {
+        "github"
+    }
+
+    fn version(&self) -> &str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    fn dispatch(&self, method: &str, mut params: HashMap<String, Value>) -> Result<Value> {
+        let include_meta = params
+            .remove("include_meta")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let schema_version = compat::negotiate(params.remove("schema_version").as_ref())?;
+
+        if !include_meta {
+            let value = self.dispatch_cached(method, params)?;
+            return Ok(compat::render(method, value, schema_version));
+        }
+
+        meta::begin();
+        let result = self.dispatch_cached(method, params);
+        let call_meta = meta::finish();
+
+        let mut value = compat::render(method, result?, schema_version);
+        if let (Some(obj), Some(call_meta)) = (value.as_object_mut(), call_meta) {
+            let mut meta_block = serde_json::json!(call_meta);
+            meta_block["schema_version"] = Value::from(schema_version);
+            obj.insert("_meta".into(), meta_block);
+        }
+        Ok(value)
+    }
+
+    fn method_list(&self) -> Vec<MethodInfo> {
+        vec![
+            // github.user - Get current authenticated user
+            MethodInfo::new("github.user", "Get current authenticated user info")
+                .schema(Self::with_common_params(SchemaBuilder::object().build()))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("login", SchemaBuilder::string().description("GitHub username"))
+                        .property("name", SchemaBuilder::string().description("Display name"))
+                        .property("email", SchemaBuilder::string().format("email"))
+                        .property("avatar_url", SchemaBuilder::string().format("uri"))
+                        .build(),
+                )
+                .example("Get current user", json!({})),
+
+            // github.repos - List repositories
+            MethodInfo::new("github.repos", "List your repositories")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Maximum number of repos to return"),
+                        )
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "repos",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property("name", SchemaBuilder::string())
+                                        .property("full_name", SchemaBuilder::string())
+                                        .property("url", SchemaBuilder::string().format("uri"))
+                                        .property("description", SchemaBuilder::string())
+                                        .property("stargazers_count", SchemaBuilder::integer()),
+                                )
+                                .description("List of repositories"),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List top 5 repos", json!({"limit": 5})),
+
+            // github.issues - List issues
+            MethodInfo::new("github.issues", "List issues for a repository")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["open", "closed", "all"])
+                                .default_value(json!("open"))
+                                .description("Issue state filter"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Maximum issues to return"),
+                        )
+                        .property(
+                            "include_body",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include each issue's body"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property(
+                            "issues",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("body", SchemaBuilder::string().description("Only with include_body"))
+                                    .property("state", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string().format("date-time"))
+                                    .property("url", SchemaBuilder::string().format("uri"))
+                                    .property("is_pinned", SchemaBuilder::boolean()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "List open issues",
+                    json!({"repo": "fast-gateway-protocol/daemon"}),
+                )
+                .example(
+                    "List closed issues",
+                    json!({"repo": "fast-gateway-protocol/daemon", "state": "closed", "limit": 5}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.prs - List pull requests
+            MethodInfo::new("github.prs", "List pull requests for a repository")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .enum_values(&["open", "closed", "all"])
+                                .default_value(json!("open"))
+                                .description("PR state filter"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(10))
+                                .description("Maximum PRs to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property(
+                            "prs",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("number", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("head_ref", SchemaBuilder::string())
+                                    .property("base_ref", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "List open PRs",
+                    json!({"repo": "fast-gateway-protocol/daemon"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.pr - Get PR details
+            MethodInfo::new("github.pr", "Get pull request details with reviews and status checks")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .description("Pull request number"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("number", SchemaBuilder::integer())
+                        .property("title", SchemaBuilder::string())
+                        .property("body", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property("mergeable", SchemaBuilder::boolean())
+                        .property("locked", SchemaBuilder::boolean())
+                        .property("lock_reason", SchemaBuilder::string())
+                        .property("resolved_threads", SchemaBuilder::integer())
+                        .property("unresolved_threads", SchemaBuilder::integer())
+                        .property("head_ref", SchemaBuilder::string())
+                        .property("base_ref", SchemaBuilder::string())
+                        .property(
+                            "reviews",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("author", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property(
+                            "status_checks",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("context", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string()),
+                            ),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Get PR #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.notifications - Get notifications
+            MethodInfo::new("github.notifications", "Get unread GitHub notifications")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "group",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Group notifications by routing bucket"),
+                        )
+                        .property(
+                            "include_ignored",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include notifications routed to the ignore bucket"),
+                        )
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "notifications",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("reason", SchemaBuilder::string())
+                                    .property("unread", SchemaBuilder::boolean())
+                                    .property("subject_title", SchemaBuilder::string())
+                                    .property("subject_type", SchemaBuilder::string())
+                                    .property("repo_full_name", SchemaBuilder::string())
+                                    .property("bucket", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property(
+                            "groups",
+                            SchemaBuilder::object()
+                                .description("Notifications keyed by bucket (when group=true)"),
+                        )
+                        .property("unread_count", SchemaBuilder::integer())
+                        .property("ignored_count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Get notifications", json!({}))
+                .example("Triage by bucket", json!({"group": true})),
+
+            // github.create_issue - Create issue
+            MethodInfo::new("github.create_issue", "Create a new issue in a repository")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "title",
+                            SchemaBuilder::string()
+                                .min_length(1)
+                                .max_length(256)
+                                .description("Issue title"),
+                        )
+                        .property(
+                            "body",
+                            SchemaBuilder::string()
+                                .description("Issue body (Markdown supported)"),
+                        )
+                        .required(&["repo", "title"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("created", SchemaBuilder::boolean())
+                        .property(
+                            "issue",
+                            SchemaBuilder::object()
+                                .property("number", SchemaBuilder::integer())
+                                .property("title", SchemaBuilder::string())
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Create a bug report",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "title": "Bug: Socket timeout after 30s",
+                        "body": "## Description\nThe socket times out unexpectedly..."
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.merge_queue - View merge queue
+            MethodInfo::new("github.merge_queue", "View a branch's merge queue")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "branch",
+                            SchemaBuilder::string()
+                                .description("Target branch (default: repository default branch)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(50))
+                                .description("Maximum entries to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "enabled",
+                            SchemaBuilder::boolean()
+                                .description("Whether a merge queue exists for the branch"),
+                        )
+                        .property("branch", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string().format("uri"))
+                        .property("merge_method", SchemaBuilder::string())
+                        .property(
+                            "entries",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("position", SchemaBuilder::integer())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("pr_number", SchemaBuilder::integer())
+                                    .property("pr_title", SchemaBuilder::string())
+                                    .property("enqueued_at", SchemaBuilder::string().format("date-time"))
+                                    .property(
+                                        "estimated_time_to_merge",
+                                        SchemaBuilder::integer()
+                                            .description("Estimated seconds until merge"),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "View main's merge queue",
+                    json!({"repo": "fast-gateway-protocol/daemon"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.enqueue_pr - Add PR to merge queue
+            MethodInfo::new("github.enqueue_pr", "Add a pull request to the merge queue")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .description("Pull request number"),
+                        )
+                        .property(
+                            "jump",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Add to the front of the queue"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("enqueued", SchemaBuilder::boolean())
+                        .property(
+                            "entry",
+                            SchemaBuilder::object()
+                                .property("position", SchemaBuilder::integer())
+                                .property("state", SchemaBuilder::string())
+                                .property("estimated_time_to_merge", SchemaBuilder::integer()),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Enqueue PR #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.dequeue_pr - Remove PR from merge queue
+            MethodInfo::new("github.dequeue_pr", "Remove a pull request from the merge queue")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .description("Pull request number"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("dequeued", SchemaBuilder::boolean())
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Dequeue PR #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.download_archive - Download repository archive
+            MethodInfo::new("github.download_archive", "Download a repository tarball or zipball at a ref")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "ref",
+                            SchemaBuilder::string()
+                                .description("Branch, tag, or commit SHA (default: default branch)"),
+                        )
+                        .property(
+                            "format",
+                            SchemaBuilder::string()
+                                .enum_values(&["tarball", "zipball"])
+                                .default_value(json!("tarball"))
+                                .description("Archive format"),
+                        )
+                        .property(
+                            "dest_path",
+                            SchemaBuilder::string()
+                                .description("Absolute file path to write the archive to (~ expanded)"),
+                        )
+                        .required(&["repo", "dest_path"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("ref", SchemaBuilder::string())
+                        .property("format", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
+                        .property("size", SchemaBuilder::integer().description("Bytes written"))
+                        .build(),
+                )
+                .example(
+                    "Download v1.0.0 tarball",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "ref": "v1.0.0",
+                        "dest_path": "~/snapshots/daemon-v1.0.0.tar.gz"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.codespaces - List codespaces
+            MethodInfo::new("github.codespaces", "List your codespaces with state and machine type")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "state",
+                            SchemaBuilder::string()
+                                .description("Only return codespaces in this state (e.g. Available, Shutdown)"),
+                        )
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "codespaces",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("display_name", SchemaBuilder::string())
+                                    .property("state", SchemaBuilder::string())
+                                    .property("repo_full_name", SchemaBuilder::string())
+                                    .property("branch", SchemaBuilder::string())
+                                    .property("machine_type", SchemaBuilder::string())
+                                    .property("web_url", SchemaBuilder::string().format("uri"))
+                                    .property("last_used_at", SchemaBuilder::string().format("date-time")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List all codespaces", json!({}))
+                .example("List running codespaces", json!({"state": "Available"}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.start_codespace - Start a codespace
+            MethodInfo::new("github.start_codespace", "Start a codespace")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "name",
+                            SchemaBuilder::string().description("Codespace name (from github.codespaces)"),
+                        )
+                        .required(&["name"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("started", SchemaBuilder::boolean())
+                        .property(
+                            "codespace",
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("state", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example("Start a codespace", json!({"name": "octocat-literate-space-parakeet-7gx2"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.stop_codespace - Stop a codespace
+            MethodInfo::new("github.stop_codespace", "Stop a running codespace")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "name",
+                            SchemaBuilder::string().description("Codespace name (from github.codespaces)"),
+                        )
+                        .required(&["name"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("stopped", SchemaBuilder::boolean())
+                        .property(
+                            "codespace",
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("state", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example("Stop a codespace", json!({"name": "octocat-literate-space-parakeet-7gx2"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.ssh_keys - List SSH keys
+            MethodInfo::new("github.ssh_keys", "List your SSH keys with fingerprints")
+                .schema(Self::with_common_params(SchemaBuilder::object().build()))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "keys",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("title", SchemaBuilder::string())
+                                    .property("fingerprint", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string().format("date-time")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List SSH keys", json!({}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.add_ssh_key - Add SSH key
+            MethodInfo::new("github.add_ssh_key", "Add an SSH public key to your account")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "title",
+                            SchemaBuilder::string().min_length(1).description("Descriptive name for the key"),
+                        )
+                        .property(
+                            "key",
+                            SchemaBuilder::string().description("Public key line (e.g. contents of id_ed25519.pub)"),
+                        )
+                        .required(&["title", "key"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("added", SchemaBuilder::boolean())
+                        .property(
+                            "key",
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("fingerprint", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Register a build machine key",
+                    json!({"title": "build-01", "key": "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAA... ci@build-01"}),
+                )
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.remove_ssh_key - Remove SSH key
+            MethodInfo::new("github.remove_ssh_key", "Remove one of your SSH keys")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "id",
+                            SchemaBuilder::integer().description("Key ID (from github.ssh_keys)"),
+                        )
+                        .required(&["id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("removed", SchemaBuilder::boolean())
+                        .property("id", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Remove key 12345", json!({"id": 12345}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.gpg_keys - List GPG keys
+            MethodInfo::new("github.gpg_keys", "List your GPG keys")
+                .schema(Self::with_common_params(SchemaBuilder::object().build()))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "keys",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("key_id", SchemaBuilder::string())
+                                    .property("emails", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("created_at", SchemaBuilder::string().format("date-time"))
+                                    .property("expires_at", SchemaBuilder::string().format("date-time")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List GPG keys", json!({}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.add_gpg_key - Add GPG key
+            MethodInfo::new("github.add_gpg_key", "Add a GPG public key to your account")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "armored_public_key",
+                            SchemaBuilder::string().description("ASCII-armored public key block"),
+                        )
+                        .property("name", SchemaBuilder::string().description("Descriptive name for the key"))
+                        .required(&["armored_public_key"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("added", SchemaBuilder::boolean())
+                        .property(
+                            "key",
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("key_id", SchemaBuilder::string()),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Add a signing key",
+                    json!({"name": "release-signing", "armored_public_key": "-----BEGIN PGP PUBLIC KEY BLOCK-----\n..."}),
+                )
+                .errors(&["UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.remove_gpg_key - Remove GPG key
+            MethodInfo::new("github.remove_gpg_key", "Remove one of your GPG keys")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "id",
+                            SchemaBuilder::integer().description("Key ID (from github.gpg_keys)"),
+                        )
+                        .required(&["id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("removed", SchemaBuilder::boolean())
+                        .property("id", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Remove key 12345", json!({"id": 12345}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.secrets - List Actions secrets
+            MethodInfo::new("github.secrets", "List Actions secret names for a repo, org, or environment (never values)")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format (or use org)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (or use repo)"))
+                        .property(
+                            "environment",
+                            SchemaBuilder::string().description("Deployment environment name (requires repo)"),
+                        )
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "secrets",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("created_at", SchemaBuilder::string().format("date-time"))
+                                    .property("updated_at", SchemaBuilder::string().format("date-time"))
+                                    .property("visibility", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List repo secrets", json!({"repo": "fast-gateway-protocol/daemon"}))
+                .example(
+                    "List environment secrets",
+                    json!({"repo": "fast-gateway-protocol/daemon", "environment": "production"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.set_secret - Create or update an Actions secret
+            MethodInfo::new("github.set_secret", "Create or update an Actions secret (encrypted client-side)")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format (or use org)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (or use repo)"))
+                        .property(
+                            "environment",
+                            SchemaBuilder::string().description("Deployment environment name (requires repo)"),
+                        )
+                        .property("name", SchemaBuilder::string().description("Secret name"))
+                        .property("value", SchemaBuilder::string().description("Plaintext secret value"))
+                        .property(
+                            "visibility",
+                            SchemaBuilder::string()
+                                .enum_values(&["all", "private", "selected"])
+                                .description("Org only: which repos can access it (default: unchanged, or private when new)"),
+                        )
+                        .property(
+                            "selected_repository_ids",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::integer())
+                                .description("Org only: repository IDs when visibility is 'selected'"),
+                        )
+                        .required(&["name", "value"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("set", SchemaBuilder::boolean())
+                        .property("name", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Rotate a deploy token",
+                    json!({"repo": "fast-gateway-protocol/daemon", "name": "DEPLOY_TOKEN", "value": "..."}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.delete_secret - Delete an Actions secret
+            MethodInfo::new("github.delete_secret", "Delete an Actions secret")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format (or use org)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (or use repo)"))
+                        .property(
+                            "environment",
+                            SchemaBuilder::string().description("Deployment environment name (requires repo)"),
+                        )
+                        .property("name", SchemaBuilder::string().description("Secret name"))
+                        .required(&["name"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("deleted", SchemaBuilder::boolean())
+                        .property("name", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Delete an org secret",
+                    json!({"org": "fast-gateway-protocol", "name": "OLD_TOKEN"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.variables - List Actions variables
+            MethodInfo::new("github.variables", "List Actions variables for a repo, org, or environment")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format (or use org)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (or use repo)"))
+                        .property(
+                            "environment",
+                            SchemaBuilder::string().description("Deployment environment name (requires repo)"),
+                        )
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "variables",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("value", SchemaBuilder::string())
+                                    .property("updated_at", SchemaBuilder::string().format("date-time")),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List org variables", json!({"org": "fast-gateway-protocol"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.set_variable - Create or update an Actions variable
+            MethodInfo::new("github.set_variable", "Create or update an Actions variable")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format (or use org)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (or use repo)"))
+                        .property(
+                            "environment",
+                            SchemaBuilder::string().description("Deployment environment name (requires repo)"),
+                        )
+                        .property("name", SchemaBuilder::string().description("Variable name"))
+                        .property("value", SchemaBuilder::string().description("Variable value"))
+                        .property(
+                            "visibility",
+                            SchemaBuilder::string()
+                                .enum_values(&["all", "private", "selected"])
+                                .description("Org only: which repos can access it (default: unchanged, or private when new)"),
+                        )
+                        .property(
+                            "selected_repository_ids",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::integer())
+                                .description("Org only: repository IDs when visibility is 'selected'"),
+                        )
+                        .required(&["name", "value"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("set", SchemaBuilder::boolean())
+                        .property("created", SchemaBuilder::boolean().description("False if an existing variable was updated"))
+                        .property("name", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Set a region variable",
+                    json!({"repo": "fast-gateway-protocol/daemon", "name": "AWS_REGION", "value": "us-east-1"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.delete_variable - Delete an Actions variable
+            MethodInfo::new("github.delete_variable", "Delete an Actions variable")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format (or use org)"),
+                        )
+                        .property("org", SchemaBuilder::string().description("Organization login (or use repo)"))
+                        .property(
+                            "environment",
+                            SchemaBuilder::string().description("Deployment environment name (requires repo)"),
+                        )
+                        .property("name", SchemaBuilder::string().description("Variable name"))
+                        .required(&["name"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("deleted", SchemaBuilder::boolean())
+                        .property("name", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Delete a variable",
+                    json!({"repo": "fast-gateway-protocol/daemon", "name": "AWS_REGION"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.run_jobs - Workflow run jobs with step timings
+            MethodInfo::new("github.run_jobs", "List a workflow run's jobs with runner and per-step timings")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("run_id", SchemaBuilder::integer().description("Workflow run ID"))
+                        .property(
+                            "filter",
+                            SchemaBuilder::string()
+                                .enum_values(&["latest", "all"])
+                                .default_value(json!("latest"))
+                                .description("Jobs from the latest attempt only, or all attempts"),
+                        )
+                        .required(&["repo", "run_id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("run_id", SchemaBuilder::integer())
+                        .property(
+                            "jobs",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("status", SchemaBuilder::string())
+                                    .property("conclusion", SchemaBuilder::string())
+                                    .property("runner_name", SchemaBuilder::string())
+                                    .property("duration_seconds", SchemaBuilder::integer())
+                                    .property(
+                                        "steps",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("number", SchemaBuilder::integer())
+                                                .property("name", SchemaBuilder::string())
+                                                .property("conclusion", SchemaBuilder::string())
+                                                .property("duration_seconds", SchemaBuilder::integer()),
+                                        ),
+                                    ),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property("failed_count", SchemaBuilder::integer())
+                        .property(
+                            "slowest_steps",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property("job", SchemaBuilder::string())
+                                        .property("step", SchemaBuilder::string())
+                                        .property("duration_seconds", SchemaBuilder::integer()),
+                                )
+                                .description("Five slowest steps across all jobs"),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Inspect a run's jobs",
+                    json!({"repo": "fast-gateway-protocol/daemon", "run_id": 7654321}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.service_status - GitHub platform status
+            MethodInfo::new("github.service_status", "Get GitHub platform status from githubstatus.com")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "components",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Only include components whose name starts with one of these"),
+                        )
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "indicator",
+                            SchemaBuilder::string().enum_values(&["none", "minor", "major", "critical"]),
+                        )
+                        .property("description", SchemaBuilder::string())
+                        .property("all_operational", SchemaBuilder::boolean())
+                        .property("degraded", SchemaBuilder::array().items(SchemaBuilder::string()))
+                        .property(
+                            "components",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("status", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property(
+                            "incidents",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("impact", SchemaBuilder::string())
+                                    .property("url", SchemaBuilder::string().format("uri")),
+                            ),
+                        )
+                        .property("updated_at", SchemaBuilder::string().format("date-time"))
+                        .build(),
+                )
+                .example("Full status", json!({}))
+                .example(
+                    "Core components only",
+                    json!({"components": ["Git Operations", "API Requests", "Actions", "Pages"]}),
+                ),
+
+            // github.transfer_issue - Move an issue to another repository
+            MethodInfo::new("github.transfer_issue", "Transfer an issue to another repository")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Source repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Issue number"),
+                        )
+                        .property(
+                            "target_repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Destination repository (must share the same owner)"),
+                        )
+                        .property(
+                            "create_labels",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Create missing labels in the target repository"),
+                        )
+                        .required(&["repo", "number", "target_repo"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("transferred", SchemaBuilder::boolean())
+                        .property(
+                            "from",
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("number", SchemaBuilder::integer()),
+                        )
+                        .property(
+                            "to",
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("number", SchemaBuilder::integer())
+                                .property("url", SchemaBuilder::string().format("uri")),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Move a misfiled issue",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "number": 17,
+                        "target_repo": "fast-gateway-protocol/github"
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.pin_issue - Pin an issue
+            MethodInfo::new("github.pin_issue", "Pin an issue to the top of the repository's issue list (max 3)")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Issue number"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("pinned", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example(
+                    "Pin issue #100",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 100}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.unpin_issue - Unpin an issue
+            MethodInfo::new("github.unpin_issue", "Unpin an issue")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Issue number"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("pinned", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example(
+                    "Unpin issue #100",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 100}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.lock - Lock an issue/PR conversation
+            MethodInfo::new("github.lock", "Lock the conversation on an issue or pull request")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Issue or pull request number"),
+                        )
+                        .property(
+                            "reason",
+                            SchemaBuilder::string()
+                                .enum_values(&["off_topic", "too_heated", "resolved", "spam"])
+                                .description("Lock reason shown on the timeline"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("locked", SchemaBuilder::boolean())
+                        .property("reason", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Lock a heated thread",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42, "reason": "too_heated"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.unlock - Unlock an issue/PR conversation
+            MethodInfo::new("github.unlock", "Unlock the conversation on an issue or pull request")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Issue or pull request number"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("locked", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example(
+                    "Unlock issue #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.invitations - List pending repository invitations
+            MethodInfo::new("github.invitations", "List your pending repository invitations")
+                .schema(Self::with_common_params(SchemaBuilder::object().build()))
+                .returns(
+                    SchemaBuilder::object()
+                        .property(
+                            "invitations",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::integer())
+                                    .property("repo_full_name", SchemaBuilder::string())
+                                    .property("inviter", SchemaBuilder::string())
+                                    .property("permissions", SchemaBuilder::string())
+                                    .property("expired", SchemaBuilder::boolean()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("List invitations", json!({}))
+                .errors(&["UNAUTHORIZED"]),
+
+            // github.accept_invitation - Accept a repository invitation
+            MethodInfo::new("github.accept_invitation", "Accept a pending repository invitation")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "id",
+                            SchemaBuilder::integer().description("Invitation ID (from github.invitations)"),
+                        )
+                        .required(&["id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::integer())
+                        .property("accepted", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Accept invitation", json!({"id": 1296269}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.decline_invitation - Decline a repository invitation
+            MethodInfo::new("github.decline_invitation", "Decline a pending repository invitation")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "id",
+                            SchemaBuilder::integer().description("Invitation ID (from github.invitations)"),
+                        )
+                        .required(&["id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::integer())
+                        .property("accepted", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Decline invitation", json!({"id": 1296269}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.create_commit - Multi-file commit via createCommitOnBranch
+            MethodInfo::new("github.create_commit", "Create a GitHub-signed commit with multiple file changes on a branch")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("branch", SchemaBuilder::string().description("Existing branch to commit to"))
+                        .property(
+                            "message",
+                            SchemaBuilder::string()
+                                .min_length(1)
+                                .description("Commit message; text after the first blank line becomes the body"),
+                        )
+                        .property(
+                            "additions",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property("path", SchemaBuilder::string())
+                                        .property("content", SchemaBuilder::string().description("UTF-8 file contents"))
+                                        .property(
+                                            "content_base64",
+                                            SchemaBuilder::string().description("Base64 contents for binary files"),
+                                        ),
+                                )
+                                .description("Files to add or replace"),
+                        )
+                        .property(
+                            "deletions",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Paths to delete"),
+                        )
+                        .property(
+                            "expected_head_oid",
+                            SchemaBuilder::string()
+                                .description("Fail unless the branch tip is this SHA (default: current tip)"),
+                        )
+                        .required(&["repo", "branch", "message"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("created", SchemaBuilder::boolean())
+                        .property("repo", SchemaBuilder::string())
+                        .property(
+                            "commit",
+                            SchemaBuilder::object()
+                                .property("oid", SchemaBuilder::string())
+                                .property("url", SchemaBuilder::string().format("uri"))
+                                .property("branch", SchemaBuilder::string())
+                                .property("additions", SchemaBuilder::integer())
+                                .property("deletions", SchemaBuilder::integer()),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Update two files and remove one",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "branch": "bot/update-config",
+                        "message": "Update CI config\n\nBumps toolchain and drops the legacy workflow.",
+                        "additions": [
+                            {"path": "rust-toolchain.toml", "content": "[toolchain]\nchannel = \"1.80\"\n"},
+                            {"path": ".github/workflows/ci.yml", "content": "name: CI\n..."}
+                        ],
+                        "deletions": [".github/workflows/legacy.yml"]
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.review_threads - List PR review threads
+            MethodInfo::new("github.review_threads", "List review threads on a pull request with resolution state")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Pull request number"),
+                        )
+                        .property(
+                            "unresolved_only",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Only return threads that still need resolving"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property(
+                            "threads",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("id", SchemaBuilder::string())
+                                    .property("is_resolved", SchemaBuilder::boolean())
+                                    .property("is_outdated", SchemaBuilder::boolean())
+                                    .property("path", SchemaBuilder::string())
+                                    .property("line", SchemaBuilder::integer())
+                                    .property("author", SchemaBuilder::string())
+                                    .property("first_comment", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("resolved_count", SchemaBuilder::integer())
+                        .property("unresolved_count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Open threads on PR #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42, "unresolved_only": true}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.resolve_thread - Resolve a review thread
+            MethodInfo::new("github.resolve_thread", "Resolve a pull request review thread")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "thread_id",
+                            SchemaBuilder::string().description("Thread node ID (from github.review_threads)"),
+                        )
+                        .required(&["thread_id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("thread_id", SchemaBuilder::string())
+                        .property("is_resolved", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Resolve a thread", json!({"thread_id": "PRRT_kwDOABCD1234"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.unresolve_thread - Unresolve a review thread
+            MethodInfo::new("github.unresolve_thread", "Unresolve a pull request review thread")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "thread_id",
+                            SchemaBuilder::string().description("Thread node ID (from github.review_threads)"),
+                        )
+                        .required(&["thread_id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("thread_id", SchemaBuilder::string())
+                        .property("is_resolved", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Unresolve a thread", json!({"thread_id": "PRRT_kwDOABCD1234"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.list_forks - List forks of a repository
+            MethodInfo::new("github.list_forks", "List forks of a repository")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
                                 .description("Repository in 'owner/repo' format"),
                         )
                         .property(
-                            "state",
+                            "sort",
                             SchemaBuilder::string()
-                                .enum_values(&["open", "closed", "all"])
-                                .default_value(json!("open"))
-                                .description("PR state filter"),
+                                .enum_values(&["updated", "pushed", "stars", "created"])
+                                .default_value(json!("updated"))
+                                .description("Sort order (descending)"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(30))
+                                .description("Maximum forks to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("sort", SchemaBuilder::string())
+                        .property(
+                            "forks",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("full_name", SchemaBuilder::string())
+                                    .property("stars", SchemaBuilder::integer())
+                                    .property("updated_at", SchemaBuilder::string())
+                                    .property("pushed_at", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Most starred forks",
+                    json!({"repo": "fast-gateway-protocol/daemon", "sort": "stars"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.sync_fork - Sync a fork with upstream
+            MethodInfo::new("github.sync_fork", "Sync a fork branch with its upstream repository")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Fork in 'owner/repo' format"),
+                        )
+                        .property(
+                            "branch",
+                            SchemaBuilder::string().description("Branch to bring up to date"),
+                        )
+                        .required(&["repo", "branch"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("branch", SchemaBuilder::string())
+                        .property("synced", SchemaBuilder::boolean())
+                        .property("message", SchemaBuilder::string())
+                        .property("merge_type", SchemaBuilder::string())
+                        .property("base_branch", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Sync main",
+                    json!({"repo": "octocat/daemon", "branch": "main"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "CONFLICT"]),
+
+            // github.pr_score - PR size and risk summary
+            MethodInfo::new("github.pr_score", "Compute a size/risk summary for a pull request")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Pull request number"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("lines_changed", SchemaBuilder::integer())
+                        .property("files_changed", SchemaBuilder::integer())
+                        .property("test_files", SchemaBuilder::integer())
+                        .property("test_ratio", SchemaBuilder::number())
+                        .property(
+                            "size",
+                            SchemaBuilder::string().enum_values(&["XS", "S", "M", "L", "XL"]),
+                        )
+                        .property(
+                            "risk",
+                            SchemaBuilder::string().enum_values(&["low", "medium", "high"]),
+                        )
+                        .property("risk_factors", SchemaBuilder::array().items(SchemaBuilder::string()))
+                        .property(
+                            "codeowner_areas",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("pattern", SchemaBuilder::string())
+                                    .property("owners", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                    .property("files", SchemaBuilder::integer()),
+                            ),
+                        )
+                        .property("has_codeowners", SchemaBuilder::boolean())
+                        .property("files_truncated", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example(
+                    "Score PR #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.pr_links - Closing links between PRs and issues
+            MethodInfo::new(
+                "github.pr_links",
+                "Get issues a PR closes, or PRs that close an issue",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                            .description("Repository in 'owner/repo' format"),
+                    )
+                    .property(
+                        "number",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .description("Pull request or issue number"),
+                    )
+                    .required(&["repo", "number"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("number", SchemaBuilder::integer())
+                    .property(
+                        "kind",
+                        SchemaBuilder::string().enum_values(&["pull_request", "issue"]),
+                    )
+                    .property(
+                        "closes",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("number", SchemaBuilder::integer())
+                                .property("title", SchemaBuilder::string())
+                                .property("state", SchemaBuilder::string())
+                                .property("url", SchemaBuilder::string()),
+                        ),
+                    )
+                    .property(
+                        "closed_by",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("number", SchemaBuilder::integer())
+                                .property("title", SchemaBuilder::string())
+                                .property("state", SchemaBuilder::string())
+                                .property("url", SchemaBuilder::string()),
+                        ),
+                    )
+                    .build(),
+            )
+            .example(
+                "Issues closed by PR #42",
+                json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+            )
+            .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.release_notes - Draft categorized release notes
+            MethodInfo::new(
+                "github.release_notes",
+                "Draft categorized release notes from the merged PRs between two tags",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                            .description("Repository in 'owner/repo' format"),
+                    )
+                    .property(
+                        "from_tag",
+                        SchemaBuilder::string().description("Previous release tag (exclusive)"),
+                    )
+                    .property(
+                        "to_tag",
+                        SchemaBuilder::string().description("New release tag or ref (inclusive)"),
+                    )
+                    .property(
+                        "use_github_notes",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(false))
+                            .description("Also return GitHub's generate-notes body as github_notes"),
+                    )
+                    .required(&["repo", "from_tag", "to_tag"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("from_tag", SchemaBuilder::string())
+                    .property("to_tag", SchemaBuilder::string())
+                    .property("commit_count", SchemaBuilder::integer())
+                    .property("unlinked_commits", SchemaBuilder::integer())
+                    .property("pr_count", SchemaBuilder::integer())
+                    .property(
+                        "sections",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("title", SchemaBuilder::string())
+                                .property(
+                                    "prs",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("number", SchemaBuilder::integer())
+                                            .property("title", SchemaBuilder::string())
+                                            .property("author", SchemaBuilder::string())
+                                            .property(
+                                                "labels",
+                                                SchemaBuilder::array().items(SchemaBuilder::string()),
+                                            ),
+                                    ),
+                                ),
+                        ),
+                    )
+                    .property("contributors", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property("markdown", SchemaBuilder::string())
+                    .property("github_notes", SchemaBuilder::string())
+                    .build(),
+            )
+            .example(
+                "Notes for v1.1.0",
+                json!({"repo": "fast-gateway-protocol/daemon", "from_tag": "v1.0.0", "to_tag": "v1.1.0"}),
+            )
+            .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.ref_gate - Combined checks/statuses gate for a ref
+            MethodInfo::new(
+                "github.ref_gate",
+                "Check whether required check runs and statuses are green on a ref",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                            .description("Repository in 'owner/repo' format"),
+                    )
+                    .property(
+                        "ref",
+                        SchemaBuilder::string().description("Branch, tag, or commit SHA"),
+                    )
+                    .property(
+                        "required_contexts",
+                        SchemaBuilder::array()
+                            .items(SchemaBuilder::string())
+                            .description("Check run names / status contexts that must pass (default: all reported)"),
+                    )
+                    .required(&["repo", "ref"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("ref", SchemaBuilder::string())
+                    .property("sha", SchemaBuilder::string())
+                    .property("passing", SchemaBuilder::boolean())
+                    .property(
+                        "contexts",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("state", SchemaBuilder::string())
+                                .property("passing", SchemaBuilder::boolean())
+                                .property("url", SchemaBuilder::string()),
+                        ),
+                    )
+                    .property("failing", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property("pending", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property("missing", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .build(),
+            )
+            .example(
+                "Deploy gate on main",
+                json!({"repo": "fast-gateway-protocol/daemon", "ref": "main", "required_contexts": ["build", "test"]}),
+            )
+            .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.start_review - Start a pending review
+            MethodInfo::new("github.start_review", "Start a pending (draft) review on a pull request")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "number",
+                            SchemaBuilder::integer().minimum(1).description("Pull request number"),
+                        )
+                        .property(
+                            "body",
+                            SchemaBuilder::string().description("Review summary (can also be set on submit)"),
+                        )
+                        .required(&["repo", "number"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("review_id", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .build(),
+                )
+                .example(
+                    "Start a review on PR #42",
+                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.add_review_comment - Add an inline comment to a pending review
+            MethodInfo::new("github.add_review_comment", "Add an inline comment to a pending review")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "review_id",
+                            SchemaBuilder::string().description("Pending review ID (from github.start_review)"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the diff"))
+                        .property(
+                            "line",
+                            SchemaBuilder::integer().minimum(1).description("Line to comment on (last line for ranges)"),
+                        )
+                        .property(
+                            "start_line",
+                            SchemaBuilder::integer().minimum(1).description("First line of a multi-line comment"),
+                        )
+                        .property(
+                            "side",
+                            SchemaBuilder::string()
+                                .enum_values(&["RIGHT", "LEFT"])
+                                .default_value(json!("RIGHT"))
+                                .description("RIGHT for new code, LEFT for removed code"),
+                        )
+                        .property("body", SchemaBuilder::string().description("Comment text (Markdown)"))
+                        .required(&["review_id", "path", "line", "body"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("review_id", SchemaBuilder::string())
+                        .property("thread_id", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
+                        .property("line", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Comment on a line",
+                    json!({"review_id": "PRR_kwDOABCD1234", "path": "src/main.rs", "line": 12, "body": "Handle the error here?"}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.submit_review - Submit a pending review
+            MethodInfo::new("github.submit_review", "Submit a pending review with all its comments")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "review_id",
+                            SchemaBuilder::string().description("Pending review ID (from github.start_review)"),
+                        )
+                        .property(
+                            "event",
+                            SchemaBuilder::string()
+                                .enum_values(&["APPROVE", "REQUEST_CHANGES", "COMMENT"])
+                                .default_value(json!("COMMENT"))
+                                .description("Review verdict"),
+                        )
+                        .property("body", SchemaBuilder::string().description("Review summary"))
+                        .required(&["review_id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string())
+                        .property("url", SchemaBuilder::string())
+                        .property("comment_count", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example(
+                    "Request changes",
+                    json!({"review_id": "PRR_kwDOABCD1234", "event": "REQUEST_CHANGES", "body": "A few issues inline."}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.discard_review - Discard a pending review
+            MethodInfo::new("github.discard_review", "Discard a pending review and its comments")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "review_id",
+                            SchemaBuilder::string().description("Pending review ID (from github.start_review)"),
+                        )
+                        .required(&["review_id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("review_id", SchemaBuilder::string())
+                        .property("discarded", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("Discard a draft", json!({"review_id": "PRR_kwDOABCD1234"}))
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.sso_status - SAML SSO authorization per org
+            MethodInfo::new(
+                "github.sso_status",
+                "Check which organizations the token is SAML SSO-authorized for",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "orgs",
+                        SchemaBuilder::array()
+                            .items(SchemaBuilder::string())
+                            .description("Organizations to check (default: all your organizations)"),
+                    )
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property(
+                        "orgs",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("org", SchemaBuilder::string())
+                                .property("authorized", SchemaBuilder::boolean())
+                                .property("authorization_url", SchemaBuilder::string())
+                                .property("error", SchemaBuilder::string().description("Why the org couldn't be checked")),
+                        ),
+                    )
+                    .property("all_authorized", SchemaBuilder::boolean())
+                    .property("unauthorized", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property(
+                        "failed",
+                        SchemaBuilder::array()
+                            .items(SchemaBuilder::string())
+                            .description("Orgs whose check failed for a reason other than SSO"),
+                    )
+                    .build(),
+            )
+            .example("Check all orgs", json!({}))
+            .example("Check one org", json!({"orgs": ["fast-gateway-protocol"]}))
+            .errors(&["UNAUTHORIZED"]),
+
+            // github.org_access_audit - Org access review report
+            MethodInfo::new(
+                "github.org_access_audit",
+                "Report org members with roles, outside collaborators per repo, and pending invitations",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property("org", SchemaBuilder::string().description("Organization login"))
+                    .required(&["org"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("org", SchemaBuilder::string())
+                    .property(
+                        "members",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("login", SchemaBuilder::string())
+                                .property("role", SchemaBuilder::string()),
+                        ),
+                    )
+                    .property("member_count", SchemaBuilder::integer())
+                    .property("admin_count", SchemaBuilder::integer())
+                    .property(
+                        "outside_collaborators",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("login", SchemaBuilder::string())
+                                .property(
+                                    "repos",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("repo", SchemaBuilder::string())
+                                            .property("permission", SchemaBuilder::string()),
+                                    ),
+                                ),
+                        ),
+                    )
+                    .property("outside_collaborator_count", SchemaBuilder::integer())
+                    .property(
+                        "pending_invitations",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("login", SchemaBuilder::string())
+                                .property("email", SchemaBuilder::string())
+                                .property("role", SchemaBuilder::string())
+                                .property("inviter", SchemaBuilder::string())
+                                .property("created_at", SchemaBuilder::string()),
+                        ),
+                    )
+                    .property("repo_count", SchemaBuilder::integer())
+                    .property(
+                        "repo_errors",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("error", SchemaBuilder::string()),
+                        ),
+                    )
+                    .build(),
+            )
+            .example("Audit an org", json!({"org": "fast-gateway-protocol"}))
+            .errors(&["NOT_FOUND", "UNAUTHORIZED", "SSO_REQUIRED"]),
+
+            // github.org_repo_summary - Fleet health across an org
+            MethodInfo::new(
+                "github.org_repo_summary",
+                "Per-repo open issues/PRs, last push, default-branch CI, and unreleased commits for an org",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property("org", SchemaBuilder::string().description("Organization login"))
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(1000)
+                            .default_value(json!(50))
+                            .description("Most recently pushed repositories to include (fetched 100 per request)"),
+                    )
+                    .property(
+                        "include_archived",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(false))
+                            .description("Include archived repositories"),
+                    )
+                    .required(&["org"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("org", SchemaBuilder::string())
+                    .property(
+                        "repos",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("open_issues", SchemaBuilder::integer())
+                                .property("open_prs", SchemaBuilder::integer())
+                                .property("pushed_at", SchemaBuilder::string())
+                                .property("default_branch", SchemaBuilder::string())
+                                .property("ci_state", SchemaBuilder::string())
+                                .property("latest_release", SchemaBuilder::string())
+                                .property("unreleased_commits", SchemaBuilder::integer()),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("failing_ci", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .build(),
+            )
+            .example("Fleet health", json!({"org": "fast-gateway-protocol"}))
+            .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.file_history - Commits that touched a file
+            MethodInfo::new("github.file_history", "List commits that touched a file, newest first")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the repository"))
+                        .property(
+                            "ref",
+                            SchemaBuilder::string()
+                                .default_value(json!("HEAD"))
+                                .description("Branch, tag, or SHA to start from"),
+                        )
+                        .property(
+                            "limit",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .maximum(100)
+                                .default_value(json!(20))
+                                .description("Maximum commits to return"),
+                        )
+                        .property(
+                            "follow_renames",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Continue through renames (up to 5)"),
+                        )
+                        .required(&["repo", "path"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
+                        .property(
+                            "commits",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("message", SchemaBuilder::string())
+                                    .property("author_name", SchemaBuilder::string())
+                                    .property("author_login", SchemaBuilder::string())
+                                    .property("committed_date", SchemaBuilder::string())
+                                    .property("path", SchemaBuilder::string()),
+                            ),
+                        )
+                        .property("count", SchemaBuilder::integer())
+                        .property(
+                            "renames",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("commit", SchemaBuilder::string())
+                                    .property("from", SchemaBuilder::string())
+                                    .property("to", SchemaBuilder::string()),
+                            ),
+                        )
+                        .build(),
+                )
+                .example(
+                    "Who changed the config",
+                    json!({"repo": "fast-gateway-protocol/daemon", "path": "config/default.toml", "limit": 5}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.tree - Directory listing
+            MethodInfo::new("github.tree", "List files and directories in a repository path")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "ref",
+                            SchemaBuilder::string()
+                                .default_value(json!("HEAD"))
+                                .description("Branch, tag, or SHA"),
+                        )
+                        .property(
+                            "path",
+                            SchemaBuilder::string()
+                                .default_value(json!(""))
+                                .description("Directory path (default: repository root)"),
+                        )
+                        .property(
+                            "recursive",
+                            SchemaBuilder::boolean()
+                                .default_value(json!(false))
+                                .description("Include subdirectories"),
+                        )
+                        .property(
+                            "max_depth",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(3))
+                                .description("Levels to descend when recursive"),
+                        )
+                        .property(
+                            "max_entries",
+                            SchemaBuilder::integer()
+                                .minimum(1)
+                                .default_value(json!(1000))
+                                .description("Maximum entries to return"),
+                        )
+                        .required(&["repo"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("ref", SchemaBuilder::string())
+                        .property("path", SchemaBuilder::string())
+                        .property(
+                            "entries",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("path", SchemaBuilder::string())
+                                    .property(
+                                        "type",
+                                        SchemaBuilder::string().enum_values(&["blob", "tree", "commit"]),
+                                    )
+                                    .property("size", SchemaBuilder::integer())
+                                    .property("sha", SchemaBuilder::string())
+                                    .property("mode", SchemaBuilder::string()),
+                            ),
                         )
+                        .property("count", SchemaBuilder::integer())
+                        .property("truncated", SchemaBuilder::boolean())
+                        .build(),
+                )
+                .example("List repo root", json!({"repo": "fast-gateway-protocol/daemon"}))
+                .example(
+                    "Explore src two levels deep",
+                    json!({"repo": "fast-gateway-protocol/daemon", "path": "src", "recursive": true, "max_depth": 2}),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.workflow_config - Inspect Actions workflow files
+            MethodInfo::new(
+                "github.workflow_config",
+                "Parse workflow files: triggers, jobs, runners, and referenced actions (flags unpinned third-party actions)",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                            .description("Repository in 'owner/repo' format"),
+                    )
+                    .property(
+                        "ref",
+                        SchemaBuilder::string()
+                            .default_value(json!("HEAD"))
+                            .description("Branch, tag, or SHA"),
+                    )
+                    .required(&["repo"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("ref", SchemaBuilder::string())
+                    .property(
+                        "workflows",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("path", SchemaBuilder::string())
+                                .property("name", SchemaBuilder::string())
+                                .property("triggers", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                .property(
+                                    "jobs",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("id", SchemaBuilder::string())
+                                            .property(
+                                                "runs_on",
+                                                SchemaBuilder::array().items(SchemaBuilder::string()),
+                                            )
+                                            .property("uses", SchemaBuilder::string())
+                                            .property("step_count", SchemaBuilder::integer()),
+                                    ),
+                                )
+                                .property(
+                                    "actions",
+                                    SchemaBuilder::array().items(
+                                        SchemaBuilder::object()
+                                            .property("uses", SchemaBuilder::string())
+                                            .property("job", SchemaBuilder::string())
+                                            .property("kind", SchemaBuilder::string())
+                                            .property("version", SchemaBuilder::string())
+                                            .property("pinned", SchemaBuilder::boolean())
+                                            .property("third_party", SchemaBuilder::boolean()),
+                                    ),
+                                )
+                                .property(
+                                    "unpinned_third_party",
+                                    SchemaBuilder::array().items(SchemaBuilder::string()),
+                                )
+                                .property("parse_error", SchemaBuilder::string()),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("unpinned_third_party_count", SchemaBuilder::integer())
+                    .build(),
+            )
+            .example("Inspect workflows", json!({"repo": "fast-gateway-protocol/daemon"}))
+            .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.stale_branches - Branch staleness report
+            MethodInfo::new(
+                "github.stale_branches",
+                "List branches with no commits within a threshold, optionally deleting merged ones",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                            .description("Repository in 'owner/repo' format"),
+                    )
+                    .property(
+                        "threshold_days",
+                        SchemaBuilder::integer()
+                            .minimum(0)
+                            .default_value(json!(90))
+                            .description("Branches whose last commit is older than this are stale"),
+                    )
+                    .property(
+                        "delete_merged",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(false))
+                            .description("Delete stale branches that are merged, unprotected, and have no open PR"),
+                    )
+                    .property(
+                        "dry_run",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(true))
+                            .description("Report what delete_merged would delete without deleting"),
+                    )
+                    .required(&["repo"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("default_branch", SchemaBuilder::string())
+                    .property(
+                        "branches",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("name", SchemaBuilder::string())
+                                .property("last_commit_date", SchemaBuilder::string())
+                                .property("days_since_commit", SchemaBuilder::integer())
+                                .property("author", SchemaBuilder::string())
+                                .property("ahead_by", SchemaBuilder::integer())
+                                .property("behind_by", SchemaBuilder::integer())
+                                .property("open_pr", SchemaBuilder::integer())
+                                .property("merged_pr", SchemaBuilder::integer())
+                                .property("merged", SchemaBuilder::boolean())
+                                .property("protected", SchemaBuilder::boolean()),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("dry_run", SchemaBuilder::boolean())
+                    .property("deleted", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property("would_delete", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property(
+                        "delete_errors",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("branch", SchemaBuilder::string())
+                                .property("error", SchemaBuilder::string()),
+                        ),
+                    )
+                    .build(),
+            )
+            .example(
+                "Branches idle for 6 months",
+                json!({"repo": "fast-gateway-protocol/daemon", "threshold_days": 180}),
+            )
+            .example(
+                "Preview cleanup",
+                json!({"repo": "fast-gateway-protocol/daemon", "delete_merged": true, "dry_run": true}),
+            )
+            .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.similar_issues - Find likely duplicate issues
+            MethodInfo::new(
+                "github.similar_issues",
+                "Find existing issues similar to a new title/body (duplicate detection)",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                            .description("Repository in 'owner/repo' format"),
+                    )
+                    .property("title", SchemaBuilder::string().description("Proposed issue title"))
+                    .property("body", SchemaBuilder::string().description("Proposed issue body"))
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(30)
+                            .default_value(json!(5))
+                            .description("Maximum matches to return"),
+                    )
+                    .property(
+                        "min_score",
+                        SchemaBuilder::number()
+                            .default_value(json!(0.2))
+                            .description("Minimum similarity score (0-1)"),
+                    )
+                    .required(&["repo", "title"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property(
+                        "similar",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("number", SchemaBuilder::integer())
+                                .property("title", SchemaBuilder::string())
+                                .property("state", SchemaBuilder::string())
+                                .property("url", SchemaBuilder::string())
+                                .property("score", SchemaBuilder::number())
+                                .property(
+                                    "matched_terms",
+                                    SchemaBuilder::array().items(SchemaBuilder::string()),
+                                ),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("likely_duplicate", SchemaBuilder::boolean())
+                    .build(),
+            )
+            .example(
+                "Check before filing",
+                json!({"repo": "fast-gateway-protocol/daemon", "title": "Daemon crashes on startup", "body": "Socket file missing"}),
+            )
+            .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+
+            // github.remind - Schedule a reminder search
+            MethodInfo::new(
+                "github.remind",
+                "Subscribe to a scheduled search (e.g. weekday 09:00, PRs assigned to me older than 2 days)",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property("name", SchemaBuilder::string().description("Reminder name"))
+                    .property(
+                        "query",
+                        SchemaBuilder::string()
+                            .description("GitHub search query, e.g. 'is:pr is:open assignee:@me'"),
+                    )
+                    .property(
+                        "older_than_days",
+                        SchemaBuilder::integer()
+                            .minimum(0)
+                            .description("Only match items created more than N days ago"),
+                    )
+                    .property(
+                        "days",
+                        SchemaBuilder::array()
+                            .items(SchemaBuilder::string())
+                            .description("mon..sun, weekdays, weekends, or daily (default: weekdays)"),
+                    )
+                    .property(
+                        "time",
+                        SchemaBuilder::string()
+                            .pattern("^[0-2][0-9]:[0-5][0-9]$")
+                            .default_value(json!("09:00"))
+                            .description("Local time of day (HH:MM)"),
+                    )
+                    .required(&["name", "query"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("id", SchemaBuilder::string())
+                    .property("name", SchemaBuilder::string())
+                    .property("query", SchemaBuilder::string())
+                    .property("days", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property("time", SchemaBuilder::string())
+                    .build(),
+            )
+            .example(
+                "Weekday stale PR nudge",
+                json!({"name": "stale assigned PRs", "query": "is:pr is:open assignee:@me", "older_than_days": 2, "days": ["weekdays"], "time": "09:00"}),
+            )
+            .errors(&["VALIDATION_FAILED"]),
+
+            // github.reminders - List reminders and latest results
+            MethodInfo::new("github.reminders", "List reminder subscriptions with their latest results")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
                         .property(
-                            "limit",
-                            SchemaBuilder::integer()
-                                .minimum(1)
-                                .maximum(100)
-                                .default_value(json!(10))
-                                .description("Maximum PRs to return"),
+                            "run_now",
+                            SchemaBuilder::string().description("Reminder ID to run immediately before listing"),
                         )
-                        .required(&["repo"])
                         .build(),
-                )
+                ))
                 .returns(
                     SchemaBuilder::object()
-                        .property("repo", SchemaBuilder::string())
-                        .property("state", SchemaBuilder::string())
                         .property(
-                            "prs",
+                            "reminders",
                             SchemaBuilder::array().items(
                                 SchemaBuilder::object()
-                                    .property("number", SchemaBuilder::integer())
-                                    .property("title", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string())
-                                    .property("head_ref", SchemaBuilder::string())
-                                    .property("base_ref", SchemaBuilder::string())
-                                    .property("url", SchemaBuilder::string().format("uri")),
+                                    .property("id", SchemaBuilder::string())
+                                    .property("name", SchemaBuilder::string())
+                                    .property("query", SchemaBuilder::string())
+                                    .property("last_run", SchemaBuilder::string())
+                                    .property(
+                                        "last_results",
+                                        SchemaBuilder::array().items(
+                                            SchemaBuilder::object()
+                                                .property("kind", SchemaBuilder::string())
+                                                .property("repo", SchemaBuilder::string())
+                                                .property("number", SchemaBuilder::integer())
+                                                .property("title", SchemaBuilder::string())
+                                                .property("url", SchemaBuilder::string()),
+                                        ),
+                                    )
+                                    .property("last_error", SchemaBuilder::string()),
                             ),
                         )
                         .property("count", SchemaBuilder::integer())
                         .build(),
                 )
-                .example(
-                    "List open PRs",
-                    json!({"repo": "fast-gateway-protocol/daemon"}),
+                .example("List reminders", json!({})),
+
+            // github.cancel_reminder - Remove a reminder
+            MethodInfo::new("github.cancel_reminder", "Cancel a reminder subscription")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string().description("Reminder ID"))
+                        .required(&["id"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("id", SchemaBuilder::string())
+                        .property("cancelled", SchemaBuilder::boolean())
+                        .build(),
                 )
-                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+                .example("Cancel", json!({"id": "rem_1705222800000_0"}))
+                .errors(&["NOT_FOUND"]),
 
-            // github.pr - Get PR details
-            MethodInfo::new("github.pr", "Get pull request details with reviews and status checks")
-                .schema(
+            // github.cache_stats - Response cache statistics
+            MethodInfo::new("github.cache_stats", "Show response cache hit rates, sizes, and per-method TTLs")
+                .schema(Self::with_common_params(SchemaBuilder::object().build()))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("memory_entries", SchemaBuilder::integer())
+                        .property("memory_capacity", SchemaBuilder::integer())
+                        .property("disk_entries", SchemaBuilder::integer())
+                        .property("memory_hits", SchemaBuilder::integer())
+                        .property("disk_hits", SchemaBuilder::integer())
+                        .property("misses", SchemaBuilder::integer())
+                        .property("evictions", SchemaBuilder::integer())
+                        .property("swept", SchemaBuilder::integer().description("Entry files removed by disk sweeps"))
+                        .property("disk_max_bytes", SchemaBuilder::integer())
+                        .property("ttls", SchemaBuilder::object().description("TTL seconds by method"))
+                        .build(),
+                )
+                .example("Cache stats", json!({})),
+
+            // github.local_search - Search cached responses
+            MethodInfo::new(
+                "github.local_search",
+                "Search content the daemon has already fetched (issue bodies, PR descriptions, workflow and CODEOWNERS files, cached responses) without API calls",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property("query", SchemaBuilder::string().min_length(1).description("Text to find"))
+                    .property(
+                        "regex",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(false))
+                            .description("Treat query as a regular expression"),
+                    )
+                    .property("case_sensitive", SchemaBuilder::boolean().default_value(json!(false)))
+                    .property(
+                        "methods",
+                        SchemaBuilder::array()
+                            .items(SchemaBuilder::string())
+                            .description("Only search content fetched by these methods (default: all)"),
+                    )
+                    .property(
+                        "include_stale",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(true))
+                            .description("Also search responses past their cache TTL"),
+                    )
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(500)
+                            .default_value(json!(50)),
+                    )
+                    .required(&["query"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("query", SchemaBuilder::string())
+                    .property(
+                        "hits",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("method", SchemaBuilder::string())
+                                .property("path", SchemaBuilder::string())
+                                .property("snippet", SchemaBuilder::string())
+                                .property("context", SchemaBuilder::object())
+                                .property("stale", SchemaBuilder::boolean()),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("searched_responses", SchemaBuilder::integer())
+                    .property("truncated", SchemaBuilder::boolean())
+                    .build(),
+            )
+            .example("Find a stack trace mention", json!({"query": "NullPointerException"}))
+            .example(
+                "Regex over cached PRs",
+                json!({"query": "fixes #\\d+", "regex": true, "methods": ["github.pr"]}),
+            ),
+
+            // github.cache_clear - Drop cached responses
+            MethodInfo::new("github.cache_clear", "Clear cached responses for one method or all methods")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "method",
+                            SchemaBuilder::string().description("Method to clear, e.g. 'github.repos' (default: all)"),
+                        )
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("method", SchemaBuilder::string())
+                        .property("removed", SchemaBuilder::integer())
+                        .build(),
+                )
+                .example("Clear everything", json!({}))
+                .example("Clear repo listings", json!({"method": "github.repos"})),
+
+            // github.update_issue - Edit an issue
+            MethodInfo::new("github.update_issue", "Update an issue's title, body, or state")
+                .schema(Self::with_common_params(
                     SchemaBuilder::object()
                         .property(
                             "repo",
                             SchemaBuilder::string()
-                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
-                                .description("Repository in 'owner/repo' format"),
+                                .pattern("^[^/]+/[^/]+$")
+                                .description("Repository in owner/repo format"),
                         )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Issue number"))
+                        .property("title", SchemaBuilder::string())
+                        .property("body", SchemaBuilder::string())
+                        .property("state", SchemaBuilder::string().enum_values(&["open", "closed"]))
                         .property(
-                            "number",
-                            SchemaBuilder::integer()
-                                .minimum(1)
-                                .description("Pull request number"),
+                            "expected_updated_at",
+                            SchemaBuilder::string()
+                                .format("date-time")
+                                .description("Fail with CONFLICT if the issue changed after this updated_at"),
                         )
                         .required(&["repo", "number"])
                         .build(),
-                )
+                ))
                 .returns(
                     SchemaBuilder::object()
-                        .property("number", SchemaBuilder::integer())
-                        .property("title", SchemaBuilder::string())
-                        .property("body", SchemaBuilder::string())
-                        .property("state", SchemaBuilder::string())
-                        .property("mergeable", SchemaBuilder::boolean())
-                        .property("head_ref", SchemaBuilder::string())
-                        .property("base_ref", SchemaBuilder::string())
+                        .property("updated", SchemaBuilder::boolean())
+                        .property("repo", SchemaBuilder::string())
                         .property(
-                            "reviews",
-                            SchemaBuilder::array().items(
-                                SchemaBuilder::object()
-                                    .property("author", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string()),
-                            ),
+                            "issue",
+                            SchemaBuilder::object()
+                                .property("number", SchemaBuilder::integer())
+                                .property("title", SchemaBuilder::string())
+                                .property("state", SchemaBuilder::string())
+                                .property("updated_at", SchemaBuilder::string()),
                         )
+                        .build(),
+                )
+                .example(
+                    "Close only if unchanged",
+                    json!({"repo": "owner/repo", "number": 42, "state": "closed", "expected_updated_at": "2024-01-14T10:00:00Z"}),
+                )
+                .errors(&["NOT_FOUND", "VALIDATION_FAILED", "CONFLICT"]),
+
+            // github.set_labels - Replace issue labels
+            MethodInfo::new("github.set_labels", "Replace all labels on an issue or pull request")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
                         .property(
-                            "status_checks",
-                            SchemaBuilder::array().items(
-                                SchemaBuilder::object()
-                                    .property("context", SchemaBuilder::string())
-                                    .property("state", SchemaBuilder::string()),
-                            ),
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[^/]+/[^/]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("number", SchemaBuilder::integer().minimum(1).description("Issue or PR number"))
+                        .property(
+                            "labels",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::string())
+                                .description("Complete label set; empty clears all labels"),
+                        )
+                        .property(
+                            "expected_updated_at",
+                            SchemaBuilder::string()
+                                .format("date-time")
+                                .description("Fail with CONFLICT if the issue changed after this updated_at"),
                         )
+                        .required(&["repo", "number", "labels"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("number", SchemaBuilder::integer())
+                        .property("labels", SchemaBuilder::array().items(SchemaBuilder::string()))
+                        .property("count", SchemaBuilder::integer())
                         .build(),
                 )
                 .example(
-                    "Get PR #42",
-                    json!({"repo": "fast-gateway-protocol/daemon", "number": 42}),
+                    "Set labels",
+                    json!({"repo": "owner/repo", "number": 42, "labels": ["bug", "p1"], "expected_updated_at": "2024-01-14T10:00:00Z"}),
                 )
-                .errors(&["NOT_FOUND", "UNAUTHORIZED"]),
+                .errors(&["NOT_FOUND", "VALIDATION_FAILED", "CONFLICT"]),
 
-            // github.notifications - Get notifications
-            MethodInfo::new("github.notifications", "Get unread GitHub notifications")
-                .schema(SchemaBuilder::object().build())
+            // github.put_file - Create or update a file
+            MethodInfo::new("github.put_file", "Create or overwrite a single file with a commit")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[^/]+/[^/]+$")
+                                .description("Repository in owner/repo format"),
+                        )
+                        .property("path", SchemaBuilder::string().description("File path in the repository"))
+                        .property("content", SchemaBuilder::string().description("UTF-8 file contents"))
+                        .property("content_base64", SchemaBuilder::string().description("Base64 file contents"))
+                        .property("message", SchemaBuilder::string().description("Commit message"))
+                        .property("branch", SchemaBuilder::string().description("Target branch (default: default branch)"))
+                        .property(
+                            "expected_sha",
+                            SchemaBuilder::string()
+                                .description("Fail with CONFLICT unless the file's current blob SHA matches"),
+                        )
+                        .required(&["repo", "path", "message"])
+                        .build(),
+                ))
                 .returns(
                     SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
                         .property(
-                            "notifications",
-                            SchemaBuilder::array().items(
-                                SchemaBuilder::object()
-                                    .property("id", SchemaBuilder::string())
-                                    .property("reason", SchemaBuilder::string())
-                                    .property("unread", SchemaBuilder::boolean())
-                                    .property("subject_title", SchemaBuilder::string())
-                                    .property("subject_type", SchemaBuilder::string())
-                                    .property("repo_full_name", SchemaBuilder::string()),
-                            ),
+                            "file",
+                            SchemaBuilder::object()
+                                .property("path", SchemaBuilder::string())
+                                .property("sha", SchemaBuilder::string())
+                                .property("previous_sha", SchemaBuilder::string())
+                                .property("created", SchemaBuilder::boolean())
+                                .property("commit_sha", SchemaBuilder::string())
+                                .property("commit_url", SchemaBuilder::string().format("uri")),
                         )
-                        .property("unread_count", SchemaBuilder::integer())
                         .build(),
                 )
-                .example("Get notifications", json!({})),
+                .example(
+                    "Update only if unchanged",
+                    json!({"repo": "owner/repo", "path": "VERSION", "content": "1.2.0\n", "message": "Bump version", "expected_sha": "3b18e512dba79e4c8300dd08aeb37f8e728b8dad"}),
+                )
+                .errors(&["NOT_FOUND", "VALIDATION_FAILED", "CONFLICT"]),
 
-            // github.create_issue - Create issue
-            MethodInfo::new("github.create_issue", "Create a new issue in a repository")
-                .schema(
+            // github.permissions - Effective repo permission
+            MethodInfo::new(
+                "github.permissions",
+                "Report your effective permission on a repository and which methods will succeed",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[^/]+/[^/]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .required(&["repo"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property(
+                        "permission",
+                        SchemaBuilder::string().enum_values(&["read", "triage", "write", "maintain", "admin"]),
+                    )
+                    .property("is_archived", SchemaBuilder::boolean())
+                    .property("can_merge", SchemaBuilder::boolean())
+                    .property("can_push", SchemaBuilder::boolean())
+                    .property("can_administer", SchemaBuilder::boolean())
+                    .property("allowed", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property(
+                        "denied",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("method", SchemaBuilder::string())
+                                .property("requires", SchemaBuilder::string())
+                                .property("reason", SchemaBuilder::string().enum_values(&["permission", "archived"])),
+                        ),
+                    )
+                    .build(),
+            )
+            .example("Check before planning a merge", json!({"repo": "owner/repo"}))
+            .errors(&["NOT_FOUND"]),
+
+            // github.events_since - Replay recorded events
+            MethodInfo::new(
+                "github.events_since",
+                "Replay events recorded after a sequence number (poller notifications, reminder hits)",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "seq",
+                        SchemaBuilder::integer()
+                            .minimum(0)
+                            .default_value(json!(0))
+                            .description("Return events with a sequence number greater than this"),
+                    )
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(1000)
+                            .default_value(json!(100)),
+                    )
+                    .property(
+                        "kinds",
+                        SchemaBuilder::array()
+                            .items(SchemaBuilder::string())
+                            .description("Only these event kinds, e.g. notification, reminder, labeled, or a trigger's emit name (default: all)"),
+                    )
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property(
+                        "events",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("seq", SchemaBuilder::integer())
+                                .property("kind", SchemaBuilder::string())
+                                .property("created_at", SchemaBuilder::string())
+                                .property("payload", SchemaBuilder::object()),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property(
+                        "next_seq",
+                        SchemaBuilder::integer().description("Pass as seq on the next call"),
+                    )
+                    .property("latest_seq", SchemaBuilder::integer())
+                    .property("oldest_seq", SchemaBuilder::integer())
+                    .property(
+                        "gap",
+                        SchemaBuilder::boolean().description("True if events after seq were already compacted away"),
+                    )
+                    .build(),
+            )
+            .example("Resume after reconnect", json!({"seq": 1042}))
+            .example("Only notifications", json!({"seq": 0, "kinds": ["notification"], "limit": 50})),
+
+            // github.verify_ref - Check commit signatures in a range
+            MethodInfo::new(
+                "github.verify_ref",
+                "Check that every commit in a range (and the tag, if head is an annotated tag) has a verified signature",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[^/]+/[^/]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .property(
+                        "head",
+                        SchemaBuilder::string().description("Branch, tag, or SHA to check"),
+                    )
+                    .property(
+                        "base",
+                        SchemaBuilder::string()
+                            .description("Only check commits after this ref (default: the latest `limit` commits of head)"),
+                    )
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(1000)
+                            .default_value(json!(250)),
+                    )
+                    .required(&["repo", "head"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("base", SchemaBuilder::string())
+                    .property("head", SchemaBuilder::string())
+                    .property("checked", SchemaBuilder::integer())
+                    .property("verified", SchemaBuilder::integer())
+                    .property("all_verified", SchemaBuilder::boolean())
+                    .property("truncated", SchemaBuilder::boolean())
+                    .property(
+                        "unverified",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("sha", SchemaBuilder::string())
+                                .property("message", SchemaBuilder::string())
+                                .property("author_login", SchemaBuilder::string())
+                                .property(
+                                    "signature",
+                                    SchemaBuilder::object()
+                                        .property("signed", SchemaBuilder::boolean())
+                                        .property("verified", SchemaBuilder::boolean())
+                                        .property("reason", SchemaBuilder::string())
+                                        .property("signer", SchemaBuilder::string()),
+                                ),
+                        ),
+                    )
+                    .property("tag_signature", SchemaBuilder::object())
+                    .build(),
+            )
+            .example(
+                "Check a release range before publishing",
+                json!({"repo": "owner/repo", "base": "v1.1.0", "head": "v1.2.0"}),
+            )
+            .errors(&["NOT_FOUND"]),
+
+            // github.bootstrap_repo - Initial files and labels for a new repo
+            MethodInfo::new(
+                "github.bootstrap_repo",
+                "Add .gitignore, LICENSE, README, and default labels to a new repository in one commit",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[^/]+/[^/]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .property(
+                        "gitignore",
+                        SchemaBuilder::string().description("GitHub .gitignore template name, e.g. \"Rust\""),
+                    )
+                    .property(
+                        "license",
+                        SchemaBuilder::string().description("License key, e.g. \"mit\" or \"apache-2.0\""),
+                    )
+                    .property(
+                        "holder",
+                        SchemaBuilder::string().description("Copyright holder (default: repo owner's name)"),
+                    )
+                    .property("readme", SchemaBuilder::boolean().default_value(json!(true)))
+                    .property(
+                        "description",
+                        SchemaBuilder::string().description("README summary (default: repo description)"),
+                    )
+                    .property(
+                        "labels",
+                        SchemaBuilder::array()
+                            .items(
+                                SchemaBuilder::object()
+                                    .property("name", SchemaBuilder::string())
+                                    .property("color", SchemaBuilder::string().pattern("^#?[0-9a-fA-F]{6}$"))
+                                    .property("description", SchemaBuilder::string())
+                                    .required(&["name", "color"]),
+                            )
+                            .description("Labels to create (default: a standard set); existing ones are skipped"),
+                    )
+                    .property(
+                        "branch",
+                        SchemaBuilder::string().description("Branch to commit to (default: default branch, or main if empty)"),
+                    )
+                    .property("message", SchemaBuilder::string().default_value(json!("Initial commit")))
+                    .property(
+                        "dry_run",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(false))
+                            .description("Return the files and labels without writing anything"),
+                    )
+                    .required(&["repo"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("branch", SchemaBuilder::string())
+                    .property("was_empty", SchemaBuilder::boolean())
+                    .property("dry_run", SchemaBuilder::boolean())
+                    .property("files", SchemaBuilder::array())
+                    .property("seed", SchemaBuilder::object().description("First file, written via the contents API when the repo was empty"))
+                    .property("commit", SchemaBuilder::object())
+                    .property("labels_created", SchemaBuilder::array().items(SchemaBuilder::string()))
+                    .property("labels_to_create", SchemaBuilder::array())
+                    .property(
+                        "label_errors",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("label", SchemaBuilder::string())
+                                .property("error", SchemaBuilder::string()),
+                        ),
+                    )
+                    .build(),
+            )
+            .example(
+                "Preview a Rust project setup",
+                json!({"repo": "owner/new-repo", "gitignore": "Rust", "license": "mit", "dry_run": true}),
+            )
+            .errors(&["NOT_FOUND", "VALIDATION_FAILED", "CONFLICT"]),
+
+            // github.community_profile - Community health files
+            MethodInfo::new(
+                "github.community_profile",
+                "Report community health files (README, CODE_OF_CONDUCT, CONTRIBUTING, SECURITY.md, templates, license) for one or many repos",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[^/]+/[^/]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .property(
+                        "repos",
+                        SchemaBuilder::array()
+                            .items(SchemaBuilder::string().pattern("^[^/]+/[^/]+$"))
+                            .description("Several repositories in owner/repo format"),
+                    )
+                    .property(
+                        "org",
+                        SchemaBuilder::string().description("Include every repository in this organization"),
+                    )
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property(
+                        "profiles",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("health_percentage", SchemaBuilder::integer())
+                                .property("readme", SchemaBuilder::boolean())
+                                .property("code_of_conduct", SchemaBuilder::boolean())
+                                .property("contributing", SchemaBuilder::boolean())
+                                .property("security_policy", SchemaBuilder::boolean())
+                                .property("issue_templates", SchemaBuilder::boolean())
+                                .property("pull_request_template", SchemaBuilder::boolean())
+                                .property("license", SchemaBuilder::string())
+                                .property("missing", SchemaBuilder::array().items(SchemaBuilder::string())),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("compliant_count", SchemaBuilder::integer())
+                    .property("average_health", SchemaBuilder::number())
+                    .property("missing_counts", SchemaBuilder::object())
+                    .property(
+                        "errors",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("error", SchemaBuilder::string()),
+                        ),
+                    )
+                    .build(),
+            )
+            .example("Single repository", json!({"repo": "owner/repo"}))
+            .example("Fleet-wide compliance report", json!({"org": "fast-gateway-protocol"}))
+            .errors(&["NOT_FOUND", "SSO_REQUIRED"]),
+
+            // github.actions_caches - List Actions caches
+            MethodInfo::new(
+                "github.actions_caches",
+                "List a repository's Actions caches with key, ref, size, and last access",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[^/]+/[^/]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .property("key", SchemaBuilder::string().description("Only keys starting with this"))
+                    .property(
+                        "ref",
+                        SchemaBuilder::string().description("Only caches for this ref, e.g. refs/heads/main"),
+                    )
+                    .property(
+                        "limit",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(1000)
+                            .default_value(json!(100)),
+                    )
+                    .required(&["repo"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property(
+                        "caches",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("key", SchemaBuilder::string())
+                                .property("ref", SchemaBuilder::string())
+                                .property("size_in_bytes", SchemaBuilder::integer())
+                                .property("created_at", SchemaBuilder::string())
+                                .property("last_accessed_at", SchemaBuilder::string()),
+                        ),
+                    )
+                    .property("count", SchemaBuilder::integer())
+                    .property("total_count", SchemaBuilder::integer())
+                    .property("listed_size_in_bytes", SchemaBuilder::integer())
+                    .build(),
+            )
+            .example("Largest consumers on main", json!({"repo": "owner/repo", "ref": "refs/heads/main"}))
+            .errors(&["NOT_FOUND"]),
+
+            // github.delete_actions_caches - Delete Actions caches by key or prefix
+            MethodInfo::new(
+                "github.delete_actions_caches",
+                "Delete Actions caches matching a key exactly or by prefix",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[^/]+/[^/]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .property("key", SchemaBuilder::string().description("Delete caches with exactly this key"))
+                    .property(
+                        "prefix",
+                        SchemaBuilder::string()
+                            .min_length(1)
+                            .description("Delete caches whose key starts with this"),
+                    )
+                    .property("ref", SchemaBuilder::string().description("Only caches for this ref"))
+                    .property(
+                        "dry_run",
+                        SchemaBuilder::boolean()
+                            .default_value(json!(false))
+                            .description("List what would be deleted without deleting"),
+                    )
+                    .required(&["repo"])
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("repo", SchemaBuilder::string())
+                    .property("dry_run", SchemaBuilder::boolean())
+                    .property("matched", SchemaBuilder::array())
+                    .property("matched_count", SchemaBuilder::integer())
+                    .property("deleted_count", SchemaBuilder::integer())
+                    .property("freed_bytes", SchemaBuilder::integer())
+                    .property(
+                        "errors",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("id", SchemaBuilder::integer())
+                                .property("key", SchemaBuilder::string())
+                                .property("error", SchemaBuilder::string()),
+                        ),
+                    )
+                    .build(),
+            )
+            .example(
+                "Preview deleting stale cargo caches",
+                json!({"repo": "owner/repo", "prefix": "Linux-cargo-", "dry_run": true}),
+            )
+            .errors(&["NOT_FOUND"]),
+
+            // github.review_metrics - Review latency and load
+            MethodInfo::new(
+                "github.review_metrics",
+                "Time to first review, time to merge, and per-reviewer load for PRs opened in a recent window",
+            )
+            .schema(Self::with_common_params(
+                SchemaBuilder::object()
+                    .property(
+                        "repo",
+                        SchemaBuilder::string()
+                            .pattern("^[^/]+/[^/]+$")
+                            .description("Repository in owner/repo format"),
+                    )
+                    .property(
+                        "org",
+                        SchemaBuilder::string().description("Aggregate across every repository in this organization"),
+                    )
+                    .property(
+                        "days",
+                        SchemaBuilder::integer()
+                            .minimum(1)
+                            .maximum(90)
+                            .default_value(json!(7))
+                            .description("Include PRs created in the last N days"),
+                    )
+                    .build(),
+            ))
+            .returns(
+                SchemaBuilder::object()
+                    .property("scope", SchemaBuilder::string())
+                    .property("days", SchemaBuilder::integer())
+                    .property("since", SchemaBuilder::string().format("date"))
+                    .property("repos", SchemaBuilder::integer())
+                    .property(
+                        "metrics",
+                        SchemaBuilder::object()
+                            .property("prs", SchemaBuilder::integer())
+                            .property("merged", SchemaBuilder::integer())
+                            .property("unreviewed", SchemaBuilder::integer())
+                            .property(
+                                "time_to_first_review",
+                                SchemaBuilder::object()
+                                    .property("count", SchemaBuilder::integer())
+                                    .property("median_hours", SchemaBuilder::number())
+                                    .property("p90_hours", SchemaBuilder::number())
+                                    .property("mean_hours", SchemaBuilder::number()),
+                            )
+                            .property("time_to_merge", SchemaBuilder::object())
+                            .property(
+                                "reviewers",
+                                SchemaBuilder::array().items(
+                                    SchemaBuilder::object()
+                                        .property("reviewer", SchemaBuilder::string())
+                                        .property("reviews", SchemaBuilder::integer())
+                                        .property("prs_reviewed", SchemaBuilder::integer())
+                                        .property("median_response_hours", SchemaBuilder::number()),
+                                ),
+                            ),
+                    )
+                    .property(
+                        "errors",
+                        SchemaBuilder::array().items(
+                            SchemaBuilder::object()
+                                .property("repo", SchemaBuilder::string())
+                                .property("error", SchemaBuilder::string()),
+                        ),
+                    )
+                    .build(),
+            )
+            .example("Last week in one repo", json!({"repo": "owner/repo"}))
+            .example("Org-wide, last 30 days", json!({"org": "fast-gateway-protocol", "days": 30}))
+            .errors(&["NOT_FOUND", "SSO_REQUIRED"]),
+
+            // github.plan - Validate a multi-step change
+            MethodInfo::new("github.plan", "Validate a multi-step change (create branch, commit, open PR, request reviewers) without making it")
+                .schema(Self::with_common_params(
                     SchemaBuilder::object()
                         .property(
                             "repo",
@@ -477,73 +6589,302 @@ impl FgpService for GitHubService {
                                 .description("Repository in 'owner/repo' format"),
                         )
                         .property(
-                            "title",
-                            SchemaBuilder::string()
-                                .min_length(1)
-                                .max_length(256)
-                                .description("Issue title"),
+                            "steps",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property(
+                                            "op",
+                                            SchemaBuilder::string().enum_values(&[
+                                                "create_branch",
+                                                "commit",
+                                                "open_pr",
+                                                "request_reviewers",
+                                            ]),
+                                        )
+                                        .property("branch", SchemaBuilder::string().description("create_branch, commit"))
+                                        .property("from", SchemaBuilder::string().description("create_branch base (default: default branch)"))
+                                        .property("message", SchemaBuilder::string().description("commit message"))
+                                        .property(
+                                            "additions",
+                                            SchemaBuilder::array()
+                                                .items(SchemaBuilder::object())
+                                                .description("commit files, as in github.create_commit"),
+                                        )
+                                        .property("deletions", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                        .property("title", SchemaBuilder::string().description("open_pr title"))
+                                        .property("body", SchemaBuilder::string())
+                                        .property("head", SchemaBuilder::string().description("open_pr head (default: last created branch)"))
+                                        .property("base", SchemaBuilder::string().description("open_pr base (default: head's 'from' or default branch)"))
+                                        .property("draft", SchemaBuilder::boolean().default_value(json!(false)))
+                                        .property("number", SchemaBuilder::integer().description("request_reviewers PR (default: PR opened by the plan)"))
+                                        .property("reviewers", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                        .property("team_reviewers", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                        .required(&["op"]),
+                                )
+                                .description("Steps in order; commit/open_pr default to the last branch created"),
                         )
+                        .required(&["repo", "steps"])
+                        .build(),
+                ))
+                .returns(
+                    SchemaBuilder::object()
+                        .property("repo", SchemaBuilder::string())
+                        .property("default_branch", SchemaBuilder::string())
+                        .property("valid", SchemaBuilder::boolean())
+                        .property("problems", SchemaBuilder::array().items(SchemaBuilder::string()))
+                        .property("summary", SchemaBuilder::array().items(SchemaBuilder::string()))
                         .property(
-                            "body",
-                            SchemaBuilder::string()
-                                .description("Issue body (Markdown supported)"),
+                            "steps",
+                            SchemaBuilder::array()
+                                .items(SchemaBuilder::object())
+                                .description("Steps with defaults filled in; pass to github.apply"),
                         )
-                        .required(&["repo", "title"])
                         .build(),
                 )
+                .example(
+                    "Branch, commit, PR, and review request",
+                    json!({
+                        "repo": "fast-gateway-protocol/daemon",
+                        "steps": [
+                            {"op": "create_branch", "branch": "bot/bump-toolchain"},
+                            {"op": "commit", "message": "Bump toolchain", "additions": [
+                                {"path": "rust-toolchain.toml", "content": "[toolchain]\nchannel = \"1.80\"\n"}
+                            ]},
+                            {"op": "open_pr", "title": "Bump toolchain to 1.80"},
+                            {"op": "request_reviewers", "reviewers": ["octocat"]}
+                        ]
+                    }),
+                )
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+
+            // github.apply - Run a multi-step change
+            MethodInfo::new("github.apply", "Run plan steps in order; on failure, report completed steps and rollback hints")
+                .schema(Self::with_common_params(
+                    SchemaBuilder::object()
+                        .property(
+                            "repo",
+                            SchemaBuilder::string()
+                                .pattern("^[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+$")
+                                .description("Repository in 'owner/repo' format"),
+                        )
+                        .property(
+                            "steps",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property(
+                                            "op",
+                                            SchemaBuilder::string().enum_values(&[
+                                                "create_branch",
+                                                "commit",
+                                                "open_pr",
+                                                "request_reviewers",
+                                            ]),
+                                        )
+                                        .property("branch", SchemaBuilder::string().description("create_branch, commit"))
+                                        .property("from", SchemaBuilder::string().description("create_branch base (default: default branch)"))
+                                        .property("message", SchemaBuilder::string().description("commit message"))
+                                        .property(
+                                            "additions",
+                                            SchemaBuilder::array()
+                                                .items(SchemaBuilder::object())
+                                                .description("commit files, as in github.create_commit"),
+                                        )
+                                        .property("deletions", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                        .property("title", SchemaBuilder::string().description("open_pr title"))
+                                        .property("body", SchemaBuilder::string())
+                                        .property("head", SchemaBuilder::string().description("open_pr head (default: last created branch)"))
+                                        .property("base", SchemaBuilder::string().description("open_pr base (default: head's 'from' or default branch)"))
+                                        .property("draft", SchemaBuilder::boolean().default_value(json!(false)))
+                                        .property("number", SchemaBuilder::integer().description("request_reviewers PR (default: PR opened by the plan)"))
+                                        .property("reviewers", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                        .property("team_reviewers", SchemaBuilder::array().items(SchemaBuilder::string()))
+                                        .required(&["op"]),
+                                )
+                                .description("Steps in order; commit/open_pr default to the last branch created"),
+                        )
+                        .required(&["repo", "steps"])
+                        .build(),
+                ))
                 .returns(
                     SchemaBuilder::object()
-                        .property("created", SchemaBuilder::boolean())
+                        .property("repo", SchemaBuilder::string())
+                        .property("run_id", SchemaBuilder::string().description("Tags this run's plan_step events"))
+                        .property("success", SchemaBuilder::boolean())
+                        .property("completed", SchemaBuilder::integer())
+                        .property("total", SchemaBuilder::integer())
                         .property(
-                            "issue",
-                            SchemaBuilder::object()
-                                .property("number", SchemaBuilder::integer())
-                                .property("title", SchemaBuilder::string())
-                                .property("url", SchemaBuilder::string().format("uri")),
+                            "results",
+                            SchemaBuilder::array().items(
+                                SchemaBuilder::object()
+                                    .property("step", SchemaBuilder::integer())
+                                    .property("op", SchemaBuilder::string())
+                                    .property("description", SchemaBuilder::string())
+                                    .property("output", SchemaBuilder::object()),
+                            ),
+                        )
+                        .property("failed_step", SchemaBuilder::integer())
+                        .property("error", SchemaBuilder::string())
+                        .property(
+                            "rollback",
+                            SchemaBuilder::array()
+                                .items(
+                                    SchemaBuilder::object()
+                                        .property("step", SchemaBuilder::integer())
+                                        .property("description", SchemaBuilder::string())
+                                        .property("method", SchemaBuilder::string())
+                                        .property("params", SchemaBuilder::object())
+                                        .property("request", SchemaBuilder::string())
+                                        .property("body", SchemaBuilder::object()),
+                                )
+                                .description("Undo steps for completed work, most recent first (only on failure)"),
                         )
                         .build(),
                 )
                 .example(
-                    "Create a bug report",
+                    "Branch, commit, PR, and review request",
                     json!({
                         "repo": "fast-gateway-protocol/daemon",
-                        "title": "Bug: Socket timeout after 30s",
-                        "body": "## Description\nThe socket times out unexpectedly..."
+                        "steps": [
+                            {"op": "create_branch", "branch": "bot/bump-toolchain"},
+                            {"op": "commit", "message": "Bump toolchain", "additions": [
+                                {"path": "rust-toolchain.toml", "content": "[toolchain]\nchannel = \"1.80\"\n"}
+                            ]},
+                            {"op": "open_pr", "title": "Bump toolchain to 1.80"},
+                            {"op": "request_reviewers", "reviewers": ["octocat"]}
+                        ]
                     }),
                 )
-                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED"]),
+                .errors(&["NOT_FOUND", "UNAUTHORIZED", "VALIDATION_FAILED", "CONFLICT"]),
         ]
     }
 
     fn on_start(&self) -> Result<()> {
-        tracing::info!("GitHubService starting, verifying API connection...");
         let client = self.client.clone();
-        self.runtime.block_on(async move {
-            match client.ping().await {
+        let handle = self.runtime.handle().clone();
+        let reminders = self.reminders.clone();
+        let events = self.events.clone();
+        let rules = self.rules.clone();
+        let poller_config = self.poller_config.clone();
+        let triggers = self.triggers.clone();
+        std::thread::Builder::new()
+            .name("github-scheduler".to_string())
+            .spawn(move || {
+                run_scheduler(
+                    client,
+                    handle,
+                    reminders,
+                    events,
+                    rules,
+                    poller_config,
+                    triggers,
+                )
+            })?;
+
+        // Verify in the background so a slow or failing ping doesn't delay
+        // or abort startup; health_check reports degraded until it succeeds.
+        tracing::info!("GitHubService starting, verifying API connection in background...");
+        let client = self.client.clone();
+        let verification = self.verification.clone();
+        self.runtime.spawn(async move {
+            let result = match client.get() {
+                Ok(client) => client.ping().await,
+                Err(e) => Err(e),
+            };
+            let state = match result {
                 Ok(true) => {
                     tracing::info!("GitHub API connection verified");
-                    Ok(())
+                    Verification::Verified
                 }
                 Ok(false) => {
                     tracing::warn!("GitHub API returned empty viewer login");
-                    Ok(())
+                    Verification::Failed("Empty viewer login".to_string())
                 }
                 Err(e) => {
                     tracing::error!("Failed to connect to GitHub API: {}", e);
-                    Err(e)
+                    Verification::Failed(e.to_string())
                 }
-            }
-        })
+            };
+            *verification.lock().unwrap_or_else(|e| e.into_inner()) = state;
+        });
+
+        Ok(())
     }
 
     fn health_check(&self) -> HashMap<String, HealthStatus> {
         let mut checks = HashMap::new();
 
-        let client = self.client.clone();
+        let verification = self
+            .verification
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        match verification {
+            Verification::Verified => {}
+            Verification::Pending => {
+                checks.insert(
+                    "startup".into(),
+                    HealthStatus::unhealthy("Degraded: verifying GitHub credentials".to_string()),
+                );
+            }
+            Verification::Failed(e) => {
+                checks.insert(
+                    "startup".into(),
+                    HealthStatus::unhealthy(format!(
+                        "Degraded: startup verification failed: {}",
+                        e
+                    )),
+                );
+            }
+        }
+
+        for (name, error) in &self.load_errors {
+            checks.insert(
+                (*name).into(),
+                HealthStatus::unhealthy(format!(
+                    "Degraded: failed to load, using defaults: {}",
+                    error
+                )),
+            );
+        }
+
+        let client = match self.client() {
+            Ok(client) => client,
+            Err(e) => {
+                checks.insert("github_api".into(), HealthStatus::unhealthy(e.to_string()));
+                return checks;
+            }
+        };
         let start = std::time::Instant::now();
-        let result = self.runtime.block_on(async move { client.ping().await });
+        // Query githubstatus.com alongside the ping so a GitHub-side incident
+        // can be told apart from a problem with this daemon or its token.
+        let ((result, latency), (status, status_latency)) = self.runtime.block_on(async move {
+            tokio::join!(
+                async {
+                    let result = client.ping().await;
+                    (result, start.elapsed().as_secs_f64() * 1000.0)
+                },
+                async {
+                    let status = client.get_service_status().await;
+                    (status, start.elapsed().as_secs_f64() * 1000.0)
+                }
+            )
+        });
+
+        let status = match status {
+            Ok(status) => Some(status),
+            Err(e) => {
+                tracing::debug!("Could not fetch githubstatus.com summary: {}", e);
+                None
+            }
+        };
+        let incident = status.as_ref().and_then(Self::incident_note);
 
-        let latency = start.elapsed().as_secs_f64() * 1000.0;
+        let api_failure = |msg: String| match &incident {
+            Some(note) => format!("{} [{}]", msg, note),
+            None => msg,
+        };
 
         match result {
             Ok(true) => {
@@ -551,18 +6892,37 @@ impl FgpService for GitHubService {
                     "github_api".into(),
                     HealthStatus::healthy_with_latency(latency),
                 );
+                // A later successful ping clears a pending or failed startup check
+                checks.remove("startup");
+                *self.verification.lock().unwrap_or_else(|e| e.into_inner()) =
+                    Verification::Verified;
             }
             Ok(false) => {
                 checks.insert(
                     "github_api".into(),
-                    HealthStatus::unhealthy("Empty viewer login"),
+                    HealthStatus::unhealthy(api_failure("Empty viewer login".to_string())),
                 );
             }
             Err(e) => {
-                checks.insert("github_api".into(), HealthStatus::unhealthy(e.to_string()));
+                checks.insert(
+                    "github_api".into(),
+                    HealthStatus::unhealthy(api_failure(e.to_string())),
+                );
             }
         }
 
+        // Informational only: github_api is the liveness signal, and an
+        // incident shouldn't fail health while the API still answers
+        if status.is_some() {
+            if let Some(note) = &incident {
+                tracing::info!("{}", note);
+            }
+            checks.insert(
+                "github_status".into(),
+                HealthStatus::healthy_with_latency(status_latency),
+            );
+        }
+
         checks
     }
 }
@@ -0,0 +1,85 @@
+//! Token-overlap text similarity for duplicate detection.
+
+use std::collections::HashSet;
+
+/// Common words that carry no signal for matching issues.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "can", "does", "doesn", "for", "from",
+    "has", "have", "how", "i", "if", "in", "into", "is", "it", "its", "not", "of", "on", "or",
+    "should", "that", "the", "this", "to", "was", "when", "while", "with", "won", "t", "we", "you",
+];
+
+/// Lowercased alphanumeric tokens of `text`, minus stop words and one-letter tokens.
+pub fn tokenize(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .map(|t| t.to_lowercase())
+        .filter(|t| t.len() > 1 && !STOP_WORDS.contains(&t.as_str()))
+        .collect()
+}
+
+/// Jaccard similarity of two token sets (0.0 - 1.0).
+pub fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let shared = a.intersection(b).count() as f64;
+    shared / ((a.len() + b.len()) as f64 - shared)
+}
+
+/// Score a candidate against a query, weighting titles over bodies.
+///
+/// Returns the score and the shared title terms.
+pub fn score_candidate(
+    query_title: &HashSet<String>,
+    query_body: &HashSet<String>,
+    title: &str,
+    body: &str,
+) -> (f64, Vec<String>) {
+    let title_tokens = tokenize(title);
+    let body_tokens = tokenize(body);
+
+    let title_score = jaccard(query_title, &title_tokens);
+    let score = if query_body.is_empty() {
+        title_score
+    } else {
+        let all_query: HashSet<String> = query_title.union(query_body).cloned().collect();
+        let all_candidate: HashSet<String> = title_tokens.union(&body_tokens).cloned().collect();
+        0.7 * title_score + 0.3 * jaccard(&all_query, &all_candidate)
+    };
+
+    let mut matched: Vec<String> = query_title.intersection(&title_tokens).cloned().collect();
+    matched.sort();
+    (score, matched)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_drops_stop_words() {
+        let tokens = tokenize("The daemon crashes when the socket_path is missing!");
+        let mut sorted: Vec<_> = tokens.into_iter().collect();
+        sorted.sort();
+        assert_eq!(sorted, ["crashes", "daemon", "missing", "socket_path"]);
+    }
+
+    #[test]
+    fn test_score_candidate_ranks_duplicates_higher() {
+        let title = tokenize("Daemon crashes on startup with missing socket");
+        let body = tokenize("Running fgp-github start panics");
+
+        let (dup, matched) = score_candidate(
+            &title,
+            &body,
+            "Crash on startup: socket missing",
+            "fgp-github start panics immediately",
+        );
+        let (unrelated, _) = score_candidate(&title, &body, "Add dark mode to docs site", "");
+
+        assert!(dup > 0.4, "duplicate scored {}", dup);
+        assert!(unrelated < 0.05, "unrelated scored {}", unrelated);
+        assert_eq!(matched, ["missing", "socket", "startup"]);
+        assert!((jaccard(&title, &title) - 1.0).abs() < f64::EPSILON);
+    }
+}
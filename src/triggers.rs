@@ -0,0 +1,382 @@
+//! Event-driven triggers.
+//!
+//! Triggers come from `[[triggers]]` in the daemon config file (see
+//! `config.rs`) and run on the
+//! scheduler thread against every event appended to the [`EventLog`]:
+//!
+//! ```toml
+//! [[triggers]]
+//! name = "deploy-on-label"
+//! on = "labeled"                 # event kind
+//! repo = "my-org/app"
+//! label = "deploy"
+//! subject = "pull_request"       # or "issue"
+//! emit = "deploy_requested"      # append an event subscribers can replay
+//! call = "slack.post_message"    # invoke an FGP method
+//! params = { channel = "#deploys", text = "{repo}#{number} labeled {label} by {actor}" }
+//! ```
+//!
+//! A trigger needs `emit`, `call`, or both. `{field}` in string params is
+//! replaced with that field of the event payload. `repo` accepts `*`
+//! wildcards, except on `labeled` / `unlabeled` triggers, whose repos are the
+//! ones the label poller watches. Events emitted by triggers never fire
+//! other triggers.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::events::{Event, EventLog};
+use crate::poller::{LABELED_EVENT, UNLABELED_EVENT};
+use crate::rules::wildcard_match;
+
+/// How long a triggered FGP call may take.
+const CALL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A configured trigger.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trigger {
+    pub name: String,
+    /// Event kind to react to, e.g. "labeled" or "notification".
+    pub on: String,
+    #[serde(default)]
+    pub repo: Option<String>,
+    /// Label name (case-insensitive).
+    #[serde(default)]
+    pub label: Option<String>,
+    /// "issue" or "pull_request".
+    #[serde(default)]
+    pub subject: Option<String>,
+    /// Event kind to append when the trigger fires.
+    #[serde(default)]
+    pub emit: Option<String>,
+    /// FGP method to call when the trigger fires.
+    #[serde(default)]
+    pub call: Option<String>,
+    #[serde(default)]
+    pub params: Option<Value>,
+}
+
+/// Check triggers parsed from the config file.
+pub(crate) fn validate(triggers: Vec<Trigger>) -> Result<Vec<Trigger>> {
+    for trigger in &triggers {
+        if trigger.emit.is_none() && trigger.call.is_none() {
+            bail!("Trigger '{}' needs 'emit' or 'call'", trigger.name);
+        }
+        if let Some(subject) = &trigger.subject {
+            if subject != "issue" && subject != "pull_request" {
+                bail!(
+                    "Trigger '{}': subject must be 'issue' or 'pull_request'",
+                    trigger.name
+                );
+            }
+        }
+        if trigger.watches_labels()
+            && !trigger
+                .repo
+                .as_deref()
+                .is_some_and(|r| r.contains('/') && !r.contains('*'))
+        {
+            bail!(
+                "Trigger '{}': label triggers need a repo in owner/repo format",
+                trigger.name
+            );
+        }
+    }
+    Ok(triggers)
+}
+
+/// Repositories the label poller must watch for these triggers.
+pub fn watched_repos(triggers: &[Trigger]) -> Vec<String> {
+    let mut repos: Vec<String> = triggers
+        .iter()
+        .filter(|t| t.watches_labels())
+        .filter_map(|t| t.repo.clone())
+        .collect();
+    repos.sort();
+    repos.dedup();
+    repos
+}
+
+impl Trigger {
+    fn watches_labels(&self) -> bool {
+        self.on == LABELED_EVENT || self.on == UNLABELED_EVENT
+    }
+
+    /// Whether this trigger reacts to `event`.
+    pub fn matches(&self, event: &Event) -> bool {
+        if event.kind != self.on || event.payload.get("trigger").is_some() {
+            return false;
+        }
+        let field = |name: &str| event.payload.get(name).and_then(|v| v.as_str());
+
+        let repo_ok = self.repo.as_deref().is_none_or(|pattern| {
+            field("repo")
+                .or(field("repo_full_name"))
+                .is_some_and(|repo| wildcard_match(pattern, repo))
+        });
+        let label_ok = self
+            .label
+            .as_deref()
+            .is_none_or(|label| field("label").is_some_and(|l| l.eq_ignore_ascii_case(label)));
+        let subject_ok = self.subject.as_deref().is_none_or(|subject| {
+            let is_pr = event
+                .payload
+                .get("is_pull_request")
+                .and_then(|v| v.as_bool())
+                .or(field("subject_type").map(|t| t == "PullRequest"));
+            is_pr == Some(subject == "pull_request")
+        });
+
+        repo_ok && label_ok && subject_ok
+    }
+}
+
+/// Runs triggers over events recorded since it last ran.
+#[derive(Debug)]
+pub struct TriggerRunner {
+    triggers: Vec<Trigger>,
+    /// Last event sequence number already considered.
+    cursor: u64,
+}
+
+impl TriggerRunner {
+    /// Start after the events already in the log, so a restart doesn't
+    /// re-fire old triggers.
+    pub fn new(triggers: Vec<Trigger>, events: &EventLog) -> Self {
+        Self {
+            triggers,
+            cursor: events.last_seq(),
+        }
+    }
+
+    /// Fire triggers for new events. Returns how many fired.
+    pub fn run(&mut self, events: &Mutex<EventLog>) -> usize {
+        if self.triggers.is_empty() {
+            return 0;
+        }
+        let pending = {
+            let log = events.lock().unwrap_or_else(|e| e.into_inner());
+            log.since(self.cursor, usize::MAX, &[])
+        };
+
+        let mut fired = 0;
+        for event in pending {
+            self.cursor = event.seq;
+            for trigger in self.triggers.iter().filter(|t| t.matches(&event)) {
+                match fire(trigger, &event, events) {
+                    Ok(()) => fired += 1,
+                    Err(e) => tracing::warn!("Trigger '{}' failed: {:#}", trigger.name, e),
+                }
+            }
+        }
+        fired
+    }
+}
+
+fn fire(trigger: &Trigger, event: &Event, events: &Mutex<EventLog>) -> Result<()> {
+    tracing::info!("Trigger '{}' fired on event {}", trigger.name, event.seq);
+
+    if let Some(kind) = &trigger.emit {
+        let mut log = events.lock().unwrap_or_else(|e| e.into_inner());
+        log.append(
+            kind,
+            serde_json::json!({
+                "trigger": trigger.name,
+                "source_seq": event.seq,
+                "source_kind": event.kind,
+                "event": event.payload,
+            }),
+        )?;
+    }
+
+    if let Some(method) = &trigger.call {
+        let params = expand(
+            trigger.params.as_ref().unwrap_or(&Value::Null),
+            &event.payload,
+        );
+        call_method(method, &params)?;
+    }
+
+    Ok(())
+}
+
+/// Replace `{field}` in string values with fields of `payload`.
+fn expand(template: &Value, payload: &Value) -> Value {
+    match template {
+        Value::String(s) => Value::String(substitute(s, payload)),
+        Value::Array(items) => Value::Array(items.iter().map(|v| expand(v, payload)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), expand(v, payload)))
+                .collect(),
+        ),
+        Value::Null => Value::Object(Default::default()),
+        other => other.clone(),
+    }
+}
+
+/// Replace each `{field}` in `text` in a single pass, so field values (which
+/// may be GitHub-controlled text like titles or labels) are never expanded
+/// themselves. Unknown fields are left as written.
+fn substitute(text: &str, payload: &Value) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let field = after.find('}').and_then(|end| {
+            let value = match payload.get(&after[..end])? {
+                Value::String(v) => v.clone(),
+                Value::Number(n) => n.to_string(),
+                Value::Bool(b) => b.to_string(),
+                _ => return None,
+            };
+            Some((value, end))
+        });
+        match field {
+            Some((value, end)) => {
+                out.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Call a method on the local FGP daemon that serves it
+/// (`~/.fgp/services/<service>/daemon.sock`).
+fn call_method(method: &str, params: &Value) -> Result<Value> {
+    let Some((service, _)) = method.split_once('.') else {
+        bail!("Method '{}' must be qualified as service.method", method);
+    };
+    let socket =
+        shellexpand::tilde(&format!("~/.fgp/services/{}/daemon.sock", service)).to_string();
+
+    let mut stream =
+        UnixStream::connect(&socket).with_context(|| format!("Failed to connect to {}", socket))?;
+    stream.set_read_timeout(Some(CALL_TIMEOUT))?;
+    stream.set_write_timeout(Some(CALL_TIMEOUT))?;
+
+    let request = serde_json::json!({
+        "id": format!("github-trigger-{}", chrono::Utc::now().timestamp_millis()),
+        "v": 1,
+        "method": method,
+        "params": params,
+    });
+    writeln!(stream, "{}", request)?;
+
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    let response: Value =
+        serde_json::from_str(&line).with_context(|| format!("Bad response from {}", socket))?;
+
+    if response.get("ok").and_then(|v| v.as_bool()) != Some(true) {
+        bail!(
+            "{} failed: {}",
+            method,
+            response.get("error").unwrap_or(&Value::Null)
+        );
+    }
+    Ok(response.get("result").cloned().unwrap_or(Value::Null))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use serde_json::json;
+
+    fn parse(content: &str) -> Result<Vec<Trigger>> {
+        Config::parse(content).map(|c| c.triggers)
+    }
+
+    fn event(kind: &str, payload: Value) -> Event {
+        Event {
+            seq: 1,
+            kind: kind.to_string(),
+            created_at: chrono::Utc::now(),
+            payload,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_match() {
+        let triggers = parse(
+            r#"
+            [[triggers]]
+            name = "deploy"
+            on = "labeled"
+            repo = "my-org/app"
+            label = "Deploy"
+            subject = "pull_request"
+            emit = "deploy_requested"
+
+            [[triggers]]
+            name = "security"
+            on = "notification"
+            repo = "my-org/*"
+            call = "slack.post_message"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(watched_repos(&triggers), ["my-org/app"]);
+
+        let labeled = event(
+            "labeled",
+            json!({"repo": "my-org/app", "label": "deploy", "is_pull_request": true}),
+        );
+        assert!(triggers[0].matches(&labeled));
+        let on_issue = event(
+            "labeled",
+            json!({"repo": "my-org/app", "label": "deploy", "is_pull_request": false}),
+        );
+        assert!(!triggers[0].matches(&on_issue));
+
+        let notification = event("notification", json!({"repo_full_name": "my-org/api"}));
+        assert!(triggers[1].matches(&notification));
+        assert!(!triggers[0].matches(&notification));
+
+        // Trigger output never re-triggers
+        let emitted = event(
+            "notification",
+            json!({"trigger": "x", "repo": "my-org/api"}),
+        );
+        assert!(!triggers[1].matches(&emitted));
+    }
+
+    #[test]
+    fn test_parse_rejects_incomplete_triggers() {
+        assert!(parse("[[triggers]]\nname = \"a\"\non = \"notification\"").is_err());
+        assert!(parse(
+            "[[triggers]]\nname = \"a\"\non = \"labeled\"\nrepo = \"org/*\"\nemit = \"x\""
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_expand_params() {
+        let template = json!({"text": "{repo}#{number} got {label}", "tags": ["{label}"], "n": 1});
+        let payload = json!({"repo": "o/r", "number": 7, "label": "deploy"});
+        assert_eq!(
+            expand(&template, &payload),
+            json!({"text": "o/r#7 got deploy", "tags": ["deploy"], "n": 1})
+        );
+
+        // Substituted values are never expanded again
+        let payload = json!({"title": "{secret} {repo}", "repo": "o/r", "secret": "s3cr3t"});
+        assert_eq!(
+            expand(&json!("{title} in {repo} {missing} {"), &payload),
+            json!("{secret} {repo} in o/r {missing} {")
+        );
+    }
+}
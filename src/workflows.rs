@@ -0,0 +1,218 @@
+//! GitHub Actions workflow file inspection.
+//!
+//! Extracts triggers, jobs, runners, and `uses:` references from workflow
+//! YAML and classifies each reference for supply-chain review.
+
+use serde_yaml::Value as Yaml;
+
+use crate::models::{ActionRef, WorkflowConfig, WorkflowJobConfig};
+
+/// Organizations whose actions are maintained by GitHub.
+const FIRST_PARTY_OWNERS: [&str; 2] = ["actions", "github"];
+
+/// Parse a workflow file. Invalid YAML yields a config with `parse_error` set.
+pub fn parse_workflow(path: &str, text: &str) -> WorkflowConfig {
+    let mut config = WorkflowConfig {
+        path: path.to_string(),
+        name: None,
+        triggers: Vec::new(),
+        jobs: Vec::new(),
+        actions: Vec::new(),
+        unpinned_third_party: Vec::new(),
+        parse_error: None,
+    };
+
+    let doc: Yaml = match serde_yaml::from_str(text) {
+        Ok(doc) => doc,
+        Err(e) => {
+            config.parse_error = Some(e.to_string());
+            return config;
+        }
+    };
+
+    config.name = doc.get("name").and_then(Yaml::as_str).map(String::from);
+    config.triggers = match doc.get("on") {
+        Some(Yaml::String(event)) => vec![event.clone()],
+        Some(Yaml::Sequence(events)) => events
+            .iter()
+            .filter_map(Yaml::as_str)
+            .map(String::from)
+            .collect(),
+        Some(Yaml::Mapping(events)) => events
+            .keys()
+            .filter_map(Yaml::as_str)
+            .map(String::from)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let jobs = doc.get("jobs").and_then(Yaml::as_mapping);
+    for (id, job) in jobs.into_iter().flatten() {
+        let Some(id) = id.as_str() else { continue };
+        let uses = job.get("uses").and_then(Yaml::as_str).map(String::from);
+        let steps = job
+            .get("steps")
+            .and_then(Yaml::as_sequence)
+            .cloned()
+            .unwrap_or_default();
+
+        if let Some(uses) = &uses {
+            config.actions.push(classify_uses(uses, id));
+        }
+        for step in &steps {
+            if let Some(uses) = step.get("uses").and_then(Yaml::as_str) {
+                config.actions.push(classify_uses(uses, id));
+            }
+        }
+
+        config.jobs.push(WorkflowJobConfig {
+            id: id.to_string(),
+            runs_on: runner_labels(job.get("runs-on")),
+            uses,
+            step_count: steps.len() as i32,
+        });
+    }
+
+    for action in &config.actions {
+        if action.third_party
+            && !action.pinned
+            && !config.unpinned_third_party.contains(&action.uses)
+        {
+            config.unpinned_third_party.push(action.uses.clone());
+        }
+    }
+
+    config
+}
+
+/// Runner labels from a `runs-on` value (string, list, or `{group, labels}`).
+fn runner_labels(runs_on: Option<&Yaml>) -> Vec<String> {
+    match runs_on {
+        Some(Yaml::String(label)) => vec![label.clone()],
+        Some(Yaml::Sequence(labels)) => labels
+            .iter()
+            .filter_map(Yaml::as_str)
+            .map(String::from)
+            .collect(),
+        Some(Yaml::Mapping(_)) => {
+            let value = runs_on.unwrap_or(&Yaml::Null);
+            let mut labels: Vec<String> = value
+                .get("group")
+                .and_then(Yaml::as_str)
+                .map(|g| vec![format!("group:{}", g)])
+                .unwrap_or_default();
+            labels.extend(runner_labels(value.get("labels")));
+            labels
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Classify a `uses:` reference.
+fn classify_uses(uses: &str, job: &str) -> ActionRef {
+    let (target, version) = match uses.split_once('@') {
+        Some((target, version)) => (target, Some(version.to_string())),
+        None => (uses, None),
+    };
+
+    let kind = if uses.starts_with("./") {
+        "local"
+    } else if uses.starts_with("docker://") {
+        "docker"
+    } else if target.contains("/.github/workflows/") {
+        "reusable_workflow"
+    } else {
+        "action"
+    };
+
+    let owner = target.split('/').next().unwrap_or("");
+    let third_party = matches!(kind, "action" | "reusable_workflow")
+        && !FIRST_PARTY_OWNERS.contains(&owner.to_lowercase().as_str());
+    let pinned = match kind {
+        "local" => true,
+        "docker" => uses.contains("@sha256:"),
+        _ => version
+            .as_deref()
+            .is_some_and(|v| v.len() == 40 && v.chars().all(|c| c.is_ascii_hexdigit())),
+    };
+
+    ActionRef {
+        uses: uses.to_string(),
+        job: job.to_string(),
+        kind: kind.to_string(),
+        version,
+        pinned,
+        third_party,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKFLOW: &str = r#"
+name: CI
+on:
+  push:
+    branches: [main]
+  pull_request:
+jobs:
+  test:
+    runs-on: [self-hosted, linux]
+    steps:
+      - uses: actions/checkout@v4
+      - uses: dtolnay/rust-toolchain@stable
+      - uses: Swatinem/rust-cache@23bce251a8cd2ffc3c1075eaa2367cf899916d84
+      - run: cargo test
+      - uses: ./.github/actions/report
+  release:
+    uses: my-org/shared/.github/workflows/release.yml@main
+  image:
+    runs-on:
+      group: large
+    steps:
+      - uses: docker://alpine:3.19
+"#;
+
+    #[test]
+    fn test_parse_workflow() {
+        let config = parse_workflow(".github/workflows/ci.yml", WORKFLOW);
+        assert!(config.parse_error.is_none());
+        assert_eq!(config.name.as_deref(), Some("CI"));
+        assert_eq!(config.triggers, ["push", "pull_request"]);
+
+        let test = &config.jobs[0];
+        assert_eq!(test.runs_on, ["self-hosted", "linux"]);
+        assert_eq!(test.step_count, 5);
+        assert_eq!(config.jobs[2].runs_on, ["group:large"]);
+
+        let kinds: Vec<&str> = config.actions.iter().map(|a| a.kind.as_str()).collect();
+        assert_eq!(
+            kinds,
+            [
+                "action",
+                "action",
+                "action",
+                "local",
+                "reusable_workflow",
+                "docker"
+            ]
+        );
+        assert!(!config.actions[0].third_party);
+        assert!(config.actions[2].pinned);
+        assert_eq!(
+            config.unpinned_third_party,
+            [
+                "dtolnay/rust-toolchain@stable",
+                "my-org/shared/.github/workflows/release.yml@main"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_yaml() {
+        let config = parse_workflow("bad.yml", "jobs: [unclosed");
+        assert!(config.parse_error.is_some());
+        assert!(config.jobs.is_empty());
+    }
+}